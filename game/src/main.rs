@@ -13,8 +13,9 @@ use bevy::{
 };
 use bevy_rapier2d::prelude::*;
 use game_library::{
-    data_loader::storage::GameData, state::Game, GeneratedMaps, GenerationSeed, Layer, LayerPlugin,
-    MarkersToBiomes, NoisePlugin, PhysicsPlugin, SchedulingPlugin,
+    data_loader::storage::GameData, state::Game, ActiveEffectsPlugin, GeneratedMaps,
+    GenerationSeed, Layer, LayerPlugin, MarkersToBiomes, NoisePlugin, PhysicsPlugin,
+    SchedulingPlugin,
 };
 use in_game::InGamePlugin;
 use leafwing_input_manager::plugin::InputManagerPlugin;
@@ -28,6 +29,7 @@ mod events;
 mod game_overlays;
 mod in_game;
 mod main_menu;
+mod monsters;
 mod player;
 mod resources;
 mod settings_menu;
@@ -164,6 +166,10 @@ impl Plugin for ElementalistGameplayPlugins {
             spells::SpellsPlugin,
             // Add the plugin for the movement
             resources::movement::MovementPlugin,
+            // Buffs and debuffs
+            ActiveEffectsPlugin,
+            // The monsters in the realm
+            monsters::MonstersPlugin,
             // Input processing
             InputManagerPlugin::<PlayerAction>::default(),
             InputManagerPlugin::<MenuInteraction>::default(),
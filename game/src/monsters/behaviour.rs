@@ -0,0 +1,135 @@
+//! Drives the monsters' [`MonsterBrain`]s: what they sense goes in, and their movement and
+//! attacks come out.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::{Faction, ParticleAttachment, StatEnum},
+    events::{ParticleAnchor, SpawnSpellParticles},
+    Health, Monster, MonsterBrain, Senses, SpellCooldowns, SpellSnapshot, StatBundle, Structure,
+};
+
+use crate::spells::{spawn_spell_projectile, ProjectileLaunch};
+
+/// Movement speed of monsters without a `movementSpeed` stat.
+const MONSTER_BASE_SPEED: f32 = 1.5;
+/// Converts the `movementSpeed` stat (distance per frame, like the player's) into velocity.
+const MONSTER_SPEED_MULTIPLIER: f32 = 60.0;
+
+/// Ticks each monster's behaviour, moving it and casting its first ready spell at its target.
+///
+/// Monsters sense the closest living character which is hostile to their faction.
+#[allow(clippy::type_complexity)]
+pub(super) fn monster_behaviour(
+    mut commands: Commands,
+    mut monsters: Query<(
+        Entity,
+        &Monster,
+        &mut MonsterBrain,
+        &Transform,
+        &Health,
+        &mut Velocity,
+        &mut SpellCooldowns,
+        Option<&StatBundle>,
+        Option<&Faction>,
+    )>,
+    characters: Query<(Entity, &Transform, &Health, Option<&Faction>), Without<Structure>>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    for (
+        entity,
+        monster,
+        mut brain,
+        transform,
+        health,
+        mut velocity,
+        mut cooldowns,
+        stats,
+        faction,
+    ) in &mut monsters
+    {
+        let Some(monster_data) = game_data.monsters.get(&monster.monster_id) else {
+            continue;
+        };
+        let faction = faction.copied().unwrap_or_default();
+        let position = transform.translation.truncate();
+
+        let target = characters
+            .iter()
+            .filter(|(other, _, other_health, other_faction)| {
+                *other != entity
+                    && !other_health.is_dead()
+                    && faction.is_hostile_to(other_faction.copied().unwrap_or_default())
+            })
+            .map(|(_, other_transform, _, _)| other_transform.translation.truncate())
+            .min_by(|a, b| {
+                position
+                    .distance_squared(*a)
+                    .total_cmp(&position.distance_squared(*b))
+            });
+        let senses = Senses {
+            position,
+            health: health.value.remaining(),
+            target,
+        };
+        let action = brain.tick(&monster_data.behaviour, &senses, time.delta_seconds());
+
+        let speed = stats
+            .and_then(|stats| stats.get_stat(&StatEnum::MovementSpeed))
+            .map_or(MONSTER_BASE_SPEED, game_library::Stat::value)
+            * MONSTER_SPEED_MULTIPLIER;
+        velocity.linvel = action.movement * speed;
+
+        let Some(attack_at) = action.attack else {
+            continue;
+        };
+        let Some((spell_id, spell)) = monster_data
+            .spells
+            .iter()
+            .filter(|spell_id| cooldowns.is_ready(spell_id))
+            .find_map(|spell_id| {
+                game_data
+                    .spells
+                    .get(spell_id)
+                    .filter(|spell| spell.launches_projectile())
+                    .map(|spell| (spell_id, spell))
+            })
+        else {
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&spell.sprite_tileset) else {
+            tracing::error!(
+                "monster_behaviour: No texture atlas found for {} (spell:{spell_id})",
+                spell.sprite_tileset
+            );
+            continue;
+        };
+        cooldowns.start(spell_id, spell.cooldown);
+
+        let to_target = attack_at - position;
+        let snapshot = SpellSnapshot::new(spell_id, spell, stats, []);
+        let launch = ProjectileLaunch {
+            caster: entity,
+            origin: transform.translation,
+            direction: to_target.normalize_or_zero(),
+            aim_distance: to_target.length(),
+            inherited_velocity: Vec2::ZERO,
+            already_hit: Vec::new(),
+        };
+        let projectile = spawn_spell_projectile(
+            &mut commands,
+            texture_atlas.clone(),
+            spell,
+            snapshot,
+            launch,
+        );
+        ew_spawn_particles.send(SpawnSpellParticles {
+            spell_id: spell_id.clone(),
+            attachment: ParticleAttachment::Projectile,
+            anchor: ParticleAnchor::Entity(projectile),
+        });
+        tracing::debug!("monster_behaviour: {entity:?} attacks with {spell_id}");
+    }
+}
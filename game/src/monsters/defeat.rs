@@ -0,0 +1,30 @@
+//! Defeated monsters drop their essence and are removed from the realm.
+use bevy::prelude::*;
+use game_library::{data_loader::storage::GameData, CollectedEssence, Health, Monster};
+
+/// Starts each run without any collected essence.
+pub(super) fn clear_collected_essence(mut collected: ResMut<CollectedEssence>) {
+    collected.clear();
+}
+
+/// Despawns defeated monsters, adding the essence they drop to the [`CollectedEssence`].
+pub(super) fn defeat_monsters(
+    mut commands: Commands,
+    monsters: Query<(Entity, &Monster, &Health)>,
+    game_data: Res<GameData>,
+    mut collected: ResMut<CollectedEssence>,
+) {
+    let mut rng = rand::thread_rng();
+    for (entity, monster, health) in &monsters {
+        if !health.is_dead() {
+            continue;
+        }
+        if let Some(monster_data) = game_data.monsters.get(&monster.monster_id) {
+            for (magic, amount) in monster_data.roll_essence(&mut rng) {
+                collected.add(magic, amount);
+            }
+        }
+        tracing::debug!("defeat_monsters: {} was defeated", monster.monster_id);
+        commands.entity(entity).despawn_recursive();
+    }
+}
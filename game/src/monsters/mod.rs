@@ -0,0 +1,14 @@
+//! Monsters module. Contains the code for the monsters which roam the realms.
+//!
+//! Monsters are spawned across the realm when the game starts, using the spawn weights of the
+//! realm and its biomes (see [`game_library::MonsterDetail`]). When they are defeated they drop
+//! their essence, which is added to the [`game_library::CollectedEssence`].
+//!
+//! Their behaviour (see [`game_library::MonsterBrain`]) is ticked with the other entity updates.
+
+mod behaviour;
+mod defeat;
+mod plugin;
+mod spawning;
+
+pub use plugin::MonstersPlugin;
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use game_library::{state::Game, CollectedEssence, GameSet, Monster};
+
+use super::{
+    behaviour::monster_behaviour,
+    defeat::{clear_collected_essence, defeat_monsters},
+    spawning::spawn_monsters,
+};
+use crate::despawn_with_tag;
+
+/// Plugin which spawns the monsters in the realm, drives their behaviour, and handles them being
+/// defeated.
+pub struct MonstersPlugin;
+
+impl Plugin for MonstersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollectedEssence>()
+            .add_systems(
+                OnEnter(Game::Playing),
+                (clear_collected_essence, spawn_monsters),
+            )
+            .add_systems(
+                Update,
+                monster_behaviour
+                    .in_set(GameSet::EntityUpdate)
+                    .run_if(in_state(Game::Playing)),
+            )
+            .add_systems(Update, defeat_monsters.run_if(in_state(Game::Playing)))
+            .add_systems(OnExit(Game::Playing), despawn_with_tag::<Monster>);
+    }
+}
@@ -0,0 +1,82 @@
+//! Spawns the monsters of the realm, driven by the spawn weights of the realm and its biomes.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData, GeneratedMaps, GenerationSeed, Health, Layer, MarkersToBiomes,
+    Monster, MonsterBrain, MovementBundle, SpellCooldowns, StatBundle,
+};
+use rand::SeedableRng;
+
+/// Monsters don't spawn within this distance (in pixels) of where the player's avatar starts.
+const SAFE_DISTANCE: f32 = 96.0;
+
+/// Spawns the monsters across the realm.
+///
+/// Each tile rolls once (using the generation seed) for a monster from its biome's and the
+/// realm's monsters, so the same seed always spawns the same monsters in the same places (and
+/// their brains are seeded from it and where they spawn, so they behave the same way too).
+pub(super) fn spawn_monsters(
+    mut commands: Commands,
+    game_data: Res<GameData>,
+    generated_map: Res<GeneratedMaps>,
+    seed: Res<GenerationSeed>,
+) {
+    let Some(realm) = game_data.realms.get("simple test realm") else {
+        tracing::error!("spawn_monsters: No realm found for 'simple test realm'");
+        return;
+    };
+
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(seed.as_u64());
+    let mut spawned: usize = 0;
+    let biome_map = realm.markers_to_biomes(generated_map.biome_map.as_slice());
+    for (i, row) in biome_map.iter().enumerate() {
+        for (j, biome) in row.iter().enumerate() {
+            // Always roll, so skipped tiles don't change what spawns on the rest of the map
+            let Some(monster_id) = biome.random_monster(&realm.monsters, &mut rng) else {
+                continue;
+            };
+            let translation = generated_map.map_to_world((i, j));
+            if translation.truncate().length() < SAFE_DISTANCE {
+                continue;
+            }
+            let Some(monster) = game_data.monsters.get(monster_id) else {
+                tracing::warn!("spawn_monsters: No monster found for id {}", monster_id);
+                continue;
+            };
+            let Some(texture_atlas) = game_data.tile_atlas.get(&monster.sprite_tileset) else {
+                tracing::error!(
+                    "spawn_monsters: No texture atlas found for {} (monster:{})",
+                    monster.sprite_tileset,
+                    monster_id
+                );
+                continue;
+            };
+
+            // same depth mapping as the environment objects
+            let depth = (translation.y / (0.5 * generated_map.dimensions().1 as f32)
+                * f32::from(i16::MAX)) as i16;
+
+            let brain_seed = seed.as_u64() ^ (((i as u64) << 32) | j as u64);
+            commands.spawn((
+                Monster::new(monster_id),
+                MonsterBrain::new(translation.truncate(), brain_seed),
+                monster.faction,
+                Health::new(monster.health),
+                StatBundle::new(monster.stats.clone()),
+                SpellCooldowns::default(),
+                MovementBundle::default(),
+                SpriteSheetBundle {
+                    texture_atlas: texture_atlas.clone(),
+                    sprite: monster.texture_atlas_index(),
+                    transform: Transform::from_translation(translation),
+                    ..Default::default()
+                },
+                RigidBody::KinematicVelocityBased,
+                monster.collider.collider(),
+                Layer::Foreground(depth),
+            ));
+            spawned += 1;
+        }
+    }
+    tracing::info!("spawn_monsters: spawned {} monsters", spawned);
+}
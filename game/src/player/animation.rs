@@ -1,6 +1,6 @@
 //! Animation states and systems for the player's avatar.
 use bevy::prelude::*;
-use game_library::events::CastSpell;
+use game_library::{events::CastSpell, AnimationSet, Polymorphed};
 
 use super::avatar::PlayerAvatar;
 
@@ -40,11 +40,6 @@ pub(super) enum PlayerFacing {
 #[derive(Debug, Default, Resource)]
 pub(super) struct PlayerAnimationTimer(pub Timer);
 
-const WALKING_ANIMATION: [usize; 4] = [0, 1, 0, 2];
-const CASTING_WALKING_ANIMATION: [usize; 4] = [5, 6, 7, 8];
-const IDLE_ANIMATION: [usize; 1] = [3];
-const CASTING_IDLE_ANIMATION: [usize; 1] = [4];
-
 /// The frame of the animation that the player's avatar is currently on.
 #[derive(Debug, Default, Resource, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) struct AnimationFrame(pub usize);
@@ -101,9 +96,10 @@ pub(super) fn advance_animation_timer(
 /// The system that updates the avatar's animation based on its state.
 ///
 /// Tracks a local "frame" that goes from 0 to 3, and updates the sprite index based on the current
-/// state. We advance the frame every 0.1 seconds.
+/// state. We advance the frame every 0.1 seconds. While polymorphed, the form's animations are
+/// used instead of the wizard's.
 pub fn update_avatar_animation(
-    mut sprite_query: Query<&mut TextureAtlasSprite, With<PlayerAvatar>>,
+    mut sprite_query: Query<(&mut TextureAtlasSprite, Option<&Polymorphed>), With<PlayerAvatar>>,
     state: Res<State<PlayerAnimation>>,
     supplemental_state: Res<State<PlayerAnimationSupplemental>>,
     facing: Res<State<PlayerFacing>>,
@@ -111,7 +107,7 @@ pub fn update_avatar_animation(
     frame: Res<AnimationFrame>,
 ) {
     // should be just one player avatar & sprite
-    let Ok(mut sprite) = sprite_query.get_single_mut() else {
+    let Ok((mut sprite, polymorphed)) = sprite_query.get_single_mut() else {
         tracing::error!("update_avatar_animation: failed to get player sprite");
         return;
     };
@@ -119,19 +115,21 @@ pub fn update_avatar_animation(
     // Flip the sprite based on the facing state
     sprite.flip_x = *facing.get() == PlayerFacing::Left;
 
-    match (state.get(), supplemental_state.get()) {
-        (PlayerAnimation::Walking, PlayerAnimationSupplemental::None) => {
-            sprite.index = WALKING_ANIMATION[frame.get(WALKING_ANIMATION.len())];
-        }
+    let wizard_animations = AnimationSet::default();
+    let animations = polymorphed.map_or(&wizard_animations, |polymorphed| {
+        &polymorphed.form.animations
+    });
+    let animation = match (state.get(), supplemental_state.get()) {
+        (PlayerAnimation::Walking, PlayerAnimationSupplemental::None) => &animations.walking,
         (PlayerAnimation::Walking, PlayerAnimationSupplemental::Casting) => {
-            sprite.index = CASTING_WALKING_ANIMATION[frame.get(CASTING_WALKING_ANIMATION.len())];
-        }
-        (PlayerAnimation::Idle, PlayerAnimationSupplemental::None) => {
-            sprite.index = IDLE_ANIMATION[frame.get(IDLE_ANIMATION.len())];
-        }
-        (PlayerAnimation::Idle, PlayerAnimationSupplemental::Casting) => {
-            sprite.index = CASTING_IDLE_ANIMATION[frame.get(CASTING_IDLE_ANIMATION.len())];
+            &animations.casting_walking
         }
+        (PlayerAnimation::Idle, PlayerAnimationSupplemental::None) => &animations.idle,
+        (PlayerAnimation::Idle, PlayerAnimationSupplemental::Casting) => &animations.casting_idle,
+    };
+    // forms from the data files may leave an animation empty
+    if !animation.is_empty() {
+        sprite.index = animation[frame.get(animation.len())];
     }
 
     // If we're casting, reset the supplemental state after the casting animation is done
@@ -3,9 +3,10 @@ use bevy_rapier2d::prelude::*;
 use game_library::{
     colors,
     data_loader::storage::GameData,
-    enums::StatEnum,
+    enums::{Faction, StatEnum},
     progress_bar::{BarState, ProgressBarConfig},
-    Health, Layer, Mana, MovementBundle, SpellChoices, StatBundle, Xp,
+    ActiveEffects, Health, Layer, Mana, MovementBundle, Skills, SpellChoices, SpellCooldowns,
+    StatBundle, UnlockedSpells, Xp,
 };
 
 use super::{
@@ -30,6 +31,8 @@ pub struct PlayerAvatar;
 pub fn spawn_player_avatar(
     mut commands: Commands,
     mut spell_choices: ResMut<SpellChoices>,
+    mut unlocked_spells: ResMut<UnlockedSpells>,
+    skills: Res<Skills>,
     game_data: Res<GameData>,
     existing_players: Query<&PlayerAvatar>,
 ) {
@@ -38,13 +41,21 @@ pub fn spawn_player_avatar(
         return;
     }
 
+    // Unlock every spell the player meets the requirements for
+    let newly_unlocked = unlocked_spells.unlock_available(&game_data.spells, &skills);
+    tracing::debug!("spawn_player_avatar: unlocked spells {newly_unlocked:?}");
+
     // Load spells (forced right now)
     for spell_id in game_data.spells.iter_ids() {
-        if spell_id.contains("time_dart") {
-            spell_choices.set_primary_by_id(spell_id.clone());
-        }
-        if spell_id.contains("spark") {
-            spell_choices.set_secondary_by_id(spell_id.clone());
+        let chosen = if spell_id.contains("time_dart") {
+            spell_choices.set_primary_by_id(spell_id.clone(), &game_data.spells, &unlocked_spells)
+        } else if spell_id.contains("freeze_ray") {
+            spell_choices.set_secondary_by_id(spell_id.clone(), &game_data.spells, &unlocked_spells)
+        } else {
+            continue;
+        };
+        if let Err(err) = chosen {
+            tracing::warn!("spawn_player_avatar: can't choose {spell_id}: {err}");
         }
     }
 
@@ -79,8 +90,11 @@ pub fn spawn_player_avatar(
                     .map(|stat| (stat.clone(), player_base_stats(stat)))
                     .collect(),
             ),
+            active_effects: ActiveEffects::default(),
+            spell_cooldowns: SpellCooldowns::default(),
             xp: Xp::default(),
             player: Player,
+            faction: Faction::Player,
             kinematic_controller: KinematicCharacterController::default(),
             collider: Collider::capsule_y(6.0, 4.0),
             rigid_body: RigidBody::KinematicVelocityBased,
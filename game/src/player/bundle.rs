@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
-use game_library::{enums::StatEnum, Health, Layer, Mana, MovementBundle, StatBundle, Xp};
+use game_library::{
+    enums::{Faction, StatEnum},
+    ActiveEffects, Health, Layer, Mana, MovementBundle, SpellCooldowns, StatBundle, Xp,
+};
 
 /// Base stats for the player. These are the stats that the player starts with, and are used to
 /// initiate the [`StatBundle`] for the player.
@@ -71,10 +74,16 @@ pub struct PlayerBundle {
     pub mana: Mana,
     /// The player's stats.
     pub stats: StatBundle,
+    /// The buffs and debuffs currently affecting the player.
+    pub active_effects: ActiveEffects,
+    /// The cooldowns of the spells the player has cast.
+    pub spell_cooldowns: SpellCooldowns,
     /// The player's experience points.
     pub xp: Xp,
     /// Player marker component.
     pub player: Player,
+    /// The player's side (for spell targeting).
+    pub faction: Faction,
     /// Player controller marker component (rapier)
     pub kinematic_controller: KinematicCharacterController,
     /// Physics collider
@@ -10,6 +10,7 @@ mod avatar;
 mod bundle;
 mod menu_control;
 mod movement;
+mod perks;
 mod player_control;
 mod player_creation;
 mod player_sprite;
@@ -0,0 +1,44 @@
+//! Applies the player's allocated perks to their avatar.
+use bevy::prelude::*;
+use game_library::{data_loader::storage::GameData, PerkState, SpellStatModifiers, StatBundle};
+
+use super::avatar::PlayerAvatar;
+
+/// Keeps the player's stats and spell modifiers in line with their allocated perks.
+///
+/// This only does anything when the allocated perks change or a new avatar is spawned.
+pub(super) fn apply_perks(
+    mut commands: Commands,
+    mut perk_state: ResMut<PerkState>,
+    game_data: Res<GameData>,
+    mut query: Query<(
+        Entity,
+        &mut StatBundle,
+        Option<&mut SpellStatModifiers>,
+        Ref<PlayerAvatar>,
+    )>,
+) {
+    let Ok((player, mut stats, spell_modifiers, avatar)) = query.get_single_mut() else {
+        return;
+    };
+    if !perk_state.is_changed() && !avatar.is_added() {
+        return;
+    }
+
+    // syncing doesn't count as changing the allocated perks
+    let perk_state = perk_state.bypass_change_detection();
+    if avatar.is_added() {
+        // a new avatar has fresh stats, so nothing has been applied to them yet
+        perk_state.forget_applied();
+    }
+    perk_state.sync_stats(&mut stats, &game_data.perks);
+
+    let modifiers = perk_state.spell_modifiers(&game_data.perks);
+    if let Some(mut spell_modifiers) = spell_modifiers {
+        spell_modifiers.modifiers = modifiers;
+    } else {
+        commands
+            .entity(player)
+            .insert(SpellStatModifiers { modifiers });
+    }
+}
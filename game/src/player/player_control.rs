@@ -1,14 +1,19 @@
 use bevy::prelude::*;
 use game_library::{
-    events::CastSpell,
+    data_loader::storage::GameData,
+    enums::Faction,
+    events::{CastSpell, StopChanneling},
     settings::GameplaySettings,
     state::{AppState, Overlay},
-    SpellChoices,
+    AutoAimCandidate, AutoAimTarget, Health, Mana, SpellChoices, SpellCooldowns,
 };
 use leafwing_input_manager::action_state::ActionState;
 
 use crate::{events::PlayerAction, player::Player};
 
+/// How far away auto-aim will look for targets.
+const AUTO_AIM_RANGE: f32 = 250.0;
+
 pub(super) struct PlayerControlsPlugin;
 
 impl Plugin for PlayerControlsPlugin {
@@ -26,12 +31,18 @@ impl Plugin for PlayerControlsPlugin {
             )
                 .run_if(in_state(AppState::InGame)),
         );
+        app.add_systems(
+            Update,
+            (player_auto_aim_system, player_auto_cast_system)
+                .run_if(in_state(AppState::InGame).and_then(not(in_state(Overlay::Settings)))),
+        );
     }
 }
 
 /// System to handle player casting primary spell
 fn player_cast_primary_spell_system(
     mut ew_cast_spell: EventWriter<CastSpell>,
+    mut ew_stop_channeling: EventWriter<StopChanneling>,
     spell_choices: Res<SpellChoices>,
     query: Query<&ActionState<PlayerAction>, With<Player>>,
 ) {
@@ -47,12 +58,18 @@ fn player_cast_primary_spell_system(
         } else {
             tracing::warn!("No primary spell selected");
         }
+    } else if action_state.just_released(PlayerAction::CastPrimary) {
+        // Releasing the button stops channeling the spell
+        if let Some(spell_id) = spell_choices.primary.clone() {
+            ew_stop_channeling.send(StopChanneling(spell_id));
+        }
     }
 }
 
 /// System to handle player casting secondary spell
 fn player_cast_secondary_spell_system(
     mut ew_cast_spell: EventWriter<CastSpell>,
+    mut ew_stop_channeling: EventWriter<StopChanneling>,
     spell_choices: Res<SpellChoices>,
     query: Query<&ActionState<PlayerAction>, With<Player>>,
 ) {
@@ -68,12 +85,18 @@ fn player_cast_secondary_spell_system(
         } else {
             tracing::warn!("No secondary spell selected");
         }
+    } else if action_state.just_released(PlayerAction::CastSecondary) {
+        // Releasing the button stops channeling the spell
+        if let Some(spell_id) = spell_choices.secondary.clone() {
+            ew_stop_channeling.send(StopChanneling(spell_id));
+        }
     }
 }
 
 /// System to handle player casting defensive spell
 fn player_cast_defensive_spell_system(
     mut ew_cast_spell: EventWriter<CastSpell>,
+    mut ew_stop_channeling: EventWriter<StopChanneling>,
     spell_choices: Res<SpellChoices>,
     query: Query<&ActionState<PlayerAction>, With<Player>>,
 ) {
@@ -89,12 +112,18 @@ fn player_cast_defensive_spell_system(
         } else {
             tracing::warn!("No defensive spell selected");
         }
+    } else if action_state.just_released(PlayerAction::CastDefensive) {
+        // Releasing the button stops channeling the spell
+        if let Some(spell_id) = spell_choices.defensive.clone() {
+            ew_stop_channeling.send(StopChanneling(spell_id));
+        }
     }
 }
 
 /// System to handle player casting ultimate spell
 fn player_cast_ultimate_spell_system(
     mut ew_cast_spell: EventWriter<CastSpell>,
+    mut ew_stop_channeling: EventWriter<StopChanneling>,
     spell_choices: Res<SpellChoices>,
     query: Query<&ActionState<PlayerAction>, With<Player>>,
 ) {
@@ -110,6 +139,11 @@ fn player_cast_ultimate_spell_system(
         } else {
             tracing::warn!("No ultimate spell selected");
         }
+    } else if action_state.just_released(PlayerAction::CastUltimate) {
+        // Releasing the button stops channeling the spell
+        if let Some(spell_id) = spell_choices.ultimate.clone() {
+            ew_stop_channeling.send(StopChanneling(spell_id));
+        }
     }
 }
 
@@ -150,6 +184,77 @@ fn player_toggle_auto_system(
     }
 }
 
+/// Pick the auto-aim target when auto-aim is on (and clear it when it's off)
+fn player_auto_aim_system(
+    gameplay_settings: Res<GameplaySettings>,
+    player_query: Query<&Transform, (With<Player>, With<Health>)>,
+    hostile_query: Query<(Entity, &Transform, &Health, Option<&Faction>), Without<Player>>,
+    mut auto_aim_target: ResMut<AutoAimTarget>,
+) {
+    if !gameplay_settings.auto_aim {
+        if auto_aim_target.entity.is_some() {
+            auto_aim_target.set(None);
+        }
+        return;
+    }
+
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    let candidates = hostile_query
+        .iter()
+        .filter(|(_, _, health, faction)| {
+            !health.value.is_empty() && !faction.copied().unwrap_or_default().is_friendly()
+        })
+        .map(|(entity, transform, health, _)| AutoAimCandidate {
+            entity,
+            position: transform.translation.truncate(),
+            health: health.value.remaining(),
+        });
+    auto_aim_target.set(gameplay_settings.auto_aim_rule.pick(
+        player_transform.translation.truncate(),
+        AUTO_AIM_RANGE,
+        candidates,
+    ));
+}
+
+/// Cast every equipped spell as soon as it's off cooldown and affordable when auto-cast is on
+fn player_auto_cast_system(
+    mut ew_cast_spell: EventWriter<CastSpell>,
+    gameplay_settings: Res<GameplaySettings>,
+    spell_choices: Res<SpellChoices>,
+    game_data: Res<GameData>,
+    query: Query<(&SpellCooldowns, &Mana), With<Player>>,
+) {
+    if !gameplay_settings.auto_cast {
+        return;
+    }
+
+    let Ok((cooldowns, mana)) = query.get_single() else {
+        return;
+    };
+
+    for spell_id in [
+        &spell_choices.primary,
+        &spell_choices.secondary,
+        &spell_choices.defensive,
+        &spell_choices.ultimate,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let Some(spell) = game_data.spells.get(spell_id) else {
+            continue;
+        };
+        let affordable =
+            u32::try_from(spell.mana_cost).is_ok_and(|cost| mana.value.current >= cost);
+        if affordable && cooldowns.is_ready(spell_id) {
+            ew_cast_spell.send(CastSpell(spell_id.clone()));
+        }
+    }
+}
+
 /// Handle player pressing pause button
 fn player_press_pause_system(
     query: Query<&ActionState<PlayerAction>, With<Player>>,
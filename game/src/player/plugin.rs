@@ -4,7 +4,7 @@ use game_library::{state::Game, Health, Xp};
 use super::{
     animation,
     avatar::{self, PlayerAvatar},
-    menu_control, movement,
+    menu_control, movement, perks,
     player_control::PlayerControlsPlugin,
     player_creation,
 };
@@ -28,6 +28,7 @@ impl Plugin for PlayerPlugin {
                 (
                     movement::player_movement_controls,
                     movement::update_player_z_index,
+                    perks::apply_perks,
                     animation::advance_animation_timer,
                     animation::set_casting_animation,
                     animation::update_avatar_animation,
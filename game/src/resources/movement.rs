@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use game_library::{
     state::{Game, Overlay},
-    Acceleration,
+    Acceleration, MaxSpeed,
 };
 
 /// Plugin that makes moving things move
@@ -18,9 +18,13 @@ impl Plugin for MovementPlugin {
     }
 }
 
-/// System that updates the velocity of moving things
-fn update_velocity(mut query: Query<(&mut Velocity, &Acceleration)>, time: Res<Time>) {
-    for (mut velocity, acceleration) in &mut query {
-        velocity.linvel += acceleration.value * time.delta_seconds();
+/// System that updates the velocity of moving things (up to their max speed, if they have one)
+fn update_velocity(
+    mut query: Query<(&mut Velocity, &Acceleration, Option<&MaxSpeed>)>,
+    time: Res<Time>,
+) {
+    for (mut velocity, acceleration, max_speed) in &mut query {
+        let linvel = velocity.linvel + acceleration.value * time.delta_seconds();
+        velocity.linvel = max_speed.map_or(linvel, |max_speed| max_speed.clamp(linvel));
     }
 }
@@ -7,7 +7,8 @@ use game_library::{
     progress_bar::ProgressBarPlugin,
     settings::SettingsPlugin,
     state::{AppState, Game, Save, Settings},
-    CursorPosition, Health, Mana, SpellChoices, Xp,
+    AutoAimTarget, CursorPosition, Health, Mana, PerkState, Skills, SpellChoices,
+    SpellModifierChoices, UnlockedSpells, Xp,
 };
 
 use crate::{app_systems, resources::buttons};
@@ -64,8 +65,18 @@ impl Plugin for ElementalistResourcesPlugin {
         app
             // The cursor position resource, used to aim spells or know cursor coordinates easily
             .insert_resource(CursorPosition::default())
+            // The target auto-aim is aiming at (used instead of the cursor position when auto-aim is on)
+            .insert_resource(AutoAimTarget::default())
             // The player's spell choices
             .insert_resource(SpellChoices::default())
+            // The spell modifiers attached to each of the player's spell slots
+            .insert_resource(SpellModifierChoices::default())
+            // The player's skills (which gate which spells can be unlocked)
+            .insert_resource(Skills::default())
+            // The spells the player has unlocked (only these can be chosen)
+            .insert_resource(UnlockedSpells::default())
+            // The perks the player has allocated in their skills' perk trees
+            .insert_resource(PerkState::default())
             // The font resource has handles to the fonts used in the game to save loading assets constantly
             // and to easily allow the user to change the font (e.g. for accessibility)
             .add_event::<ChangeFont>()
@@ -27,6 +27,8 @@ pub(super) enum ButtonAction {
     ToggleAutoCast,
     /// Toggle Auto-Aim
     ToggleAutoAim,
+    /// Change how Auto-Aim picks its target
+    RotateAutoAimRule,
     /// Change the font family
     RotateFontFamily,
     /// Increment the main volume
@@ -91,6 +93,11 @@ pub fn menu_actions(
                         setting: IndividualSetting::AutoAim,
                     });
                 }
+                ButtonAction::RotateAutoAimRule => {
+                    ew_change_setting.send(ChangeSetting {
+                        setting: IndividualSetting::AutoAimRule,
+                    });
+                }
                 ButtonAction::RotateFontFamily => {
                     ew_change_setting.send(ChangeSetting {
                         setting: IndividualSetting::FontFamily,
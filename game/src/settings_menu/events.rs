@@ -11,6 +11,8 @@ pub(super) enum IndividualSetting {
     AutoCast,
     /// Change the auto-aim setting.
     AutoAim,
+    /// Change how auto-aim picks its target.
+    AutoAimRule,
     /// Change the main volume.
     MainVolume,
     /// Change the music volume.
@@ -65,6 +65,31 @@ fn show_gameplay_settings(
                                 CurrentAutoAimStateText,
                             ));
                         });
+                    // Auto-aim target button (as a row with a label and a button)
+                    menu_buttons
+                        .spawn(style_prefab::settings_menu_button_row_node_bundle())
+                        .with_children(|row| {
+                            // Button for auto-aim target
+                            row.spawn((
+                                style_prefab::menu_button_bundle(),
+                                ButtonAction::RotateAutoAimRule,
+                                SettingsMenuButton,
+                            ))
+                            .with_children(|button| {
+                                button.spawn(style_prefab::menu_button_text(
+                                    "Auto-Aim Target",
+                                    fonts.interface_font.clone(),
+                                ));
+                            });
+                            // Text for auto-aim target
+                            row.spawn((
+                                style_prefab::settings_menu_info_text_bundle(
+                                    format!("{}", gameplay_settings.auto_aim_rule).as_str(),
+                                    fonts.main_font.clone(),
+                                ),
+                                CurrentAutoAimRuleText,
+                            ));
+                        });
                     // Auto-cast button (as a row with a label and a button)
                     menu_buttons
                         .spawn(style_prefab::settings_menu_button_row_node_bundle())
@@ -118,6 +143,7 @@ impl Plugin for GameplaySettingsMenuPlugin {
                 handle_gameplay_setting_changes,
                 (
                     update_current_auto_aim_state_text,
+                    update_current_auto_aim_rule_text,
                     update_current_auto_cast_state_text,
                 ),
             )
@@ -148,6 +174,11 @@ fn handle_gameplay_setting_changes(
                 // Alert the system that the font has changed (to flush settings to disk)
                 ew_setting_changed.send(SettingChanged(SettingCategory::Gameplay));
             }
+            IndividualSetting::AutoAimRule => {
+                gameplay_settings.auto_aim_rule = gameplay_settings.auto_aim_rule.next();
+                // Alert the system that the setting has changed (to flush settings to disk)
+                ew_setting_changed.send(SettingChanged(SettingCategory::Gameplay));
+            }
             _ => {}
         }
     }
@@ -156,6 +187,9 @@ fn handle_gameplay_setting_changes(
 #[derive(Component)]
 struct CurrentAutoAimStateText;
 
+#[derive(Component)]
+struct CurrentAutoAimRuleText;
+
 #[derive(Component)]
 struct CurrentAutoCastStateText;
 
@@ -169,6 +203,16 @@ fn update_current_auto_aim_state_text(
     }
 }
 
+/// System to update the text for the current auto-aim target rule.
+fn update_current_auto_aim_rule_text(
+    mut text_query: Query<(&mut Text, &CurrentAutoAimRuleText)>,
+    gameplay_settings: Res<GameplaySettings>,
+) {
+    for (mut text, _tag) in &mut text_query {
+        text.sections[0].value = format!("{}", gameplay_settings.auto_aim_rule);
+    }
+}
+
 /// System to update the text for the current auto-cast state.
 fn update_current_auto_cast_state_text(
     mut text_query: Query<(&mut Text, &CurrentAutoCastStateText)>,
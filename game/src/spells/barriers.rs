@@ -0,0 +1,81 @@
+//! Barrier spells raise walls and shields which destroy, absorb or reflect hostile spells (see
+//! `spell_collisions` for what happens when a spell touches one).
+use bevy::prelude::*;
+use game_library::{data_loader::storage::GameData, Barrier, Shield};
+
+/// Sent when a spell raises a barrier shielding its caster.
+#[derive(Event, Debug, Clone)]
+pub struct RaiseBarrier {
+    /// The unique id of the barrier spell.
+    pub spell_id: String,
+    /// The entity which cast the spell (and is shielded by it).
+    pub owner: Entity,
+}
+
+/// Shields the casters of barrier spells cast on themselves.
+///
+/// A new barrier replaces the one the caster already had, but their shield pools add up.
+pub(super) fn raise_barriers(
+    mut commands: Commands,
+    mut er_barrier: EventReader<RaiseBarrier>,
+    mut shields: Query<&mut Shield>,
+    game_data: Res<GameData>,
+) {
+    for event in er_barrier.read() {
+        let Some(barrier) = game_data
+            .spells
+            .get(&event.spell_id)
+            .and_then(|spell| spell.barrier.as_ref())
+        else {
+            tracing::error!("raise_barriers: no barrier data for {}", event.spell_id);
+            continue;
+        };
+        let Some(mut owner) = commands.get_entity(event.owner) else {
+            continue;
+        };
+        owner.insert(Barrier::shielding(
+            event.owner,
+            &event.spell_id,
+            barrier.clone(),
+        ));
+        add_shield(&mut commands, &mut shields, event.owner, barrier.shield);
+    }
+}
+
+/// Adds `amount` to the shield pool of `owner`, giving them one if they don't have it yet.
+pub(super) fn add_shield(
+    commands: &mut Commands,
+    shields: &mut Query<&mut Shield>,
+    owner: Entity,
+    amount: u32,
+) {
+    if amount == 0 {
+        return;
+    }
+    if let Ok(mut shield) = shields.get_mut(owner) {
+        shield.add(amount);
+    } else if let Some(mut owner) = commands.get_entity(owner) {
+        owner.insert(Shield::new(amount));
+    }
+}
+
+/// Counts down the barriers shielding characters, removing them when they run out, and removes
+/// shield pools which have been used up.
+pub(super) fn tick_barriers(
+    mut commands: Commands,
+    mut barriers: Query<(Entity, &mut Barrier)>,
+    shields: Query<(Entity, &Shield)>,
+    time: Res<Time>,
+) {
+    for (entity, mut barrier) in &mut barriers {
+        barrier.tick(time.delta_seconds());
+        if barrier.is_expired() {
+            commands.entity(entity).remove::<Barrier>();
+        }
+    }
+    for (entity, shield) in &shields {
+        if shield.is_empty() {
+            commands.entity(entity).remove::<Shield>();
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Beam spells fire a continuous beam from the caster instead of a projectile.
+//!
+//! The beam is raycast from the caster toward where they aim every frame, stopping at the first
+//! thing which blocks it, and hits whatever it touches every tick.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    collision_groups,
+    enums::{CastType, Faction, ParticleAttachment},
+    events::{ParticleAnchor, SpawnSpellParticles, SpellImpact, StopChanneling},
+    AutoAimTarget, Beam, BeamData, CursorPosition, Health, Layer, SpellCaster, SpellData,
+    SpellLifetime, SpellSnapshot, Structure,
+};
+
+use super::components::SpellEntity;
+
+/// The size (in pixels) of the tiles in the spell sprite tilesets. The beam's sprite is stretched
+/// from this to the length and width of the beam.
+const BEAM_SPRITE_SIZE: f32 = 16.0;
+
+/// Spawns the beam entity for a beam spell cast by `caster`, returning it.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn spawn_beam(
+    commands: &mut Commands,
+    texture_atlas: Handle<TextureAtlas>,
+    spell: &SpellData,
+    beam: &BeamData,
+    snapshot: SpellSnapshot,
+    caster: Entity,
+    origin: Vec3,
+    direction: Vec2,
+) -> Entity {
+    let channeled = spell.cast_type == CastType::Channel;
+    commands
+        .spawn((
+            Beam::new(beam, direction, snapshot.range, channeled),
+            SpellLifetime::new(snapshot.lifetime),
+            SpriteSheetBundle {
+                texture_atlas,
+                sprite: spell.texture_atlas_index(),
+                // the beam is stretched to its length once it has been cast (see `update_beams`)
+                transform: Transform::from_translation(origin - Vec3::new(0.0, 0.0, 0.1))
+                    .with_scale(Vec3::ZERO),
+                ..Default::default()
+            },
+            SpellEntity,
+            SpellCaster(caster),
+            snapshot,
+            Layer::Foreground(10),
+        ))
+        .id()
+}
+
+/// Re-aims and re-casts beams every frame, hitting what they touch every tick.
+///
+/// Beams follow their caster and where they aim, stop at the first character they can affect,
+/// structure of the other side or obstacle, and are stretched (along with their particles) to
+/// reach it. Channeled beams end when their cast is released, and beams whose caster is gone end
+/// straight away.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn update_beams(
+    mut commands: Commands,
+    mut er_stop: EventReader<StopChanneling>,
+    mut beams: Query<(
+        Entity,
+        &mut Beam,
+        &mut Transform,
+        &SpellSnapshot,
+        &SpellCaster,
+    )>,
+    casters: Query<&Transform, Without<Beam>>,
+    characters: Query<Option<&Faction>, (With<Health>, Without<Structure>)>,
+    structures: Query<Option<&Faction>, With<Structure>>,
+    factions: Query<&Faction>,
+    rapier_context: Res<RapierContext>,
+    cursor_position: Res<CursorPosition>,
+    auto_aim_target: Res<AutoAimTarget>,
+    time: Res<Time>,
+    mut ew_impact: EventWriter<SpellImpact>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+) {
+    let stopped: Vec<String> = er_stop
+        .read()
+        .map(|StopChanneling(spell_id)| spell_id.clone())
+        .collect();
+    // beams are only cast by the player, so they follow the player's aim
+    let aim_position = auto_aim_target.position.unwrap_or(cursor_position.position);
+
+    for (entity, mut beam, mut transform, snapshot, caster) in &mut beams {
+        let Ok(caster_transform) = casters.get(caster.0) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        if beam.channeled && stopped.contains(&snapshot.spell_id) {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let origin = caster_transform.translation.truncate();
+        beam.aim(aim_position - origin);
+
+        // characters the spell can't affect and structures of the caster's side don't block it
+        let caster_faction = factions.get(caster.0).copied().unwrap_or_default();
+        let blocks = |other: Entity| {
+            if other == caster.0 {
+                false
+            } else if let Ok(faction) = structures.get(other) {
+                faction
+                    .copied()
+                    .unwrap_or_default()
+                    .is_hostile_to(caster_faction)
+            } else if let Ok(faction) = characters.get(other) {
+                caster_faction.can_affect(faction.copied().unwrap_or_default(), &snapshot.target)
+            } else {
+                true
+            }
+        };
+        let filter = QueryFilter::new()
+            .exclude_sensors()
+            .groups(collision_groups::spell(caster_faction, &snapshot.target))
+            .predicate(&blocks);
+        let blocker = rapier_context.cast_ray(origin, beam.direction, beam.range, true, filter);
+        beam.set_blocker(blocker);
+
+        // the beam's sprite (and its particles, which are its children) are stretched between
+        // the caster and the end of the beam
+        transform.translation =
+            ((origin + beam.end(origin)) / 2.0).extend(caster_transform.translation.z - 0.1);
+        transform.rotation = Quat::from_rotation_z(beam.direction.y.atan2(beam.direction.x));
+        transform.scale = Vec3::new(
+            beam.length / BEAM_SPRITE_SIZE,
+            beam.width / BEAM_SPRITE_SIZE,
+            1.0,
+        );
+
+        let ticks = beam.tick(time.delta_seconds());
+        let Some(target) = beam.target else {
+            continue;
+        };
+        if ticks == 0 || !(characters.contains(target) || structures.contains(target)) {
+            continue;
+        }
+        let point = beam.end(origin);
+        for _ in 0..ticks {
+            ew_impact.send(SpellImpact {
+                spell: entity,
+                spell_id: snapshot.spell_id.clone(),
+                caster: caster.0,
+                target,
+                point,
+                damage: snapshot.damage,
+                jump: 0,
+            });
+            // the impact does the damage and applies the debuffs (see `damage_hits`,
+            // `apply_spell_debuffs` and `damage_structures`)
+            if characters.contains(target) {
+                ew_spawn_particles.send(SpawnSpellParticles {
+                    spell_id: snapshot.spell_id.clone(),
+                    attachment: ParticleAttachment::Target,
+                    anchor: ParticleAnchor::Entity(target),
+                });
+            }
+        }
+    }
+}
@@ -1,29 +1,75 @@
 //! This system listens for the `CastSpell` event and spawns a spell entity based on the spell identifier.
+//!
+//! Spells cast on the caster apply their buffs when cast, and spells apply their debuffs to the
+//! characters they hit (see [`apply_spell_debuffs`]).
 use bevy::prelude::*;
-use bevy_hanabi::{ParticleEffect, ParticleEffectBundle};
 use bevy_rapier2d::prelude::*;
 use game_library::{
-    data_loader::storage::GameData, enums::ParticleAttachment, events::CastSpell, math,
-    Acceleration, CursorPosition, InternalId, Layer, MovementBundle, SpellBundle, SpellLifetime,
+    data_loader::storage::GameData,
+    enums::{CastCategory, CastSlot, ParticleAttachment},
+    events::{ApplyStatEffect, CastSpell, ParticleAnchor, SpawnSpellParticles, SpellImpact},
+    ground_target, Acceleration, AutoAimTarget, CursorPosition, Health, InternalId, Layer, Mana,
+    MaxSpeed, MovementBundle, Polymorphed, Skills, SpellBundle, SpellCaster, SpellCooldowns,
+    SpellData, SpellHits, SpellLifetime, SpellModifier, SpellModifierChoices, SpellMotion,
+    SpellSnapshot, SpellStatModifier, SpellStatModifiers, SpellTravel, StatBundle, Structure,
 };
 
 use crate::player::Player;
 
-use super::components::SpellEntity;
+use super::{
+    barriers::RaiseBarrier, beams::spawn_beam, components::SpellEntity, polymorph::Polymorph,
+    structures::PlaceStructure, summon::SpawnSummons, zones::PlaceZone,
+};
 
 const SPELL_SPRITE_SCALE: f32 = 0.5;
 const SPELL_SPEED_MULTIPLIER: f32 = 100.0;
 const SPELL_ACCELERATION: f32 = 5.0;
+/// How much faster than its launch speed a spell can get by accelerating
+const SPELL_MAX_SPEED_FACTOR: f32 = 1.5;
 
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub(super) fn cast_spells(
     mut commands: Commands,
     mut event_reader: EventReader<CastSpell>,
-    query: Query<&Transform, With<Player>>,
+    mut ew_apply_effect: EventWriter<ApplyStatEffect>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    mut ew_summon: EventWriter<SpawnSummons>,
+    mut ew_polymorph: EventWriter<Polymorph>,
+    mut ew_structure: EventWriter<PlaceStructure>,
+    mut ew_zone: EventWriter<PlaceZone>,
+    mut ew_barrier: EventWriter<RaiseBarrier>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&StatBundle>,
+            Option<&SpellStatModifiers>,
+            Option<&KinematicCharacterControllerOutput>,
+            Option<&mut SpellCooldowns>,
+            Option<&mut Mana>,
+            Option<&Polymorphed>,
+        ),
+        With<Player>,
+    >,
     cursor_position: Res<CursorPosition>,
+    auto_aim_target: Res<AutoAimTarget>,
+    modifier_choices: Res<SpellModifierChoices>,
+    mut skills: ResMut<Skills>,
     game_data: Res<GameData>,
+    time: Res<Time>,
 ) {
     for CastSpell(spell_identifier) in event_reader.read() {
-        let Ok(player_transform) = query.get_single() else {
+        let Ok((
+            player,
+            player_transform,
+            player_stats,
+            spell_modifiers,
+            controller_output,
+            cooldowns,
+            mana,
+            polymorphed,
+        )) = query.get_single_mut()
+        else {
             tracing::error!("cast_spells: No player found, not spawning a spell");
             return;
         };
@@ -33,80 +79,427 @@ pub(super) fn cast_spells(
             continue;
         };
 
-        let Some(texture_atlas) = game_data.tile_atlas.get(&spell.sprite_tileset) else {
-            tracing::error!(
-                "cast_spells: No texture atlas found for {} (spell:{})",
-                spell.sprite_tileset,
-                spell.get_internal_id()
+        // Polymorphed casters can only cast the spells their form allows
+        if polymorphed.is_some_and(|polymorphed| !polymorphed.allows_spell(spell_identifier)) {
+            tracing::debug!("cast_spells: {spell_identifier} can't be cast in this form");
+            continue;
+        }
+
+        // Summoning spells spawn allies (see `spawn_summons`), polymorph spells transform the
+        // caster (see `apply_polymorphs`), and structure and zone spells place obstacles and
+        // areas (see `place_structures` and `place_zones`) instead of launching a projectile
+        let texture_atlas = if spell.launches_projectile() || spell.beam.is_some() {
+            let Some(texture_atlas) = game_data.tile_atlas.get(&spell.sprite_tileset) else {
+                tracing::error!(
+                    "cast_spells: No texture atlas found for {} (spell:{})",
+                    spell.sprite_tileset,
+                    spell.get_internal_id()
+                );
+                continue;
+            };
+            Some(texture_atlas)
+        } else {
+            if spell.cast_category == CastCategory::Summon && spell.summon.is_none() {
+                tracing::error!("cast_spells: {spell_identifier} has no summon data");
+                continue;
+            }
+            None
+        };
+
+        // Spells can't be cast while on cooldown or without enough mana
+        if !pay_for_spell(spell_identifier, spell, cooldowns, mana) {
+            continue;
+        }
+
+        // Casting a spell trains its skill
+        skills.add_xp(spell.skill(), spell.cast_xp);
+
+        // Spells cast on the caster apply their buffs right away
+        if spell.cast_category == CastCategory::OnSelf {
+            for effect in &spell.buffs {
+                ew_apply_effect.send(ApplyStatEffect {
+                    target: player,
+                    effect: effect.clone(),
+                });
+            }
+        }
+        if spell.shields_caster() {
+            ew_barrier.send(RaiseBarrier {
+                spell_id: spell_identifier.clone(),
+                owner: player,
+            });
+        }
+        if spell.polymorph.is_some() {
+            ew_polymorph.send(Polymorph {
+                spell_id: spell_identifier.clone(),
+                target: player,
+            });
+        }
+
+        // To know where to "aim" the spell, we need to calculate the slope between the player and the
+        // cursor (or the auto-aim target, when auto-aim has one)
+        let aim_position = auto_aim_target.position.unwrap_or(cursor_position.position);
+        let aim_offset = aim_position - player_transform.translation.truncate();
+        let slope_vec = aim_offset.normalize_or_zero();
+
+        // The player's stats and spell modifiers (and the modifiers attached to the slot the
+        // spell is cast from) affect the spell as it's cast
+        let slot_modifiers = attached_modifiers(&game_data, &modifier_choices, spell.cast_slot);
+        let slot_stat_modifiers: Vec<SpellStatModifier> = slot_modifiers
+            .iter()
+            .map(|modifier| modifier.stat_modifier())
+            .collect();
+        let snapshot = SpellSnapshot::new(
+            spell_identifier,
+            spell,
+            player_stats,
+            spell_modifiers
+                .into_iter()
+                .flat_map(SpellStatModifiers::iter)
+                .chain(&slot_stat_modifiers),
+        )
+        .with_modifiers(spell, slot_modifiers);
+
+        // The spell inherits the player's velocity (the player is moved by a kinematic controller,
+        // so their velocity is the distance they moved over the last frame)
+        let player_velocity = match controller_output {
+            Some(output) if time.delta_seconds() > 0.0 => {
+                output.effective_translation / time.delta_seconds()
+            }
+            _ => Vec2::ZERO,
+        };
+
+        // spawn the particles for casting the spell
+        for attachment in [ParticleAttachment::Cast, ParticleAttachment::Caster] {
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: spell_identifier.clone(),
+                attachment,
+                anchor: ParticleAnchor::Entity(player),
+            });
+        }
+
+        let Some(texture_atlas) = texture_atlas else {
+            // ground spells are placed where they're aimed (up to their range)
+            let ground_position = ground_target(
+                player_transform.translation.truncate(),
+                aim_position,
+                snapshot.range,
+            )
+            .extend(player_transform.translation.z);
+            if spell.cast_category == CastCategory::Summon {
+                ew_summon.send(SpawnSummons {
+                    spell_id: spell_identifier.clone(),
+                    owner: player,
+                    origin: player_transform.translation,
+                });
+            } else if spell.structure.is_some() {
+                ew_structure.send(PlaceStructure {
+                    spell_id: spell_identifier.clone(),
+                    owner: player,
+                    position: ground_position,
+                });
+            } else if spell.zone.is_some() {
+                ew_zone.send(PlaceZone {
+                    spell_id: spell_identifier.clone(),
+                    owner: player,
+                    position: ground_position,
+                });
+            }
+            continue;
+        };
+
+        // beam spells fire a single beam which follows the caster (see `update_beams`)
+        if let Some(beam) = &spell.beam {
+            let beam = spawn_beam(
+                &mut commands,
+                texture_atlas.clone(),
+                spell,
+                beam,
+                snapshot,
+                player,
+                player_transform.translation,
+                slope_vec,
+            );
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: spell_identifier.clone(),
+                attachment: ParticleAttachment::Projectile,
+                anchor: ParticleAnchor::Entity(beam),
+            });
+            continue;
+        }
+
+        for direction in snapshot.projectile_directions(slope_vec) {
+            let launch = ProjectileLaunch {
+                caster: player,
+                origin: player_transform.translation,
+                direction,
+                aim_distance: aim_offset.length(),
+                inherited_velocity: player_velocity,
+                already_hit: Vec::new(),
+            };
+            let spell_projectile = spawn_spell_projectile(
+                &mut commands,
+                texture_atlas.clone(),
+                spell,
+                snapshot.clone(),
+                launch,
             );
+
+            // spawn the particles for the projectile in flight
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: spell_identifier.clone(),
+                attachment: ParticleAttachment::Projectile,
+                anchor: ParticleAnchor::Entity(spell_projectile),
+            });
+        }
+    }
+}
+
+/// Applies the debuffs of spells to the characters they hit (every hit, so stacking debuffs stack
+/// with each tick of a beam or jump of a chain).
+pub(super) fn apply_spell_debuffs(
+    mut er_impact: EventReader<SpellImpact>,
+    characters: Query<(), (With<Health>, Without<Structure>)>,
+    game_data: Res<GameData>,
+    mut ew_apply_effect: EventWriter<ApplyStatEffect>,
+) {
+    for impact in er_impact.read() {
+        if !characters.contains(impact.target) {
+            continue;
+        }
+        let Some(spell) = game_data.spells.get(&impact.spell_id) else {
             continue;
         };
+        for effect in &spell.debuffs {
+            ew_apply_effect.send(ApplyStatEffect {
+                target: impact.target,
+                effect: effect.clone(),
+            });
+        }
+    }
+}
+
+/// The spell modifiers attached to a cast slot, in the order they were attached.
+fn attached_modifiers<'a>(
+    game_data: &'a GameData,
+    modifier_choices: &SpellModifierChoices,
+    slot: CastSlot,
+) -> Vec<&'a SpellModifier> {
+    modifier_choices
+        .get(slot)
+        .iter()
+        .filter_map(|modifier_id| {
+            let modifier = game_data.spell_modifiers.get(modifier_id);
+            if modifier.is_none() {
+                tracing::warn!("cast_spells: 404 spell modifier {modifier_id} not found");
+            }
+            modifier
+        })
+        .collect()
+}
+
+/// Where and in which direction a spell projectile is launched.
+pub struct ProjectileLaunch {
+    /// The entity casting the spell.
+    pub caster: Entity,
+    /// Where the spell is launched from.
+    pub origin: Vec3,
+    /// The direction the spell is launched in (normalized).
+    pub direction: Vec2,
+    /// How far away the spell was aimed (arcing spells land there).
+    pub aim_distance: f32,
+    /// Velocity the spell inherits from its caster.
+    pub inherited_velocity: Vec2,
+    /// Targets the spell counts as already hit (e.g. the target a split spell split from).
+    pub already_hit: Vec<Entity>,
+}
 
-        // To know where to "aim" the spell, we need to calculate the slope between the player and the cursor
-        let slope_vec = math::slope_vec(player_transform, &cursor_position);
-
-        // Todo: include the player's velocity in the spell's velocity
-        // Todo: include the player's stats to effect the spell (damage, speed, etc)
-        // Todo: figure out how we will track cooldowns. Maybe a resource?
-
-        let spell_projectile = commands
-            .spawn((
-                SpellBundle {
-                    lifetime: SpellLifetime::new(spell.duration),
-                    movement: MovementBundle {
-                        velocity: Velocity {
-                            linvel: slope_vec * (spell.speed * SPELL_SPEED_MULTIPLIER),
-                            ..default()
-                        },
-                        acceleration: Acceleration::new(slope_vec * SPELL_ACCELERATION),
+/// Spawns the projectile entity for a spell, returning it.
+pub fn spawn_spell_projectile(
+    commands: &mut Commands,
+    texture_atlas: Handle<TextureAtlas>,
+    spell: &SpellData,
+    snapshot: SpellSnapshot,
+    launch: ProjectileLaunch,
+) -> Entity {
+    let speed = snapshot.speed * SPELL_SPEED_MULTIPLIER;
+    let launch_velocity = launch.direction * speed + launch.inherited_velocity;
+    let motion = SpellMotion::new(
+        snapshot.trajectory,
+        launch.origin.truncate(),
+        launch.direction,
+        speed,
+        launch.aim_distance,
+        snapshot.range,
+    );
+    // Only spells which accelerate are capped by a max speed (other trajectories set their
+    // velocity directly) and only some trajectories run out of range
+    let acceleration = if motion.trajectory.is_accelerated() {
+        launch.direction * SPELL_ACCELERATION
+    } else {
+        Vec2::ZERO
+    };
+    let max_range = if motion.trajectory.is_range_limited() {
+        snapshot.range
+    } else {
+        0.0
+    };
+
+    let projectile = commands
+        .spawn((
+            SpellBundle {
+                lifetime: SpellLifetime::new(snapshot.lifetime),
+                movement: MovementBundle {
+                    velocity: Velocity {
+                        linvel: launch_velocity,
+                        ..default()
                     },
-                    sprite: SpriteSheetBundle {
-                        texture_atlas: texture_atlas.clone(),
-                        sprite: spell.texture_atlas_index(),
-                        transform: Transform {
-                            translation: player_transform.translation - Vec3::new(0.0, 0.0, 0.1),
-                            rotation: Quat::from_rotation_z(slope_vec.y.atan2(slope_vec.x)),
-                            scale: Vec3::splat(SPELL_SPRITE_SCALE),
-                        },
-                        ..Default::default()
+                    acceleration: Acceleration::new(acceleration),
+                },
+                sprite: SpriteSheetBundle {
+                    texture_atlas,
+                    sprite: spell.texture_atlas_index(),
+                    transform: Transform {
+                        translation: launch.origin - Vec3::new(0.0, 0.0, 0.1),
+                        rotation: Quat::from_rotation_z(
+                            launch.direction.y.atan2(launch.direction.x),
+                        ),
+                        scale: Vec3::splat(SPELL_SPRITE_SCALE * snapshot.scale),
                     },
+                    ..Default::default()
                 },
-                SpellEntity,
-                RigidBody::KinematicVelocityBased,
-                Collider::ball(4.0),
-                Layer::Foreground(10),
-            ))
-            .id();
-
-        // check for any particles that go on the projectile
-        let projectile_particles = spell
-            .particles
-            .iter()
-            .filter_map(|particle_link| {
-                if particle_link.attachment == ParticleAttachment::Projectile {
-                    tracing::info!("Spawning projectile particle {}", particle_link.particle_id);
-                    game_data.particles.get(&particle_link.particle_id)
-                } else {
-                    None
-                }
-            })
-            .map(|particle| {
-                commands
-                    .spawn(ParticleEffectBundle {
-                        effect: ParticleEffect::new(particle.clone()),
-                        transform: Transform {
-                            translation: Vec3::ZERO,
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    })
-                    .id()
-            })
-            .collect::<Vec<Entity>>();
-
-        // insert the particles into the spell entity
-        commands
-            .entity(spell_projectile)
-            .push_children(&projectile_particles);
+            },
+            SpellEntity,
+            SpellTravel::new(launch.origin.truncate(), max_range),
+            SpellCaster(launch.caster),
+            SpellHits {
+                hit: launch.already_hit,
+                ..SpellHits::new(snapshot.pierce, snapshot.bounces)
+            },
+            motion,
+            snapshot,
+            RigidBody::KinematicVelocityBased,
+            Collider::ball(4.0),
+            // spells are sensors which report when they start touching things (including
+            // kinematic characters and fixed obstacles, which rapier skips by default)
+            Sensor,
+            ActiveEvents::COLLISION_EVENTS,
+            ActiveCollisionTypes::all(),
+            Layer::Foreground(10),
+        ))
+        .id();
+    if motion.trajectory.is_accelerated() {
+        commands.entity(projectile).insert(MaxSpeed::new(
+            launch_velocity.length() * SPELL_MAX_SPEED_FACTOR,
+        ));
+    }
+    projectile
+}
+
+/// The stats of spell casters, which apply to the spells they trigger (see
+/// [`spawn_triggered_spell`]).
+pub type CasterStats<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Option<&'static StatBundle>,
+        Option<&'static SpellStatModifiers>,
+    ),
+>;
+
+/// A spell triggered by something other than casting (e.g. an elemental reaction or a destroyed
+/// structure).
+pub struct TriggeredSpell<'a> {
+    /// The unique id of the spell.
+    pub spell_id: &'a str,
+    /// The entity the spell belongs to (whose stats apply to it).
+    pub caster: Entity,
+    /// Where the spell is spawned.
+    pub origin: Vec3,
+    /// Targets the spell won't hit (e.g. the target of the reaction which triggered it).
+    pub already_hit: Vec<Entity>,
+}
+
+/// Spawns a triggered spell, with its caster's stats and spell stat modifiers applied. Arcing
+/// spells land at their full range.
+pub(super) fn spawn_triggered_spell(
+    commands: &mut Commands,
+    game_data: &GameData,
+    casters: &CasterStats,
+    triggered: TriggeredSpell,
+    ew_spawn_particles: &mut EventWriter<SpawnSpellParticles>,
+) {
+    let spell_id = triggered.spell_id;
+    let Some(spell) = game_data.spells.get(spell_id) else {
+        tracing::error!("spawn_triggered_spell: 404 {spell_id} not found");
+        return;
+    };
+    let Some(texture_atlas) = game_data.tile_atlas.get(&spell.sprite_tileset) else {
+        tracing::error!(
+            "spawn_triggered_spell: No texture atlas found for {} (spell:{spell_id})",
+            spell.sprite_tileset
+        );
+        return;
+    };
+
+    let (stats, modifiers) = casters.get(triggered.caster).unwrap_or_default();
+    let snapshot = SpellSnapshot::new(
+        spell_id,
+        spell,
+        stats,
+        modifiers.into_iter().flat_map(SpellStatModifiers::iter),
+    );
+    let launch = ProjectileLaunch {
+        caster: triggered.caster,
+        origin: triggered.origin,
+        direction: Vec2::X,
+        aim_distance: snapshot.range,
+        inherited_velocity: Vec2::ZERO,
+        already_hit: triggered.already_hit,
+    };
+    let projectile =
+        spawn_spell_projectile(commands, texture_atlas.clone(), spell, snapshot, launch);
+    ew_spawn_particles.send(SpawnSpellParticles {
+        spell_id: spell_id.to_string(),
+        attachment: ParticleAttachment::Projectile,
+        anchor: ParticleAnchor::Entity(projectile),
+    });
+}
+
+/// Checks the spell is off cooldown and the caster has enough mana for it. If so, the mana is spent,
+/// the cooldown is started, and this returns true.
+fn pay_for_spell(
+    spell_id: &str,
+    spell: &SpellData,
+    cooldowns: Option<Mut<SpellCooldowns>>,
+    mana: Option<Mut<Mana>>,
+) -> bool {
+    if cooldowns
+        .as_ref()
+        .is_some_and(|cooldowns| !cooldowns.is_ready(spell_id))
+    {
+        tracing::debug!("cast_spells: {spell_id} is on cooldown");
+        return false;
+    }
+    if let Some(mut mana) = mana {
+        let Ok(mana_cost) = u32::try_from(spell.mana_cost) else {
+            return false;
+        };
+        if mana.value.current < mana_cost {
+            tracing::debug!("cast_spells: not enough mana for {spell_id}");
+            return false;
+        }
+        mana.value -= mana_cost;
+    }
+    if let Some(mut cooldowns) = cooldowns {
+        cooldowns.start(spell_id, spell.cooldown);
+    }
+    true
+}
+
+/// Counts down the cooldowns of the spells that have been cast.
+pub(super) fn tick_spell_cooldowns(time: Res<Time>, mut query: Query<&mut SpellCooldowns>) {
+    for mut cooldowns in &mut query {
+        cooldowns.tick(time.delta_seconds());
     }
 }
@@ -0,0 +1,102 @@
+//! Chaining spells jump from the character they hit to other targets nearby.
+use bevy::{ecs::event::ManualEventReader, prelude::*};
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    collision_groups,
+    data_loader::storage::GameData,
+    enums::{Faction, ParticleAttachment},
+    events::{ParticleAnchor, SpawnSpellParticles, SpellImpact},
+    Health, Structure,
+};
+
+/// Chains spells with [`game_library::ChainData`] from the characters they hit to the closest
+/// valid targets nearby, sending a [`SpellImpact`] for each jump.
+///
+/// The jumps are impacts like any other hit, so they cause elemental reactions, do damage (with
+/// the chain's falloff) and award experience. Jumps don't start new chains.
+pub(super) fn chain_spells(
+    mut impacts: ResMut<Events<SpellImpact>>,
+    mut impact_reader: Local<ManualEventReader<SpellImpact>>,
+    characters: Query<(&GlobalTransform, Option<&Faction>), (With<Health>, Without<Structure>)>,
+    factions: Query<&Faction>,
+    rapier_context: Res<RapierContext>,
+    game_data: Res<GameData>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+) {
+    let mut links = Vec::new();
+
+    for impact in impact_reader.read(&impacts) {
+        if impact.jump > 0 {
+            continue;
+        }
+        let Ok((first_transform, _)) = characters.get(impact.target) else {
+            continue;
+        };
+        let Some(spell) = game_data.spells.get(&impact.spell_id) else {
+            continue;
+        };
+        let Some(chain) = spell.chain else {
+            continue;
+        };
+
+        // reflected spells are on the side of whoever reflected them
+        let spell_faction = factions
+            .get(impact.spell)
+            .or_else(|_| factions.get(impact.caster))
+            .copied()
+            .unwrap_or_default();
+        let first = (impact.target, first_transform.translation().truncate());
+        let jump_area = Collider::ball(chain.jump_radius);
+        let chain_links = chain.links(first, |from| {
+            let mut candidates = Vec::new();
+            rapier_context.intersections_with_shape(
+                from,
+                0.0,
+                &jump_area,
+                QueryFilter::new()
+                    .exclude_sensors()
+                    .groups(collision_groups::spell(spell_faction, &spell.target)),
+                |entity| {
+                    if entity == impact.caster {
+                        return true;
+                    }
+                    if let Ok((transform, faction)) = characters.get(entity) {
+                        if spell_faction
+                            .can_affect(faction.copied().unwrap_or_default(), &spell.target)
+                        {
+                            candidates.push((entity, transform.translation().truncate()));
+                        }
+                    }
+                    true
+                },
+            );
+            candidates
+        });
+
+        for (jump, (target, point)) in (1..).zip(chain_links) {
+            links.push(SpellImpact {
+                spell: impact.spell,
+                spell_id: impact.spell_id.clone(),
+                caster: impact.caster,
+                target,
+                point,
+                damage: chain.jump_damage(impact.damage, jump),
+                jump,
+            });
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: impact.spell_id.clone(),
+                attachment: ParticleAttachment::Target,
+                anchor: ParticleAnchor::Entity(target),
+            });
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: impact.spell_id.clone(),
+                attachment: ParticleAttachment::Impact,
+                anchor: ParticleAnchor::Position(point),
+            });
+        }
+    }
+
+    for link in links {
+        impacts.send(link);
+    }
+}
@@ -1,17 +1,68 @@
 use bevy::prelude::*;
-use game_library::SpellLifetime;
+use game_library::{
+    enums::ParticleAttachment,
+    events::{ParticleAnchor, SpawnSpellParticles, SpellExpired, SpellExpiryReason},
+    SpellCaster, SpellLifetime, SpellMotion, SpellSnapshot, SpellTravel,
+};
 
-/// Despawns spells when their lifetime expires, and updates their lifetime
-pub fn despawn_expired_spells(
+/// Updates the lifetime and distance travelled of spells, and despawns them when either runs out
+/// (or when they reach the end of their trajectory).
+///
+/// A [`SpellExpired`] event is sent for every spell that expires (which detonates spells with a
+/// radius, see `detonate_expired_spells`), and its impact and ground particles are spawned where
+/// it expired.
+#[allow(clippy::type_complexity)]
+pub fn expire_spells(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut SpellLifetime)>,
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &mut SpellLifetime,
+        Option<&mut SpellTravel>,
+        Option<&SpellMotion>,
+        Option<&SpellSnapshot>,
+        Option<&SpellCaster>,
+    )>,
+    mut ew_expired: EventWriter<SpellExpired>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
 ) {
-    for (entity, mut lifetime) in &mut query {
+    for (entity, transform, mut lifetime, travel, motion, snapshot, caster) in &mut query {
+        let position = transform.translation.truncate();
         lifetime.update(time.delta_seconds());
-        if lifetime.is_expired() {
-            commands.entity(entity).despawn_recursive();
+
+        let out_of_range = travel.is_some_and(|mut travel| {
+            travel.update(position);
+            travel.is_out_of_range()
+        });
+
+        let reason = if lifetime.is_expired() {
+            SpellExpiryReason::Lifetime
+        } else if out_of_range {
+            SpellExpiryReason::Range
+        } else if motion.is_some_and(|motion| motion.finished) {
+            SpellExpiryReason::Arrived
+        } else {
+            continue;
+        };
+
+        let spell_id = snapshot.map_or_else(String::new, |s| s.spell_id.clone());
+        for attachment in [ParticleAttachment::Impact, ParticleAttachment::Ground] {
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: spell_id.clone(),
+                attachment,
+                anchor: ParticleAnchor::Position(position),
+            });
         }
+        ew_expired.send(SpellExpired {
+            spell: entity,
+            spell_id,
+            caster: caster.map_or(entity, |caster| caster.0),
+            position,
+            damage: snapshot.map_or(0.0, |snapshot| snapshot.damage),
+            reason,
+        });
+        commands.entity(entity).despawn_recursive();
     }
 }
 
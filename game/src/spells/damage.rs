@@ -0,0 +1,38 @@
+//! Applies damage and healing to the health of entities.
+use bevy::prelude::*;
+use game_library::{
+    events::{ApplyDamage, ApplyHealing},
+    Health,
+};
+
+/// Damages the targets of [`ApplyDamage`] events.
+pub(super) fn apply_damage(
+    mut er_damage: EventReader<ApplyDamage>,
+    mut targets: Query<&mut Health>,
+) {
+    for damage in er_damage.read() {
+        let Ok(mut health) = targets.get_mut(damage.target) else {
+            continue;
+        };
+        health.value -= damage.amount;
+        tracing::debug!(
+            "apply_damage: {:?} took {} {} damage from {:?}",
+            damage.target,
+            damage.amount,
+            damage.magic,
+            damage.source
+        );
+    }
+}
+
+/// Heals the targets of [`ApplyHealing`] events.
+pub(super) fn apply_healing(
+    mut er_healing: EventReader<ApplyHealing>,
+    mut targets: Query<&mut Health>,
+) {
+    for healing in er_healing.read() {
+        if let Ok(mut health) = targets.get_mut(healing.target) {
+            health.value += healing.amount;
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Turns Rapier collision events involving spells into [`SpellImpact`] events.
+//!
+//! Spells are sensors, so they don't push anything around. When a spell starts touching
+//! something, it either hits it (if it's a character the spell can affect, or a structure of the
+//! other side), bounces off it or stops (if it's an obstacle), or passes through it. Barriers of
+//! the other side destroy, absorb or reflect the spells they affect before they can hit anything.
+//!
+//! Arcing spells also hit what they land on (see [`land_arcs`]), and spells with a radius hit
+//! everything around them when they expire (see [`detonate_expired_spells`]). Each hit on a
+//! character then does the damage carried by its impact (see [`damage_hits`]).
+use bevy::{prelude::*, utils::HashSet};
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::{Faction, ParticleAttachment, StatEnum},
+    events::{
+        ApplyDamage, ElementalReactionTriggered, ParticleAnchor, SpawnSpellParticles, SpellExpired,
+        SpellImpact,
+    },
+    math, Acceleration, Barrier, BarrierAction, Health, ReactionMultipliers, Shield, SpellCaster,
+    SpellHits, SpellMotion, SpellSnapshot, Stat, StatBundle, Structure,
+};
+
+use super::{components::SpellEntity, split::SplitSpell};
+
+/// Returns the normal of the obstacle's surface closest to the point.
+fn surface_normal(collider: &Collider, transform: &GlobalTransform, point: Vec2) -> Vec2 {
+    let (_, rotation, translation) = transform.to_scale_rotation_translation();
+    let projection = collider.project_point(
+        translation.truncate(),
+        rotation.to_euler(EulerRot::ZYX).0,
+        point,
+        false,
+    );
+    let normal = point - projection.point;
+    if projection.is_inside {
+        -normal
+    } else {
+        normal
+    }
+}
+
+/// Handles spells colliding with things, sending [`SpellImpact`] events and despawning spells
+/// when they have no pierces or bounces left.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(super) fn spell_collisions(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut spells: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &mut Acceleration,
+            &mut SpellHits,
+            Option<&mut SpellMotion>,
+            &mut SpellSnapshot,
+            &mut SpellCaster,
+        ),
+        With<SpellEntity>,
+    >,
+    barriers: Query<&Barrier>,
+    mut shields: Query<&mut Shield>,
+    stats: Query<&StatBundle>,
+    characters: Query<Option<&Faction>, (With<Health>, Without<Structure>)>,
+    structures: Query<Option<&Faction>, With<Structure>>,
+    factions: Query<&Faction>,
+    obstacles: Query<(&Collider, &GlobalTransform), (Without<SpellEntity>, Without<Sensor>)>,
+    mut ew_impact: EventWriter<SpellImpact>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    mut ew_split: EventWriter<SplitSpell>,
+) {
+    // spells which were stopped this frame (they may have more collision events queued)
+    let mut finished = HashSet::new();
+
+    for collision_event in collision_events.read() {
+        let CollisionEvent::Started(entity1, entity2, _) = collision_event else {
+            continue;
+        };
+        let (spell, other) = if spells.contains(*entity1) {
+            (*entity1, *entity2)
+        } else {
+            (*entity2, *entity1)
+        };
+        if finished.contains(&spell) || spells.contains(other) {
+            continue;
+        }
+        let Ok((
+            mut transform,
+            mut velocity,
+            mut acceleration,
+            mut hits,
+            mut motion,
+            mut snapshot,
+            mut caster,
+        )) = spells.get_mut(spell)
+        else {
+            continue;
+        };
+        if other == caster.0 || hits.has_hit(other) {
+            continue;
+        }
+
+        let point = transform.translation.truncate();
+        // reflected spells are on the side of whoever reflected them
+        let spell_faction = factions
+            .get(spell)
+            .or_else(|_| factions.get(caster.0))
+            .copied()
+            .unwrap_or_default();
+
+        // barriers of the other side handle the spells they affect before anything else
+        let mut blocked = false;
+        if let Ok(barrier) = barriers.get(other) {
+            let barrier_faction = factions
+                .get(other)
+                .or_else(|_| factions.get(barrier.owner))
+                .copied()
+                .unwrap_or_default();
+            if barrier_faction.is_hostile_to(spell_faction)
+                && barrier.affects(snapshot.magic, &snapshot.target)
+            {
+                match barrier.data.action {
+                    BarrierAction::Destroy => blocked = true,
+                    BarrierAction::Absorb => {
+                        if let Ok(mut shield) = shields.get_mut(barrier.owner) {
+                            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                            let damage = snapshot.damage.max(0.0).ceil() as u32;
+                            #[allow(clippy::cast_precision_loss)]
+                            let absorbed = shield.absorb(damage) as f32;
+                            snapshot.damage = (snapshot.damage - absorbed).max(0.0);
+                            blocked = absorbed > 0.0 && snapshot.damage <= 0.0;
+                        }
+                    }
+                    BarrierAction::Reflect => {
+                        let normal = obstacles.get(other).map_or(Vec2::ZERO, |(collider, t)| {
+                            surface_normal(collider, t, point)
+                        });
+                        let normal = if normal == Vec2::ZERO {
+                            -velocity.linvel
+                        } else {
+                            normal
+                        };
+                        velocity.linvel = math::reflect(velocity.linvel, normal);
+                        acceleration.value = math::reflect(acceleration.value, normal);
+                        if let Some(motion) = motion.as_mut() {
+                            let direction = math::reflect(motion.direction, normal);
+                            motion.redirect(point, direction);
+                        }
+                        transform.rotation =
+                            Quat::from_rotation_z(velocity.linvel.y.atan2(velocity.linvel.x));
+
+                        // the spell now belongs to the barrier's owner, and is sent back with
+                        // their damage reflection
+                        let reflection: f32 = stats.get(barrier.owner).map_or(0.0, |stats| {
+                            [
+                                StatEnum::DamageReflection,
+                                StatEnum::MagicalDamageReflection,
+                            ]
+                            .iter()
+                            .map(|stat| stats.get_stat(stat).map_or(0.0, Stat::value))
+                            .sum()
+                        });
+                        snapshot.damage *= barrier.data.reflected_damage + reflection;
+                        caster.0 = barrier.owner;
+                        commands.entity(spell).insert(barrier_faction);
+                        ew_spawn_particles.send(SpawnSpellParticles {
+                            spell_id: snapshot.spell_id.clone(),
+                            attachment: ParticleAttachment::Impact,
+                            anchor: ParticleAnchor::Position(point),
+                        });
+                        continue;
+                    }
+                }
+            }
+        }
+        let mut impact = |target: Entity| {
+            ew_impact.send(SpellImpact {
+                spell,
+                spell_id: snapshot.spell_id.clone(),
+                caster: caster.0,
+                target,
+                point,
+                damage: snapshot.damage,
+                jump: 0,
+            });
+        };
+
+        let stopped = if blocked {
+            true
+        } else if let Ok(faction) = structures.get(other) {
+            // structures only stop the spells of the other side
+            if !faction
+                .copied()
+                .unwrap_or_default()
+                .is_hostile_to(spell_faction)
+            {
+                continue;
+            }
+            impact(other);
+            true
+        } else if let Ok(faction) = characters.get(other) {
+            if !spell_faction.can_affect(faction.copied().unwrap_or_default(), &snapshot.target) {
+                continue;
+            }
+            impact(other);
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: snapshot.spell_id.clone(),
+                attachment: ParticleAttachment::Target,
+                anchor: ParticleAnchor::Entity(other),
+            });
+            if snapshot.split.is_some() {
+                ew_split.send(SplitSpell {
+                    parent: snapshot.clone(),
+                    caster: caster.0,
+                    origin: transform.translation,
+                    direction: velocity.linvel.normalize_or_zero(),
+                    target: other,
+                });
+            }
+            !hits.hit_target(other)
+        } else if let Ok((collider, obstacle_transform)) = obstacles.get(other) {
+            if hits.bounce() {
+                let normal = surface_normal(collider, obstacle_transform, point);
+                let normal = if normal == Vec2::ZERO {
+                    -velocity.linvel
+                } else {
+                    normal
+                };
+                velocity.linvel = math::reflect(velocity.linvel, normal);
+                acceleration.value = math::reflect(acceleration.value, normal);
+                if let Some(mut motion) = motion {
+                    let direction = math::reflect(motion.direction, normal);
+                    motion.redirect(point, direction);
+                }
+                transform.rotation =
+                    Quat::from_rotation_z(velocity.linvel.y.atan2(velocity.linvel.x));
+                false
+            } else {
+                impact(other);
+                true
+            }
+        } else {
+            // other sensors (and things without a collider) don't affect spells
+            false
+        };
+
+        if stopped {
+            finished.insert(spell);
+            for attachment in [ParticleAttachment::Impact, ParticleAttachment::Ground] {
+                ew_spawn_particles.send(SpawnSpellParticles {
+                    spell_id: snapshot.spell_id.clone(),
+                    attachment,
+                    anchor: ParticleAnchor::Position(point),
+                });
+            }
+            commands.entity(spell).despawn_recursive();
+        }
+    }
+}
+
+/// Lands arcing spells which reached their landing point this frame: they hit every character
+/// under them which they can affect (and haven't hit already). The spell then expires where it
+/// landed (see `expire_spells`).
+#[allow(clippy::type_complexity)]
+pub(super) fn land_arcs(
+    rapier_context: Res<RapierContext>,
+    mut spells: Query<
+        (
+            Entity,
+            &mut Transform,
+            &SpellMotion,
+            &Collider,
+            &SpellSnapshot,
+            &SpellCaster,
+            &SpellHits,
+        ),
+        With<SpellEntity>,
+    >,
+    characters: Query<Option<&Faction>, (With<Health>, Without<Structure>)>,
+    factions: Query<&Faction>,
+    mut ew_impact: EventWriter<SpellImpact>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+) {
+    for (spell, mut transform, motion, collider, snapshot, caster, hits) in &mut spells {
+        if !motion.has_landed() {
+            continue;
+        }
+        let point = motion.landing_point();
+        transform.translation = point.extend(transform.translation.z);
+
+        // reflected spells are on the side of whoever reflected them
+        let spell_faction = factions
+            .get(spell)
+            .or_else(|_| factions.get(caster.0))
+            .copied()
+            .unwrap_or_default();
+        let mut targets = Vec::new();
+        rapier_context.intersections_with_shape(
+            point,
+            0.0,
+            collider,
+            QueryFilter::new().exclude_sensors(),
+            |other| {
+                if other != caster.0
+                    && !hits.has_hit(other)
+                    && characters.get(other).is_ok_and(|faction| {
+                        spell_faction
+                            .can_affect(faction.copied().unwrap_or_default(), &snapshot.target)
+                    })
+                {
+                    targets.push(other);
+                }
+                true
+            },
+        );
+
+        for target in targets {
+            ew_impact.send(SpellImpact {
+                spell,
+                spell_id: snapshot.spell_id.clone(),
+                caster: caster.0,
+                target,
+                point,
+                damage: snapshot.damage,
+                jump: 0,
+            });
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: snapshot.spell_id.clone(),
+                attachment: ParticleAttachment::Target,
+                anchor: ParticleAnchor::Entity(target),
+            });
+        }
+    }
+}
+
+/// Detonates expired spells which have a [`game_library::SpellData::radius`] (e.g. a fireball at
+/// the end of its flight): they hit every character within the radius which they can affect.
+pub(super) fn detonate_expired_spells(
+    rapier_context: Res<RapierContext>,
+    mut er_expired: EventReader<SpellExpired>,
+    characters: Query<Option<&Faction>, (With<Health>, Without<Structure>)>,
+    factions: Query<&Faction>,
+    game_data: Res<GameData>,
+    mut ew_impact: EventWriter<SpellImpact>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+) {
+    for expired in er_expired.read() {
+        let Some(spell) = game_data.spells.get(&expired.spell_id) else {
+            continue;
+        };
+        if spell.radius <= 0 {
+            continue;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let radius = spell.radius as f32;
+        let spell_faction = factions.get(expired.caster).copied().unwrap_or_default();
+
+        let mut targets = Vec::new();
+        rapier_context.intersections_with_shape(
+            expired.position,
+            0.0,
+            &Collider::ball(radius),
+            QueryFilter::new().exclude_sensors(),
+            |other| {
+                if other != expired.caster
+                    && characters.get(other).is_ok_and(|faction| {
+                        spell_faction
+                            .can_affect(faction.copied().unwrap_or_default(), &spell.target)
+                    })
+                {
+                    targets.push(other);
+                }
+                true
+            },
+        );
+
+        for target in targets {
+            ew_impact.send(SpellImpact {
+                spell: expired.spell,
+                spell_id: expired.spell_id.clone(),
+                caster: expired.caster,
+                target,
+                point: expired.position,
+                damage: expired.damage,
+                jump: 0,
+            });
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: expired.spell_id.clone(),
+                attachment: ParticleAttachment::Target,
+                anchor: ParticleAnchor::Entity(target),
+            });
+        }
+    }
+}
+
+/// Damages the characters hit by spells with the damage of each hit.
+///
+/// The hit which caused an elemental reaction is scaled by the reaction's damage multiplier. Only
+/// that one impact is scaled: later impacts of the same spell on the same target in the frame
+/// (e.g. further ticks of a beam) do their normal damage.
+/// Structures are damaged separately (see `damage_structures`).
+pub(super) fn damage_hits(
+    mut er_impact: EventReader<SpellImpact>,
+    mut er_reaction: EventReader<ElementalReactionTriggered>,
+    characters: Query<(), (With<Health>, Without<Structure>)>,
+    game_data: Res<GameData>,
+    mut ew_damage: EventWriter<ApplyDamage>,
+) {
+    let mut multipliers = ReactionMultipliers::default();
+    for reaction in er_reaction.read() {
+        multipliers.add(reaction.spell, reaction.target, reaction.damage_multiplier);
+    }
+
+    for impact in er_impact.read() {
+        if !characters.contains(impact.target) {
+            continue;
+        }
+        let Some(spell) = game_data.spells.get(&impact.spell_id) else {
+            continue;
+        };
+        let amount = multipliers.damage(impact.spell, impact.target, impact.damage);
+        if amount == 0 {
+            continue;
+        }
+        ew_damage.send(ApplyDamage {
+            target: impact.target,
+            source: impact.caster,
+            amount,
+            magic: spell.magic,
+        });
+    }
+}
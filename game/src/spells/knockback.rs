@@ -0,0 +1,126 @@
+//! Knockback pushes (or pulls) the characters hit by spells with
+//! [`game_library::KnockbackData`], and those in zones with knockback.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::StatEnum,
+    events::{ApplyKnockback, SpellImpact},
+    Health, Knockback, StatBundle, Structure,
+};
+
+/// Knocks back the characters hit by spells with knockback, away from (or toward) the caster.
+///
+/// Hits whose caster is gone are knocked back from where they hit instead.
+pub(super) fn knockback_hits(
+    mut er_impact: EventReader<SpellImpact>,
+    characters: Query<(), (With<Health>, Without<Structure>)>,
+    transforms: Query<&GlobalTransform>,
+    game_data: Res<GameData>,
+    mut ew_knockback: EventWriter<ApplyKnockback>,
+) {
+    for impact in er_impact.read() {
+        if !characters.contains(impact.target) {
+            continue;
+        }
+        let Some(knockback) = game_data
+            .spells
+            .get(&impact.spell_id)
+            .and_then(|spell| spell.knockback)
+        else {
+            continue;
+        };
+        let origin = transforms
+            .get(impact.caster)
+            .map_or(impact.point, |transform| transform.translation().truncate());
+        ew_knockback.send(ApplyKnockback {
+            target: impact.target,
+            origin,
+            knockback,
+        });
+    }
+}
+
+/// Knocks back the targets of [`ApplyKnockback`] events, after their stun resistance.
+///
+/// Dynamic bodies get an impulse; everything else (e.g. the player's kinematic character) gets
+/// a [`Knockback`] which moves them until it slows down (see `move_knocked_back`).
+#[allow(clippy::type_complexity)]
+pub(super) fn apply_knockback(
+    mut commands: Commands,
+    mut er_knockback: EventReader<ApplyKnockback>,
+    mut targets: Query<(
+        &GlobalTransform,
+        Option<&StatBundle>,
+        Option<&RigidBody>,
+        Option<&ReadMassProperties>,
+        Option<&mut ExternalImpulse>,
+        Option<&mut Knockback>,
+    )>,
+) {
+    for event in er_knockback.read() {
+        let Ok((transform, stats, body, mass, impulse, knockback)) = targets.get_mut(event.target)
+        else {
+            continue;
+        };
+        let stun_resistance = stats
+            .and_then(|stats| stats.get_stat(&StatEnum::StunResistance))
+            .map_or(0.0, game_library::Stat::value);
+        let force = event.knockback.mitigated_force(stun_resistance);
+        let direction = event
+            .knockback
+            .direction_from(event.origin, transform.translation().truncate());
+        let velocity = event.knockback.velocity(direction, force);
+        if velocity == Vec2::ZERO {
+            continue;
+        }
+
+        if body == Some(&RigidBody::Dynamic) {
+            let mass = mass.map_or(1.0, |mass| mass.mass);
+            if let Some(mut impulse) = impulse {
+                impulse.impulse += velocity * mass;
+            } else {
+                commands.entity(event.target).insert(ExternalImpulse {
+                    impulse: velocity * mass,
+                    ..Default::default()
+                });
+            }
+            continue;
+        }
+
+        let new = Knockback::new(velocity, event.knockback.decay);
+        if let Some(mut knockback) = knockback {
+            knockback.add(new);
+        } else {
+            commands.entity(event.target).insert(new);
+        }
+    }
+}
+
+/// Moves knocked back characters, removing their [`Knockback`] once it stops.
+///
+/// Kinematic characters are moved through their controller (on top of their own movement), and
+/// anything else is moved directly.
+pub(super) fn move_knocked_back(
+    mut commands: Commands,
+    mut knocked_back: Query<(
+        Entity,
+        &mut Knockback,
+        &mut Transform,
+        Option<&mut KinematicCharacterController>,
+    )>,
+    time: Res<Time>,
+) {
+    for (entity, mut knockback, mut transform, controller) in &mut knocked_back {
+        let displacement = knockback.step(time.delta_seconds());
+        if let Some(mut controller) = controller {
+            controller.translation =
+                Some(controller.translation.unwrap_or_default() + displacement);
+        } else {
+            transform.translation += displacement.extend(0.0);
+        }
+        if knockback.is_finished() {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
@@ -1,6 +1,23 @@
 pub mod components;
 
+mod barriers;
+mod beams;
 mod cast_spell;
+mod chain;
+mod damage;
+mod impact;
+mod knockback;
+mod particles;
 mod plugin;
+mod polymorph;
+mod reactions;
+mod skill_xp;
+mod split;
+mod status_icons;
+mod structures;
+mod summon;
+mod trajectory;
+mod zones;
 
+pub use cast_spell::{spawn_spell_projectile, ProjectileLaunch};
 pub use plugin::SpellsPlugin;
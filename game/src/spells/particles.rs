@@ -0,0 +1,117 @@
+//! Spawns the particle effects of spells at their attachment points.
+//!
+//! Other systems send [`SpawnSpellParticles`] when something happens to a spell (it is cast,
+//! launched, hits something, etc.) and [`spawn_spell_particles`] spawns the matching particle
+//! effects from [`GameData::particles`].
+use bevy::prelude::*;
+use bevy_hanabi::{ParticleEffect, ParticleEffectBundle};
+use game_library::{
+    data_loader::storage::GameData,
+    enums::ParticleAttachment,
+    events::{ParticleAnchor, SpawnSpellParticles},
+};
+
+/// How long particles which are emitted once (e.g. on cast or on impact) are kept around.
+const ONE_SHOT_PARTICLE_DURATION: f32 = 0.75;
+
+/// A particle effect spawned for a spell.
+///
+/// Effects attached to an entity are its children, so they follow it and are despawned with it.
+/// Effects with a remaining time are despawned when it runs out.
+#[derive(Component, Debug, Reflect)]
+pub struct SpellParticleEffect {
+    /// How long until the effect is despawned (in seconds), if it's not kept for its owner's life.
+    pub remaining: Option<f32>,
+}
+
+/// How long particles with the given attachment should last, or `None` to last as long as the
+/// entity they are attached to.
+fn particle_duration(attachment: &ParticleAttachment, spell_duration: f32) -> Option<f32> {
+    match attachment {
+        ParticleAttachment::Cast | ParticleAttachment::Impact | ParticleAttachment::Target => {
+            Some(ONE_SHOT_PARTICLE_DURATION)
+        }
+        ParticleAttachment::Caster | ParticleAttachment::Ground => {
+            Some(spell_duration.max(ONE_SHOT_PARTICLE_DURATION))
+        }
+        ParticleAttachment::Projectile | ParticleAttachment::Summon => None,
+    }
+}
+
+/// Spawns the particle effects requested by [`SpawnSpellParticles`] events.
+pub(super) fn spawn_spell_particles(
+    mut commands: Commands,
+    mut er_spawn_particles: EventReader<SpawnSpellParticles>,
+    game_data: Res<GameData>,
+) {
+    for event in er_spawn_particles.read() {
+        let Some(spell) = game_data.spells.get(&event.spell_id) else {
+            tracing::error!("spawn_spell_particles: 404 {} not found", event.spell_id);
+            continue;
+        };
+
+        let translation = match event.anchor {
+            ParticleAnchor::Entity(owner) => {
+                if commands.get_entity(owner).is_none() {
+                    tracing::warn!(
+                        "spawn_spell_particles: {:?} owner no longer exists (spell:{})",
+                        event.attachment,
+                        event.spell_id
+                    );
+                    continue;
+                }
+                Vec3::ZERO
+            }
+            ParticleAnchor::Position(position) => position.extend(0.0),
+        };
+
+        for particle_link in spell
+            .particles
+            .iter()
+            .filter(|link| link.attachment == event.attachment)
+        {
+            let Some(particle) = game_data.particles.get(&particle_link.particle_id) else {
+                tracing::error!(
+                    "spawn_spell_particles: 404 particle {} not found (spell:{})",
+                    particle_link.particle_id,
+                    event.spell_id
+                );
+                continue;
+            };
+
+            let effect = commands
+                .spawn((
+                    ParticleEffectBundle {
+                        effect: ParticleEffect::new(particle.clone()),
+                        transform: Transform::from_translation(translation),
+                        ..Default::default()
+                    },
+                    SpellParticleEffect {
+                        remaining: particle_duration(&event.attachment, spell.duration),
+                    },
+                ))
+                .id();
+
+            if let ParticleAnchor::Entity(owner) = event.anchor {
+                commands.entity(owner).add_child(effect);
+            }
+        }
+    }
+}
+
+/// Despawns spell particle effects when their time runs out.
+pub(super) fn despawn_expired_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SpellParticleEffect)>,
+) {
+    for (entity, mut effect) in &mut query {
+        let Some(remaining) = effect.remaining.as_mut() else {
+            continue;
+        };
+        *remaining -= time.delta_seconds();
+        if *remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -1,11 +1,36 @@
 use crate::despawn_with_tag;
 use bevy::prelude::*;
-use game_library::state::AppState;
-use game_library::{events::CastSpell, state::Overlay};
+use bevy_rapier2d::prelude::PhysicsSet;
+use game_library::state::{AppState, Game};
+use game_library::{
+    events::{
+        ApplyDamage, ApplyHealing, ApplyKnockback, CastSpell, ElementalReactionTriggered,
+        PolymorphEnded, SkillLeveledUp, SpawnSpellParticles, SpellExpired, SpellImpact,
+        StopChanneling,
+    },
+    state::Overlay,
+    Structure, Summoned, Zone,
+};
 
 use super::{
-    cast_spell::cast_spells,
-    components::{despawn_expired_spells, SpellEntity},
+    barriers::{raise_barriers, tick_barriers, RaiseBarrier},
+    beams::update_beams,
+    cast_spell::{apply_spell_debuffs, cast_spells, tick_spell_cooldowns},
+    chain::chain_spells,
+    components::{expire_spells, SpellEntity},
+    damage::{apply_damage, apply_healing},
+    impact::{damage_hits, detonate_expired_spells, land_arcs, spell_collisions},
+    knockback::{apply_knockback, knockback_hits, move_knocked_back},
+    particles::{despawn_expired_particles, spawn_spell_particles, SpellParticleEffect},
+    polymorph::{apply_polymorphs, tick_polymorphs, Polymorph},
+    reactions::{elemental_reactions, tick_elemental_statuses},
+    skill_xp::{award_hit_xp, level_up_skills},
+    split::{split_spells, SplitSpell},
+    status_icons::update_status_icons,
+    structures::{damage_structures, place_structures, tick_structures, PlaceStructure},
+    summon::{spawn_summons, summon_behaviour, tick_summons, SpawnSummons},
+    trajectory::move_spells,
+    zones::{place_zones, tick_zones, zone_collisions, PlaceZone},
 };
 
 /// Spells are fired using the `CastSpell` event.
@@ -19,14 +44,98 @@ impl Plugin for SpellsPlugin {
     fn build(&self, app: &mut App) {
         // Spell data supporting event and resources
         app.add_event::<CastSpell>()
+            .add_event::<StopChanneling>()
+            .add_event::<SpellExpired>()
+            .add_event::<SpellImpact>()
+            .add_event::<SplitSpell>()
+            .add_event::<SpawnSummons>()
+            .add_event::<Polymorph>()
+            .add_event::<PlaceStructure>()
+            .add_event::<PlaceZone>()
+            .add_event::<RaiseBarrier>()
+            .add_event::<ApplyDamage>()
+            .add_event::<ApplyHealing>()
+            .add_event::<ApplyKnockback>()
+            .add_event::<PolymorphEnded>()
+            .add_event::<SpawnSpellParticles>()
+            .add_event::<SkillLeveledUp>()
+            .add_event::<ElementalReactionTriggered>()
             // Spell systems
             .add_systems(
                 Update,
-                (despawn_expired_spells, cast_spells)
+                (
+                    (
+                        tick_spell_cooldowns,
+                        tick_elemental_statuses,
+                        tick_summons,
+                        tick_polymorphs,
+                        tick_structures,
+                        tick_zones,
+                        tick_barriers,
+                    )
+                        .chain(),
+                    (
+                        cast_spells,
+                        place_structures,
+                        place_zones,
+                        raise_barriers,
+                        apply_polymorphs,
+                        spawn_summons,
+                        summon_behaviour,
+                    )
+                        .chain(),
+                    (
+                        move_spells,
+                        update_beams,
+                        spell_collisions,
+                        land_arcs,
+                        chain_spells,
+                        zone_collisions,
+                        elemental_reactions,
+                        damage_hits,
+                        apply_spell_debuffs,
+                        knockback_hits,
+                        apply_knockback,
+                        damage_structures,
+                        apply_damage,
+                        apply_healing,
+                        award_hit_xp,
+                        level_up_skills,
+                        split_spells,
+                    )
+                        .chain(),
+                    (
+                        expire_spells,
+                        detonate_expired_spells,
+                        spawn_spell_particles,
+                        despawn_expired_particles,
+                        update_status_icons,
+                    )
+                        .chain(),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame).and_then(not(in_state(Overlay::Settings)))),
+            )
+            // knockback moves characters right before the physics step, on top of their own movement
+            .add_systems(
+                PostUpdate,
+                move_knocked_back
+                    .before(PhysicsSet::SyncBackend)
                     .run_if(in_state(AppState::InGame).and_then(not(in_state(Overlay::Settings)))),
             )
             // despawn all spells when leaving the game (to main menu)
             // stuff automatically despawns when the game exits
-            .add_systems(OnExit(AppState::InGame), despawn_with_tag::<SpellEntity>);
+            .add_systems(
+                OnExit(AppState::InGame),
+                (
+                    despawn_with_tag::<SpellParticleEffect>,
+                    despawn_with_tag::<SpellEntity>,
+                    despawn_with_tag::<Structure>,
+                    despawn_with_tag::<Zone>,
+                )
+                    .chain(),
+            )
+            // summons don't outlive the caster's run
+            .add_systems(OnExit(Game::Playing), despawn_with_tag::<Summoned>);
     }
 }
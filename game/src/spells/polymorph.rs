@@ -0,0 +1,104 @@
+//! Polymorph spells transform their caster into another form for a while.
+use bevy::prelude::*;
+use game_library::{
+    data_loader::storage::GameData, events::PolymorphEnded, Health, Polymorphed, StatBundle,
+};
+
+/// Sent when a polymorph spell is cast.
+#[derive(Event, Debug, Clone)]
+pub struct Polymorph {
+    /// The unique id of the polymorph spell.
+    pub spell_id: String,
+    /// The entity to transform.
+    pub target: Entity,
+}
+
+/// Transforms the targets of polymorph spells, swapping their sprite atlas and changing their
+/// stats.
+///
+/// A target which is already transformed changes into the new form (or has its duration
+/// refreshed, for the same form) and keeps its original sprite.
+#[allow(clippy::type_complexity)]
+pub(super) fn apply_polymorphs(
+    mut commands: Commands,
+    mut er_polymorph: EventReader<Polymorph>,
+    mut query: Query<(
+        &mut StatBundle,
+        &mut Handle<TextureAtlas>,
+        &mut Transform,
+        Option<&mut Polymorphed>,
+    )>,
+    game_data: Res<GameData>,
+) {
+    for event in er_polymorph.read() {
+        let Some(form) = game_data
+            .spells
+            .get(&event.spell_id)
+            .and_then(|spell| spell.polymorph.as_ref())
+        else {
+            tracing::error!("apply_polymorphs: no polymorph data for {}", event.spell_id);
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&form.sprite_tileset) else {
+            tracing::error!(
+                "apply_polymorphs: No texture atlas found for {} (spell:{})",
+                form.sprite_tileset,
+                event.spell_id
+            );
+            continue;
+        };
+        let Ok((mut stats, mut atlas, mut transform, polymorphed)) = query.get_mut(event.target)
+        else {
+            continue;
+        };
+
+        let original_scale = if let Some(mut polymorphed) = polymorphed {
+            polymorphed.transform(&event.spell_id, form.clone(), &mut stats);
+            polymorphed.original_scale
+        } else {
+            let mut polymorphed = Polymorphed::new(
+                &event.spell_id,
+                form.clone(),
+                atlas.clone(),
+                transform.scale,
+            );
+            polymorphed.apply_stats(&mut stats);
+            let original_scale = polymorphed.original_scale;
+            commands.entity(event.target).insert(polymorphed);
+            original_scale
+        };
+        *atlas = texture_atlas.clone();
+        transform.scale = original_scale * form.scale;
+    }
+}
+
+/// Counts down polymorphs, changing their targets back when the form runs out or they die.
+pub(super) fn tick_polymorphs(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut Polymorphed,
+        &mut StatBundle,
+        &mut Handle<TextureAtlas>,
+        &mut Transform,
+        Option<&Health>,
+    )>,
+    mut ew_ended: EventWriter<PolymorphEnded>,
+    time: Res<Time>,
+) {
+    for (entity, mut polymorphed, mut stats, mut atlas, mut transform, health) in &mut query {
+        polymorphed.tick(time.delta_seconds());
+        if !polymorphed.is_expired() && !health.is_some_and(Health::is_dead) {
+            continue;
+        }
+
+        polymorphed.revert_stats(&mut stats);
+        *atlas = polymorphed.original_atlas.clone();
+        transform.scale = polymorphed.original_scale;
+        commands.entity(entity).remove::<Polymorphed>();
+        ew_ended.send(PolymorphEnded {
+            target: entity,
+            spell_id: polymorphed.spell_id.clone(),
+        });
+    }
+}
@@ -0,0 +1,110 @@
+//! Elemental reactions between the spells hitting a target and the elements lingering on it.
+use bevy::{prelude::*, utils::HashMap};
+use game_library::{
+    data_loader::storage::GameData,
+    events::{ApplyStatEffect, ElementalReactionTriggered, SpawnSpellParticles, SpellImpact},
+    ElementalStatus, Health, Structure,
+};
+
+use super::cast_spell::{spawn_triggered_spell, CasterStats, TriggeredSpell};
+
+/// Leaves the magic of each spell on the targets it hits, triggering an
+/// [`game_library::ElementalReaction`] when it reacts with an element already lingering there.
+///
+/// The reaction's effects are applied to the target and its spell is spawned where the target
+/// was hit.
+pub(super) fn elemental_reactions(
+    mut commands: Commands,
+    mut er_impact: EventReader<SpellImpact>,
+    mut targets: Query<
+        (Option<&mut ElementalStatus>, &Transform),
+        (With<Health>, Without<Structure>),
+    >,
+    casters: CasterStats,
+    game_data: Res<GameData>,
+    mut ew_apply_effect: EventWriter<ApplyStatEffect>,
+    mut ew_reaction: EventWriter<ElementalReactionTriggered>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+) {
+    // Targets without `ElementalStatus` get one inserted; collect them first so that multiple
+    // spells hitting them in the same frame don't overwrite each other.
+    let mut inserted: HashMap<Entity, ElementalStatus> = HashMap::new();
+
+    for impact in er_impact.read() {
+        // only characters carry elements (obstacles don't)
+        let Ok((status, transform)) = targets.get_mut(impact.target) else {
+            continue;
+        };
+        let Some(spell) = game_data.spells.get(&impact.spell_id) else {
+            continue;
+        };
+
+        let reactions = &game_data.elemental_reactions;
+        let reaction = if let Some(mut status) = status {
+            status.hit(spell.magic, spell.status_duration, reactions)
+        } else {
+            inserted.entry(impact.target).or_default().hit(
+                spell.magic,
+                spell.status_duration,
+                reactions,
+            )
+        };
+        let Some((lingering, reaction_id, reaction)) = reaction else {
+            continue;
+        };
+        tracing::debug!(
+            "elemental_reactions: {} on {:?} ({lingering} + {})",
+            reaction.name,
+            impact.target,
+            spell.magic
+        );
+
+        for effect in &reaction.effects {
+            ew_apply_effect.send(ApplyStatEffect {
+                target: impact.target,
+                effect: effect.clone(),
+            });
+        }
+
+        if let Some(reaction_spell_id) = &reaction.spell {
+            // the reaction's spell won't hit the target again
+            spawn_triggered_spell(
+                &mut commands,
+                &game_data,
+                &casters,
+                TriggeredSpell {
+                    spell_id: reaction_spell_id,
+                    caster: impact.caster,
+                    origin: impact.point.extend(transform.translation.z),
+                    already_hit: vec![impact.target],
+                },
+                &mut ew_spawn_particles,
+            );
+        }
+
+        ew_reaction.send(ElementalReactionTriggered {
+            reaction_id: reaction_id.clone(),
+            lingering,
+            magic: spell.magic,
+            spell: impact.spell,
+            caster: impact.caster,
+            target: impact.target,
+            point: impact.point,
+            damage_multiplier: reaction.damage_multiplier,
+        });
+    }
+
+    for (target, status) in inserted {
+        commands.entity(target).insert(status);
+    }
+}
+
+/// Counts down the elements lingering on entities.
+pub(super) fn tick_elemental_statuses(time: Res<Time>, mut query: Query<&mut ElementalStatus>) {
+    for mut status in &mut query {
+        if status.is_empty() {
+            continue;
+        }
+        status.tick(time.delta_seconds());
+    }
+}
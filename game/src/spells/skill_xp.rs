@@ -0,0 +1,46 @@
+//! Awards skill experience for hitting things with spells and levels up the player's skills.
+use bevy::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    events::{SkillLeveledUp, SpellImpact},
+    Health, Skills, Structure,
+};
+
+use crate::player::Player;
+
+/// Awards the spell's [`game_library::SpellData::hit_xp`] to its skill for each target the player
+/// hits (see [`Skills::add_hit_xp`]).
+///
+/// Spells stopping against an obstacle or a structure don't award any experience.
+pub(super) fn award_hit_xp(
+    mut er_impact: EventReader<SpellImpact>,
+    player: Query<Entity, With<Player>>,
+    targets: Query<(), (With<Health>, Without<Structure>)>,
+    game_data: Res<GameData>,
+    mut skills: ResMut<Skills>,
+) {
+    let Ok(player) = player.get_single() else {
+        er_impact.clear();
+        return;
+    };
+    for impact in er_impact.read() {
+        if !targets.contains(impact.target) {
+            continue;
+        }
+        let Some(spell) = game_data.spells.get(&impact.spell_id) else {
+            continue;
+        };
+        skills.add_hit_xp(spell, impact.caster, player);
+    }
+}
+
+/// Processes any pending skill levels, sending a [`SkillLeveledUp`] for each level gained.
+pub(super) fn level_up_skills(
+    mut skills: ResMut<Skills>,
+    mut ew_leveled_up: EventWriter<SkillLeveledUp>,
+) {
+    for (skill, new_level) in skills.level_up_pending() {
+        tracing::info!("level_up_skills: {skill} is now level {new_level}");
+        ew_leveled_up.send(SkillLeveledUp { skill, new_level });
+    }
+}
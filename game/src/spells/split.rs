@@ -0,0 +1,97 @@
+//! Splits spells into child spells when they hit a target.
+use bevy::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::ParticleAttachment,
+    events::{ParticleAnchor, SpawnSpellParticles},
+    spread_directions, SpellSnapshot, SpellStatModifiers,
+};
+
+use super::cast_spell::{spawn_spell_projectile, CasterStats, ProjectileLaunch};
+
+/// Sent when a spell which splits hits a target.
+#[derive(Event, Debug, Clone)]
+pub struct SplitSpell {
+    /// The snapshot of the spell which split.
+    pub parent: SpellSnapshot,
+    /// The entity which cast the spell.
+    pub caster: Entity,
+    /// Where the spell hit the target.
+    pub origin: Vec3,
+    /// The direction the spell was travelling.
+    pub direction: Vec2,
+    /// The target which was hit (the child spells won't hit it again).
+    pub target: Entity,
+}
+
+/// Spawns the child spells of spells which split.
+///
+/// The child spells don't split again, and only get one projectile each.
+pub(super) fn split_spells(
+    mut commands: Commands,
+    mut er_split: EventReader<SplitSpell>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    casters: CasterStats,
+    game_data: Res<GameData>,
+) {
+    for event in er_split.read() {
+        let Some(split) = &event.parent.split else {
+            continue;
+        };
+        let child_id = split
+            .spell
+            .clone()
+            .unwrap_or_else(|| event.parent.spell_id.clone());
+        let Some(child_spell) = game_data.spells.get(&child_id) else {
+            tracing::error!("split_spells: 404 {child_id} not found");
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&child_spell.sprite_tileset) else {
+            tracing::error!(
+                "split_spells: No texture atlas found for {} (spell:{child_id})",
+                child_spell.sprite_tileset,
+            );
+            continue;
+        };
+
+        // A spell splitting into itself keeps the stats it was cast with, and other spells get
+        // the caster's stats
+        let mut child = if child_id == event.parent.spell_id {
+            event.parent.clone()
+        } else {
+            let (stats, modifiers) = casters.get(event.caster).unwrap_or_default();
+            SpellSnapshot::new(
+                &child_id,
+                child_spell,
+                stats,
+                modifiers.into_iter().flat_map(SpellStatModifiers::iter),
+            )
+        };
+        child.projectiles = 1;
+        child.spread = 0.0;
+        child.split = None;
+
+        for direction in spread_directions(event.direction, split.count, split.spread) {
+            let launch = ProjectileLaunch {
+                caster: event.caster,
+                origin: event.origin,
+                direction,
+                aim_distance: child.range,
+                inherited_velocity: Vec2::ZERO,
+                already_hit: vec![event.target],
+            };
+            let projectile = spawn_spell_projectile(
+                &mut commands,
+                texture_atlas.clone(),
+                child_spell,
+                child.clone(),
+                launch,
+            );
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: child_id.clone(),
+                attachment: ParticleAttachment::Projectile,
+                anchor: ParticleAnchor::Entity(projectile),
+            });
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Status icons show the buffs and debuffs active on an entity, in a row above it.
+use bevy::prelude::*;
+use game_library::{data_loader::storage::GameData, ActiveEffects};
+
+/// How far (in pixels) above the entity the row of icons is.
+const ICON_OFFSET: f32 = 12.0;
+/// The distance (in pixels) between the icons in the row.
+const ICON_SPACING: f32 = 6.0;
+/// The scale of the icons, which are shrunk to fit in a row above the entity.
+const ICON_SCALE: f32 = 0.25;
+
+/// An icon showing an active effect, as a child of the entity it's active on.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct StatusIcon {
+    /// The id of the effect it shows.
+    pub effect_id: String,
+}
+
+/// Keeps the status icons of entities in line with their active effects.
+///
+/// Effects without an icon (or whose icon's tileset isn't loaded) aren't shown. When the effects
+/// with icons change, the row of icons is rebuilt (in the order the effects were applied).
+pub(super) fn update_status_icons(
+    mut commands: Commands,
+    affected: Query<(Entity, &ActiveEffects, Option<&Children>)>,
+    icons: Query<&StatusIcon>,
+    game_data: Res<GameData>,
+) {
+    for (entity, active_effects, children) in &affected {
+        let shown: Vec<(Entity, &StatusIcon)> = children
+            .into_iter()
+            .flatten()
+            .filter_map(|child| icons.get(*child).ok().map(|icon| (*child, icon)))
+            .collect();
+        let wanted: Vec<(&str, Handle<TextureAtlas>, usize)> = active_effects
+            .iter()
+            .filter_map(|active| {
+                let tileset = active.effect.icon_tileset.as_ref()?;
+                let texture_atlas = game_data.tile_atlas.get(tileset)?;
+                Some((
+                    active.effect.id.as_str(),
+                    texture_atlas.clone(),
+                    active.effect.icon_index,
+                ))
+            })
+            .collect();
+
+        if shown.len() == wanted.len()
+            && shown
+                .iter()
+                .zip(&wanted)
+                .all(|((_, icon), (effect_id, _, _))| icon.effect_id == *effect_id)
+        {
+            continue;
+        }
+
+        for (icon, _) in shown {
+            commands.entity(icon).despawn_recursive();
+        }
+
+        let mut x = -(wanted.len().saturating_sub(1) as f32) * ICON_SPACING / 2.0;
+        let mut row = Vec::new();
+        for (effect_id, texture_atlas, icon_index) in wanted {
+            row.push(
+                commands
+                    .spawn((
+                        StatusIcon {
+                            effect_id: effect_id.to_string(),
+                        },
+                        SpriteSheetBundle {
+                            texture_atlas,
+                            sprite: TextureAtlasSprite::new(icon_index),
+                            transform: Transform::from_xyz(x, ICON_OFFSET, 1.0)
+                                .with_scale(Vec3::splat(ICON_SCALE)),
+                            ..Default::default()
+                        },
+                    ))
+                    .id(),
+            );
+            x += ICON_SPACING;
+        }
+        commands.entity(entity).push_children(&row);
+    }
+}
@@ -0,0 +1,158 @@
+//! Structure spells place temporary walls and pillars which block movement and the spells of the
+//! other side.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::{Faction, ParticleAttachment},
+    events::{ParticleAnchor, SpawnSpellParticles, SpellImpact},
+    Barrier, Health, Layer, Shield, Structure,
+};
+
+use super::{
+    barriers::add_shield,
+    cast_spell::{spawn_triggered_spell, CasterStats, TriggeredSpell},
+};
+
+/// Sent when a structure spell is cast.
+#[derive(Event, Debug, Clone)]
+pub struct PlaceStructure {
+    /// The unique id of the structure spell.
+    pub spell_id: String,
+    /// The entity which cast the spell.
+    pub owner: Entity,
+    /// Where the structure is placed.
+    pub position: Vec3,
+}
+
+/// Places the structures of structure spells.
+///
+/// Structures are on the same side as the caster, and have a fixed collider so they block
+/// movement. Structures placed by barrier spells are barriers, adding their shield to the
+/// caster's shield pool.
+pub(super) fn place_structures(
+    mut commands: Commands,
+    mut er_structure: EventReader<PlaceStructure>,
+    factions: Query<&Faction>,
+    mut shields: Query<&mut Shield>,
+    game_data: Res<GameData>,
+) {
+    for event in er_structure.read() {
+        let Some(spell) = game_data.spells.get(&event.spell_id) else {
+            tracing::error!("place_structures: 404 {} not found", event.spell_id);
+            continue;
+        };
+        let Some(structure) = spell.structure.as_ref() else {
+            tracing::error!("place_structures: no structure data for {}", event.spell_id);
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&structure.sprite_tileset) else {
+            tracing::error!(
+                "place_structures: No texture atlas found for {} (spell:{})",
+                structure.sprite_tileset,
+                event.spell_id
+            );
+            continue;
+        };
+
+        let faction = if factions
+            .get(event.owner)
+            .copied()
+            .unwrap_or_default()
+            .is_friendly()
+        {
+            Faction::Ally
+        } else {
+            Faction::Enemy
+        };
+
+        let entity = commands
+            .spawn((
+                Structure::new(event.owner, &event.spell_id, structure.lifetime),
+                faction,
+                Health::new(structure.health),
+                SpriteSheetBundle {
+                    texture_atlas: texture_atlas.clone(),
+                    sprite: structure.texture_atlas_index(),
+                    transform: Transform::from_translation(event.position),
+                    ..Default::default()
+                },
+                RigidBody::Fixed,
+                structure.shape.collider(),
+                Layer::Foreground(0),
+            ))
+            .id();
+        if let Some(barrier) = &spell.barrier {
+            commands.entity(entity).insert(Barrier::new(
+                event.owner,
+                &event.spell_id,
+                barrier.clone(),
+            ));
+            add_shield(&mut commands, &mut shields, event.owner, barrier.shield);
+        }
+    }
+}
+
+/// Damages structures hit by spells. Each hit does its damage, but at least 1.
+pub(super) fn damage_structures(
+    mut er_impact: EventReader<SpellImpact>,
+    mut structures: Query<&mut Health, With<Structure>>,
+) {
+    for impact in er_impact.read() {
+        let Ok(mut health) = structures.get_mut(impact.target) else {
+            continue;
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let damage = (impact.damage.max(0.0).round() as u32).max(1);
+        health.value -= damage;
+    }
+}
+
+/// Counts down the lifetime of structures, despawning them when it runs out or they are
+/// destroyed. Destroyed structures spawn their destruction spell.
+pub(super) fn tick_structures(
+    mut commands: Commands,
+    mut structures: Query<(Entity, &mut Structure, &Health, &Transform)>,
+    casters: CasterStats,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    for (entity, mut structure, health, transform) in &mut structures {
+        structure.tick(time.delta_seconds());
+        let destroyed = health.is_dead();
+        if !destroyed && !structure.is_expired() {
+            continue;
+        }
+
+        commands.entity(entity).despawn_recursive();
+        ew_spawn_particles.send(SpawnSpellParticles {
+            spell_id: structure.spell_id.clone(),
+            attachment: ParticleAttachment::Impact,
+            anchor: ParticleAnchor::Position(transform.translation.truncate()),
+        });
+
+        if !destroyed {
+            continue;
+        }
+        let destruction_spell = game_data
+            .spells
+            .get(&structure.spell_id)
+            .and_then(|spell| spell.structure.as_ref())
+            .and_then(|data| data.destruction_spell.as_ref());
+        if let Some(spell_id) = destruction_spell {
+            spawn_triggered_spell(
+                &mut commands,
+                &game_data,
+                &casters,
+                TriggeredSpell {
+                    spell_id,
+                    caster: structure.owner,
+                    origin: transform.translation,
+                    already_hit: Vec::new(),
+                },
+                &mut ew_spawn_particles,
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Summoning spells spawn allies which follow their caster and attack nearby enemies.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::{Faction, ParticleAttachment, StatEnum},
+    events::{ParticleAnchor, SpawnSpellParticles},
+    Health, Layer, MovementBundle, SpellCooldowns, SpellSnapshot, StatBundle, Summoned,
+};
+
+use super::cast_spell::{spawn_spell_projectile, ProjectileLaunch};
+
+/// How far from the caster summons appear.
+const SUMMON_SPAWN_DISTANCE: f32 = 24.0;
+/// How close summons stay to their caster when there is nothing to attack.
+const SUMMON_FOLLOW_DISTANCE: f32 = 40.0;
+/// How far away summons notice enemies.
+const SUMMON_AGGRO_RANGE: f32 = 160.0;
+/// How close summons get to an enemy before they attack it.
+const SUMMON_ATTACK_RANGE: f32 = 80.0;
+/// Movement speed of summons without a `movementSpeed` stat.
+const SUMMON_BASE_SPEED: f32 = 2.0;
+/// Converts the `movementSpeed` stat (distance per frame, like the player's) into velocity.
+const SUMMON_SPEED_MULTIPLIER: f32 = 60.0;
+
+/// Sent when a summoning spell is cast.
+#[derive(Event, Debug, Clone)]
+pub struct SpawnSummons {
+    /// The unique id of the summoning spell.
+    pub spell_id: String,
+    /// The entity which cast the spell.
+    pub owner: Entity,
+    /// Where the spell was cast from.
+    pub origin: Vec3,
+}
+
+/// Spawns the allies of summoning spells.
+///
+/// Each caster can only have [`game_library::SummonData::max_count`] summons of a spell at once;
+/// summoning more replaces the oldest.
+pub(super) fn spawn_summons(
+    mut commands: Commands,
+    mut er_summon: EventReader<SpawnSummons>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    existing: Query<(Entity, &Summoned)>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    for event in er_summon.read() {
+        let Some(summon) = game_data
+            .spells
+            .get(&event.spell_id)
+            .and_then(|spell| spell.summon.as_ref())
+        else {
+            tracing::error!("spawn_summons: no summon data for {}", event.spell_id);
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&summon.sprite_tileset) else {
+            tracing::error!(
+                "spawn_summons: No texture atlas found for {} (spell:{})",
+                summon.sprite_tileset,
+                event.spell_id
+            );
+            continue;
+        };
+
+        let mut owned: Vec<(Entity, f32)> = existing
+            .iter()
+            .filter(|(_, summoned)| {
+                summoned.owner == event.owner && summoned.spell_id == event.spell_id
+            })
+            .map(|(entity, summoned)| (entity, summoned.summoned_at))
+            .collect();
+        owned.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let (spawned, replaced) = summon.plan_spawns(owned.len());
+        for (entity, _) in owned.iter().take(replaced) {
+            commands.entity(*entity).despawn_recursive();
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let angle_step = std::f32::consts::TAU / spawned.max(1) as f32;
+        for i in 0..spawned {
+            #[allow(clippy::cast_precision_loss)]
+            let offset = Vec2::from_angle(angle_step * i as f32) * SUMMON_SPAWN_DISTANCE;
+            let summoned = commands
+                .spawn((
+                    Summoned::new(
+                        event.owner,
+                        &event.spell_id,
+                        time.elapsed_seconds(),
+                        summon.lifetime,
+                    ),
+                    Faction::Ally,
+                    Health::new(summon.health),
+                    StatBundle::new(summon.stats.clone()),
+                    SpellCooldowns::default(),
+                    MovementBundle::default(),
+                    SpriteSheetBundle {
+                        texture_atlas: texture_atlas.clone(),
+                        sprite: summon.texture_atlas_index(),
+                        transform: Transform::from_translation(event.origin + offset.extend(0.0)),
+                        ..Default::default()
+                    },
+                    RigidBody::KinematicVelocityBased,
+                    Collider::capsule_y(6.0, 4.0),
+                    Layer::Foreground(i16::MAX - 1),
+                ))
+                .id();
+
+            ew_spawn_particles.send(SpawnSpellParticles {
+                spell_id: event.spell_id.clone(),
+                attachment: ParticleAttachment::Summon,
+                anchor: ParticleAnchor::Entity(summoned),
+            });
+        }
+    }
+}
+
+/// Moves summons towards the nearest enemy and attacks it with their attack spell, or follows
+/// their caster when there are no enemies nearby.
+#[allow(clippy::type_complexity)]
+pub(super) fn summon_behaviour(
+    mut commands: Commands,
+    mut summons: Query<(
+        Entity,
+        &Summoned,
+        &Transform,
+        &mut Velocity,
+        &mut SpellCooldowns,
+        Option<&StatBundle>,
+    )>,
+    owners: Query<&Transform, Without<Summoned>>,
+    characters: Query<(Entity, &Transform, &Health, Option<&Faction>), Without<Summoned>>,
+    mut ew_spawn_particles: EventWriter<SpawnSpellParticles>,
+    game_data: Res<GameData>,
+) {
+    for (entity, summoned, transform, mut velocity, mut cooldowns, stats) in &mut summons {
+        let position = transform.translation.truncate();
+        let speed = stats
+            .and_then(|stats| stats.get_stat(&StatEnum::MovementSpeed))
+            .map_or(SUMMON_BASE_SPEED, game_library::Stat::value)
+            * SUMMON_SPEED_MULTIPLIER;
+
+        let target = characters
+            .iter()
+            .filter(|(_, _, health, faction)| {
+                !health.is_dead() && !faction.copied().unwrap_or_default().is_friendly()
+            })
+            .map(|(target, target_transform, _, _)| {
+                (target, target_transform.translation.truncate())
+            })
+            .filter(|(_, target_position)| {
+                position.distance(*target_position) <= SUMMON_AGGRO_RANGE
+            })
+            .min_by(|a, b| {
+                position
+                    .distance_squared(a.1)
+                    .total_cmp(&position.distance_squared(b.1))
+            });
+
+        let Some((target, target_position)) = target else {
+            // nothing to attack, so stay close to the caster
+            velocity.linvel = owners.get(summoned.owner).map_or(Vec2::ZERO, |owner| {
+                let to_owner = owner.translation.truncate() - position;
+                if to_owner.length() > SUMMON_FOLLOW_DISTANCE {
+                    to_owner.normalize_or_zero() * speed
+                } else {
+                    Vec2::ZERO
+                }
+            });
+            continue;
+        };
+
+        let to_target = target_position - position;
+        if to_target.length() > SUMMON_ATTACK_RANGE {
+            velocity.linvel = to_target.normalize_or_zero() * speed;
+            continue;
+        }
+        velocity.linvel = Vec2::ZERO;
+
+        let Some(summon) = game_data
+            .spells
+            .get(&summoned.spell_id)
+            .and_then(|spell| spell.summon.as_ref())
+        else {
+            continue;
+        };
+        let Some(attack_id) = &summon.attack_spell else {
+            continue;
+        };
+        if !cooldowns.is_ready(attack_id) {
+            continue;
+        }
+        let Some(attack) = game_data.spells.get(attack_id) else {
+            tracing::error!("summon_behaviour: 404 {attack_id} not found");
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&attack.sprite_tileset) else {
+            tracing::error!(
+                "summon_behaviour: No texture atlas found for {} (spell:{attack_id})",
+                attack.sprite_tileset
+            );
+            continue;
+        };
+        cooldowns.start(attack_id, attack.cooldown);
+
+        let snapshot = SpellSnapshot::new(attack_id, attack, stats, []);
+        let launch = ProjectileLaunch {
+            caster: entity,
+            origin: transform.translation,
+            direction: to_target.normalize_or_zero(),
+            aim_distance: to_target.length(),
+            inherited_velocity: Vec2::ZERO,
+            already_hit: Vec::new(),
+        };
+        let projectile = spawn_spell_projectile(
+            &mut commands,
+            texture_atlas.clone(),
+            attack,
+            snapshot,
+            launch,
+        );
+        ew_spawn_particles.send(SpawnSpellParticles {
+            spell_id: attack_id.clone(),
+            attachment: ParticleAttachment::Projectile,
+            anchor: ParticleAnchor::Entity(projectile),
+        });
+        tracing::debug!("summon_behaviour: {entity:?} attacks {target:?} with {attack_id}");
+    }
+}
+
+/// Counts down the lifetime of summons, despawning them when it runs out, when they die, or when
+/// their caster is gone.
+pub(super) fn tick_summons(
+    mut commands: Commands,
+    mut summons: Query<(Entity, &mut Summoned, &Health)>,
+    owners: Query<(), Without<Summoned>>,
+    time: Res<Time>,
+) {
+    for (entity, mut summoned, health) in &mut summons {
+        summoned.tick(time.delta_seconds());
+        if summoned.is_expired() || health.is_dead() || owners.get(summoned.owner).is_err() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Moves spells along their trajectories.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    enums::{Faction, Trajectory},
+    Acceleration, Health, SpellCaster, SpellHits, SpellMotion, SpellSnapshot,
+};
+
+/// Steps every spell's [`SpellMotion`] and sets its velocity to match.
+///
+/// Homing spells turn towards the nearest valid target which they haven't hit yet, and orbiting
+/// and boomerang spells follow their caster (or where it was, if it's gone).
+#[allow(clippy::type_complexity)]
+pub(super) fn move_spells(
+    time: Res<Time>,
+    mut spells: Query<(
+        &mut Transform,
+        &mut Velocity,
+        &mut Acceleration,
+        &mut SpellMotion,
+        &SpellSnapshot,
+        &SpellCaster,
+        &SpellHits,
+        Option<&Faction>,
+    )>,
+    targets: Query<(Entity, &GlobalTransform, Option<&Faction>), With<Health>>,
+    casters: Query<&GlobalTransform>,
+    factions: Query<&Faction>,
+) {
+    for (
+        mut transform,
+        mut velocity,
+        mut acceleration,
+        mut motion,
+        snapshot,
+        caster,
+        hits,
+        faction,
+    ) in &mut spells
+    {
+        let position = transform.translation.truncate();
+        let caster_position = casters
+            .get(caster.0)
+            .map_or(motion.origin, |caster| caster.translation().truncate());
+        let target = if matches!(motion.trajectory, Trajectory::Homing { .. }) {
+            // reflected spells are on the side of whoever reflected them
+            let spell_faction = faction
+                .or_else(|| factions.get(caster.0).ok())
+                .copied()
+                .unwrap_or_default();
+            targets
+                .iter()
+                .filter(|(entity, _, faction)| {
+                    *entity != caster.0
+                        && !hits.has_hit(*entity)
+                        && spell_faction
+                            .can_affect(faction.copied().unwrap_or_default(), &snapshot.target)
+                })
+                .map(|(_, target_transform, _)| target_transform.translation().truncate())
+                .min_by(|a, b| position.distance(*a).total_cmp(&position.distance(*b)))
+        } else {
+            None
+        };
+
+        velocity.linvel = motion.step(
+            time.delta_seconds(),
+            position,
+            velocity.linvel,
+            caster_position,
+            target,
+        );
+
+        // accelerating spells keep accelerating the way they're going
+        if motion.trajectory.is_accelerated() {
+            acceleration.value = velocity.linvel.normalize_or_zero() * acceleration.value.length();
+        }
+        if velocity.linvel != Vec2::ZERO {
+            transform.rotation = Quat::from_rotation_z(velocity.linvel.y.atan2(velocity.linvel.x));
+        }
+    }
+}
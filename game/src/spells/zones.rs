@@ -0,0 +1,235 @@
+//! Zone spells place lingering areas which affect the characters inside them.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use game_library::{
+    data_loader::storage::GameData,
+    enums::{Faction, MagicType},
+    events::{ApplyDamage, ApplyHealing, ApplyKnockback, ApplyStatEffect},
+    ActiveEffects, Health, Layer, Structure, Zone, ZoneData, ZoneEffects,
+};
+
+/// Sent when a zone spell is cast.
+#[derive(Event, Debug, Clone)]
+pub struct PlaceZone {
+    /// The unique id of the zone spell.
+    pub spell_id: String,
+    /// The entity which cast the spell.
+    pub owner: Entity,
+    /// Where the zone is placed.
+    pub position: Vec3,
+}
+
+/// The events used to apply a zone's effects.
+#[derive(bevy::ecs::system::SystemParam)]
+pub(super) struct ZoneEffectWriters<'w> {
+    damage: EventWriter<'w, ApplyDamage>,
+    healing: EventWriter<'w, ApplyHealing>,
+    effects: EventWriter<'w, ApplyStatEffect>,
+    knockback: EventWriter<'w, ApplyKnockback>,
+}
+
+impl ZoneEffectWriters<'_> {
+    /// Applies the effects of one of the zone's triggers to `target` (knocking them back from the
+    /// zone's `center`).
+    fn apply(
+        &mut self,
+        effects: &ZoneEffects,
+        zone: &Zone,
+        center: Vec2,
+        magic: MagicType,
+        target: Entity,
+    ) {
+        if effects.damage > 0 {
+            self.damage.send(ApplyDamage {
+                target,
+                source: zone.owner,
+                amount: effects.damage,
+                magic,
+            });
+        }
+        if effects.healing > 0 {
+            self.healing.send(ApplyHealing {
+                target,
+                amount: effects.healing,
+            });
+        }
+        for effect in &effects.effects {
+            self.effects.send(ApplyStatEffect {
+                target,
+                effect: effect.clone(),
+            });
+        }
+        if let Some(knockback) = effects.knockback {
+            self.knockback.send(ApplyKnockback {
+                target,
+                origin: center,
+                knockback,
+            });
+        }
+    }
+}
+
+/// Places the zones of zone spells.
+///
+/// Zones are sensors, so they don't block anything; they only notice characters entering and
+/// leaving them.
+pub(super) fn place_zones(
+    mut commands: Commands,
+    mut er_zone: EventReader<PlaceZone>,
+    game_data: Res<GameData>,
+) {
+    for event in er_zone.read() {
+        let Some(zone) = game_data
+            .spells
+            .get(&event.spell_id)
+            .and_then(|spell| spell.zone.as_ref())
+        else {
+            tracing::error!("place_zones: no zone data for {}", event.spell_id);
+            continue;
+        };
+        let Some(texture_atlas) = game_data.tile_atlas.get(&zone.sprite_tileset) else {
+            tracing::error!(
+                "place_zones: No texture atlas found for {} (spell:{})",
+                zone.sprite_tileset,
+                event.spell_id
+            );
+            continue;
+        };
+
+        commands.spawn((
+            Zone::new(event.owner, &event.spell_id, zone),
+            SpriteSheetBundle {
+                texture_atlas: texture_atlas.clone(),
+                sprite: zone.texture_atlas_index(),
+                transform: Transform::from_translation(event.position),
+                ..Default::default()
+            },
+            zone.shape.collider(),
+            // zones are sensors which report characters entering and leaving them (including
+            // kinematic characters, which rapier skips by default)
+            Sensor,
+            ActiveEvents::COLLISION_EVENTS,
+            ActiveCollisionTypes::all(),
+            Layer::Background(10),
+        ));
+    }
+}
+
+/// Tracks the characters entering and leaving zones, applying the zones' `onEnter` and `onExit`
+/// effects (and their movement speed effect while inside).
+#[allow(clippy::type_complexity)]
+pub(super) fn zone_collisions(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut zones: Query<(&mut Zone, &Transform)>,
+    characters: Query<Option<&Faction>, (With<Health>, Without<Structure>)>,
+    factions: Query<&Faction>,
+    mut active_effects: Query<&mut ActiveEffects>,
+    mut writers: ZoneEffectWriters,
+    game_data: Res<GameData>,
+) {
+    for collision_event in collision_events.read() {
+        let (entity1, entity2, entered) = match collision_event {
+            CollisionEvent::Started(entity1, entity2, _) => (*entity1, *entity2, true),
+            CollisionEvent::Stopped(entity1, entity2, _) => (*entity1, *entity2, false),
+        };
+        let (zone_entity, other) = if zones.contains(entity1) {
+            (entity1, entity2)
+        } else {
+            (entity2, entity1)
+        };
+        let Ok((mut zone, transform)) = zones.get_mut(zone_entity) else {
+            continue;
+        };
+        let center = transform.translation.truncate();
+        let Some(spell) = game_data.spells.get(&zone.spell_id) else {
+            continue;
+        };
+        let Some(data) = &spell.zone else {
+            continue;
+        };
+
+        if entered {
+            let Ok(faction) = characters.get(other) else {
+                continue;
+            };
+            let owner_faction = factions.get(zone.owner).copied().unwrap_or_default();
+            if !owner_faction.can_affect(faction.copied().unwrap_or_default(), &spell.target)
+                || !zone.enter(other)
+            {
+                continue;
+            }
+            writers.apply(&data.on_enter, &zone, center, spell.magic, other);
+            if let Some(effect) = data.movement_effect(&zone.spell_id) {
+                writers.effects.send(ApplyStatEffect {
+                    target: other,
+                    effect,
+                });
+            }
+        } else {
+            if !zone.exit(other) {
+                continue;
+            }
+            writers.apply(&data.on_exit, &zone, center, spell.magic, other);
+            let spell_id = zone.spell_id.clone();
+            // another zone of the same spell keeps slowing them
+            let still_inside = zones
+                .iter()
+                .any(|(zone, _)| zone.spell_id == spell_id && zone.contains(other));
+            if !still_inside {
+                remove_movement_effect(&mut active_effects, &spell_id, other);
+            }
+        }
+    }
+}
+
+/// Counts down zones, applying their `onTick` effects to everyone inside and despawning them
+/// when their lifetime runs out.
+pub(super) fn tick_zones(
+    mut commands: Commands,
+    mut zones: Query<(Entity, &mut Zone, &Transform)>,
+    characters: Query<(), (With<Health>, Without<Structure>)>,
+    mut active_effects: Query<&mut ActiveEffects>,
+    mut writers: ZoneEffectWriters,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    for (entity, mut zone, transform) in &mut zones {
+        // characters which were despawned while inside never leave
+        zone.retain_occupants(|occupant| characters.contains(occupant));
+
+        let ticks = zone.tick(time.delta_seconds());
+        let spell = game_data.spells.get(&zone.spell_id);
+        let data = spell.and_then(|spell| spell.zone.as_ref());
+        if let (Some(spell), Some(data)) = (spell, data) {
+            for _ in 0..ticks {
+                for occupant in zone.occupants() {
+                    writers.apply(
+                        &data.on_tick,
+                        &zone,
+                        transform.translation.truncate(),
+                        spell.magic,
+                        occupant,
+                    );
+                }
+            }
+        }
+
+        if zone.is_expired() {
+            for occupant in zone.occupants() {
+                remove_movement_effect(&mut active_effects, &zone.spell_id, occupant);
+            }
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Removes the movement speed effect of zones placed by the spell from `target`.
+fn remove_movement_effect(
+    active_effects: &mut Query<&mut ActiveEffects>,
+    spell_id: &str,
+    target: Entity,
+) {
+    if let Ok(mut active_effects) = active_effects.get_mut(target) {
+        active_effects.remove(&ZoneData::movement_effect_id(spell_id));
+    }
+}
@@ -0,0 +1,223 @@
+//! The `ActiveEffects` component, which tracks the stat effects currently applied to an entity.
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    enums::{StackingRule, StatEnum},
+    PeriodicEffect, Stat, StatBundle, StatEffect,
+};
+
+/// How close (in seconds) a periodic effect has to be to its next tick for it to tick.
+const TICK_TOLERANCE: f32 = 1e-4;
+
+/// A single [`StatEffect`] which is active on an entity.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ActiveEffect {
+    /// The effect which was applied.
+    pub effect: StatEffect,
+    /// How many times the effect is currently stacked.
+    pub stacks: u32,
+    /// The remaining duration of the effect in seconds.
+    pub remaining: f32,
+    /// Seconds until the effect's next periodic tick (if it has a [`PeriodicEffect`]).
+    pub until_tick: f32,
+}
+
+impl ActiveEffect {
+    /// Returns true if the effect has run out of time.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+/// The buffs and debuffs which are currently active on an entity.
+///
+/// Effects are applied with [`ActiveEffects::apply`] and counted down with [`ActiveEffects::tick`].
+/// Neither of those touch the entity's stats directly; instead [`ActiveEffects::sync_stats`] brings
+/// the entity's [`StatBundle`] in line with the active effects. Because the actual change made to
+/// each stat bonus is remembered, expired effects are reverted exactly, even when a stat bonus was
+/// clamped while the effect was active.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+pub struct ActiveEffects {
+    /// The active effects, in the order they were first applied.
+    effects: Vec<ActiveEffect>,
+    /// The change currently applied to each stat's bonus by these effects.
+    applied: HashMap<StatEnum, f32>,
+    /// Whether the effects changed since the last time the stats were synced.
+    dirty: bool,
+}
+
+impl ActiveEffects {
+    /// Apply an effect.
+    ///
+    /// If the effect (by `id`) is already active, a stack is added (up to `max_stacks`, where
+    /// `0` means no limit) and the duration is refreshed or extended according to the effect's
+    /// [`StackingRule`].
+    ///
+    /// Returns the number of stacks of the effect after applying it.
+    pub fn apply(&mut self, effect: &StatEffect) -> u32 {
+        self.dirty = true;
+        let duration = effect.duration_seconds();
+
+        let Some(active) = self.effects.iter_mut().find(|e| e.effect.id == effect.id) else {
+            self.effects.push(ActiveEffect {
+                effect: effect.clone(),
+                stacks: 1,
+                remaining: duration,
+                until_tick: effect
+                    .periodic
+                    .map_or(0.0, |periodic| periodic.interval_seconds()),
+            });
+            return 1;
+        };
+
+        if effect.max_stacks == 0 || active.stacks < effect.max_stacks {
+            active.stacks += 1;
+        }
+        match effect.stacking {
+            StackingRule::Refresh => active.remaining = duration,
+            StackingRule::Extend => active.remaining += duration,
+        }
+        active.effect = effect.clone();
+
+        active.stacks
+    }
+
+    /// Count down the periodic effects by `delta` seconds.
+    ///
+    /// Returns each periodic effect which ticked along with its total amount: the effect's
+    /// `amount` for every stack and every time it ticked. Effects tick for the last time when
+    /// they expire, so this should be called before [`ActiveEffects::tick`].
+    pub fn tick_periodic(&mut self, delta: f32) -> Vec<(PeriodicEffect, u32)> {
+        let mut ticked = Vec::new();
+        for active in &mut self.effects {
+            let Some(periodic) = active.effect.periodic else {
+                continue;
+            };
+            let interval = periodic.interval_seconds();
+            if interval <= 0.0 {
+                continue;
+            }
+            // the effect doesn't tick past its remaining duration
+            let elapsed = delta.min(active.remaining.max(0.0));
+            active.until_tick -= elapsed;
+            let mut ticks: u32 = 0;
+            // a little leeway so that rounding errors don't skip the tick an effect ends on
+            while active.until_tick <= TICK_TOLERANCE {
+                active.until_tick += interval;
+                ticks += 1;
+            }
+            if ticks > 0 {
+                let total = periodic
+                    .amount
+                    .saturating_mul(active.stacks)
+                    .saturating_mul(ticks);
+                ticked.push((periodic, total));
+            }
+        }
+        ticked
+    }
+
+    /// Count down the remaining duration of all effects by `delta` seconds.
+    ///
+    /// Any effects which expire are removed and returned.
+    pub fn tick(&mut self, delta: f32) -> Vec<StatEffect> {
+        for active in &mut self.effects {
+            active.remaining -= delta;
+        }
+
+        let (expired, active): (Vec<ActiveEffect>, Vec<ActiveEffect>) =
+            self.effects.drain(..).partition(ActiveEffect::is_expired);
+        self.effects = active;
+
+        if !expired.is_empty() {
+            self.dirty = true;
+        }
+        expired.into_iter().map(|e| e.effect).collect()
+    }
+
+    /// Remove an active effect by its `id`, regardless of its remaining duration.
+    pub fn remove(&mut self, id: &str) -> Option<ActiveEffect> {
+        let index = self.effects.iter().position(|e| e.effect.id == id)?;
+        self.dirty = true;
+        Some(self.effects.remove(index))
+    }
+
+    /// Remove all active effects, returning them.
+    pub fn clear(&mut self) -> Vec<ActiveEffect> {
+        self.dirty = true;
+        self.effects.drain(..).collect()
+    }
+
+    /// Get an active effect by its `id`.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&ActiveEffect> {
+        self.effects.iter().find(|e| e.effect.id == id)
+    }
+
+    /// The number of stacks of an effect, or 0 if it is not active.
+    #[must_use]
+    pub fn stacks(&self, id: &str) -> u32 {
+        self.get(id).map_or(0, |e| e.stacks)
+    }
+
+    /// Returns an iterator over the active effects.
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveEffect> {
+        self.effects.iter()
+    }
+
+    /// Returns true if there are no active effects.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns true if the effects changed since the stats were last synced.
+    #[must_use]
+    pub const fn needs_sync(&self) -> bool {
+        self.dirty
+    }
+
+    /// The total change to each stat's bonus from all active effects (and their stacks).
+    #[must_use]
+    pub fn bonus_totals(&self) -> HashMap<StatEnum, f32> {
+        let mut totals = HashMap::new();
+        for active in &self.effects {
+            for (stat, bonus) in &active.effect.effects {
+                *totals.entry(stat.clone()).or_insert(0.0) +=
+                    (bonus.value() - 1.0) * active.stacks as f32;
+            }
+        }
+        totals
+    }
+
+    /// Bring the stat bonuses in `stats` in line with the active effects.
+    ///
+    /// Whatever was applied by the previous sync is removed first, then the current totals
+    /// are applied.
+    pub fn sync_stats(&mut self, stats: &mut StatBundle) {
+        self.revert_stats(stats);
+
+        for (stat, total) in self.bonus_totals() {
+            let entry = stats
+                .stats
+                .entry(stat.clone())
+                .or_insert_with(Stat::default);
+            let before = entry.bonus();
+            entry.add_bonus(total);
+            self.applied.insert(stat, entry.bonus() - before);
+        }
+        self.dirty = false;
+    }
+
+    /// Remove everything these effects have applied to the stat bonuses in `stats`.
+    pub fn revert_stats(&mut self, stats: &mut StatBundle) {
+        for (stat, applied) in self.applied.drain() {
+            if let Some(entry) = stats.stats.get_mut(&stat) {
+                entry.add_bonus(-applied);
+            }
+        }
+        self.dirty = true;
+    }
+}
@@ -0,0 +1,13 @@
+//! Buffs and debuffs which are active on an entity.
+//!
+//! Spells (and later other things) carry [`crate::StatEffect`]s. When one of those is applied to
+//! an entity (by sending [`crate::events::ApplyStatEffect`]), it is tracked in that entity's
+//! [`ActiveEffects`] component, applied to its [`crate::StatBundle`] bonuses, and reverted when
+//! it expires.
+
+mod component;
+mod plugin;
+
+pub use component::{ActiveEffect, ActiveEffects};
+#[allow(clippy::module_name_repetitions)]
+pub use plugin::ActiveEffectsPlugin;
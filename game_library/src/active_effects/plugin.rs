@@ -0,0 +1,114 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    events::{ApplyDamage, ApplyHealing, ApplyStatEffect, StatEffectApplied, StatEffectExpired},
+    state::{Game, Overlay},
+    GameSet, StatBundle,
+};
+
+use super::ActiveEffects;
+
+/// Plugin which applies, counts down and reverts [`ActiveEffects`].
+#[allow(clippy::module_name_repetitions)]
+pub struct ActiveEffectsPlugin;
+
+impl Plugin for ActiveEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyStatEffect>()
+            .add_event::<StatEffectApplied>()
+            .add_event::<StatEffectExpired>()
+            .add_event::<ApplyDamage>()
+            .add_event::<ApplyHealing>();
+
+        app.add_systems(
+            Update,
+            (apply_stat_effects, tick_active_effects)
+                .chain()
+                .in_set(GameSet::EntityUpdate)
+                .run_if(in_state(Game::Playing).and_then(not(in_state(Overlay::Settings)))),
+        );
+    }
+}
+
+/// Adds any newly applied effects to the target's [`ActiveEffects`].
+fn apply_stat_effects(
+    mut commands: Commands,
+    mut er_apply: EventReader<ApplyStatEffect>,
+    mut ew_applied: EventWriter<StatEffectApplied>,
+    mut query: Query<Option<&mut ActiveEffects>>,
+) {
+    // Targets without `ActiveEffects` get one inserted; collect them first so that multiple
+    // effects applied in the same frame don't overwrite each other.
+    let mut inserted: HashMap<Entity, ActiveEffects> = HashMap::new();
+
+    for ApplyStatEffect { target, effect } in er_apply.read() {
+        let Ok(existing) = query.get_mut(*target) else {
+            tracing::warn!("apply_stat_effects: target {target:?} no longer exists");
+            continue;
+        };
+
+        let stacks = existing.map_or_else(
+            || inserted.entry(*target).or_default().apply(effect),
+            |mut active_effects| active_effects.apply(effect),
+        );
+
+        ew_applied.send(StatEffectApplied {
+            target: *target,
+            effect_id: effect.id.clone(),
+            stacks,
+        });
+    }
+
+    for (target, active_effects) in inserted {
+        commands.entity(target).insert(active_effects);
+    }
+}
+
+/// Counts down the active effects, does the damage and healing of periodic ones, removes expired
+/// ones, and keeps the stats in sync.
+///
+/// Periodic damage has no caster to blame, so the affected entity is its source.
+#[allow(clippy::needless_pass_by_value)]
+fn tick_active_effects(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ActiveEffects, &mut StatBundle)>,
+    mut ew_expired: EventWriter<StatEffectExpired>,
+    mut ew_damage: EventWriter<ApplyDamage>,
+    mut ew_healing: EventWriter<ApplyHealing>,
+) {
+    for (entity, mut active_effects, mut stats) in &mut query {
+        if active_effects.is_empty() && !active_effects.needs_sync() {
+            continue;
+        }
+
+        for (periodic, amount) in active_effects.tick_periodic(time.delta_seconds()) {
+            if amount == 0 {
+                continue;
+            }
+            if periodic.heals {
+                ew_healing.send(ApplyHealing {
+                    target: entity,
+                    amount,
+                });
+            } else {
+                ew_damage.send(ApplyDamage {
+                    target: entity,
+                    source: entity,
+                    amount,
+                    magic: periodic.magic,
+                });
+            }
+        }
+
+        for expired in active_effects.tick(time.delta_seconds()) {
+            ew_expired.send(StatEffectExpired {
+                target: entity,
+                effect_id: expired.id,
+            });
+        }
+
+        if active_effects.needs_sync() {
+            active_effects.sync_stats(&mut stats);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! The shape of an area placed on the ground by a spell (e.g. a wall or a zone).
+use bevy::{prelude::*, reflect::Reflect};
+use bevy_rapier2d::geometry::Collider;
+use serde::{Deserialize, Serialize};
+
+/// The shape of an area's collider, centered on where the area is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum AreaShape {
+    /// A circle (e.g. a pillar).
+    Circle {
+        /// The radius of the circle.
+        radius: f32,
+    },
+    /// A rectangle (e.g. a wall).
+    Rectangle {
+        /// The width of the rectangle.
+        width: f32,
+        /// The height of the rectangle.
+        height: f32,
+    },
+}
+
+impl Default for AreaShape {
+    fn default() -> Self {
+        Self::Circle { radius: 8.0 }
+    }
+}
+
+impl AreaShape {
+    /// The collider for the shape.
+    #[must_use]
+    pub fn collider(self) -> Collider {
+        match self {
+            Self::Circle { radius } => Collider::ball(radius),
+            Self::Rectangle { width, height } => Collider::cuboid(width / 2.0, height / 2.0),
+        }
+    }
+    /// Returns true if `point` is inside the shape when it's placed at `center`.
+    #[must_use]
+    pub fn contains(self, center: Vec2, point: Vec2) -> bool {
+        let offset = point - center;
+        match self {
+            Self::Circle { radius } => offset.length() <= radius,
+            Self::Rectangle { width, height } => {
+                offset.x.abs() <= width / 2.0 && offset.y.abs() <= height / 2.0
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Picks targets for auto-aim.
+//!
+//! When auto-aim is turned on (see [`crate::settings::GameplaySettings`]), spells are aimed at
+//! a target picked by the [`AutoAimRule`] instead of at the cursor.
+
+use bevy::prelude::*;
+
+use crate::enums::AutoAimRule;
+
+/// A possible target for auto-aim.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoAimCandidate {
+    /// The target entity.
+    pub entity: Entity,
+    /// Where the target is.
+    pub position: Vec2,
+    /// The target's remaining health (between 0.0 and 1.0).
+    pub health: f32,
+}
+
+/// The target auto-aim is currently aiming at, if any.
+///
+/// This is updated every frame while auto-aim is turned on, and cleared when it's off.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct AutoAimTarget {
+    /// The target entity.
+    pub entity: Option<Entity>,
+    /// Where the target is.
+    pub position: Option<Vec2>,
+}
+
+impl AutoAimTarget {
+    /// Set the target to the candidate (or clear it if `None`).
+    pub fn set(&mut self, candidate: Option<AutoAimCandidate>) {
+        self.entity = candidate.map(|c| c.entity);
+        self.position = candidate.map(|c| c.position);
+    }
+}
+
+impl AutoAimRule {
+    /// Picks a target from the candidates which are within `max_range` of `origin`.
+    ///
+    /// Ties are broken by distance, and then by entity, so the same target is picked given the
+    /// same candidates.
+    #[must_use]
+    pub fn pick(
+        self,
+        origin: Vec2,
+        max_range: f32,
+        candidates: impl IntoIterator<Item = AutoAimCandidate>,
+    ) -> Option<AutoAimCandidate> {
+        let key = |candidate: &AutoAimCandidate| {
+            let distance = origin.distance(candidate.position);
+            let primary = match self {
+                Self::Nearest => distance,
+                Self::LowestHealth => candidate.health,
+            };
+            (primary, distance, candidate.entity)
+        };
+
+        candidates
+            .into_iter()
+            .filter(|candidate| origin.distance(candidate.position) <= max_range)
+            .min_by(|a, b| {
+                let (a_primary, a_distance, a_entity) = key(a);
+                let (b_primary, b_distance, b_entity) = key(b);
+                a_primary
+                    .total_cmp(&b_primary)
+                    .then(a_distance.total_cmp(&b_distance))
+                    .then(a_entity.cmp(&b_entity))
+            })
+    }
+}
@@ -0,0 +1,181 @@
+//! Barrier data describes walls and shields which stop, absorb or reflect hostile spells (e.g. a
+//! magic mirror).
+//!
+//! Barrier data is part of the [`crate::SpellData`] (the `barrier` in the data files). Spells
+//! which place a structure make the structure a barrier; spells cast on the caster (`onSelf`)
+//! shield the caster for the barrier's `duration`. A barrier only affects spells of the other
+//! side, and only those with one of its `magic` types and `targets` (empty lists affect every
+//! spell).
+//!
+//! Absorbing barriers draw the damage of the spells they absorb from the `shield` pool they give
+//! their owner, and stop absorbing once it's used up.
+//!
+//! ```yaml
+//! barrier:
+//!   action: reflect
+//!   magic: [fire, ice]
+//!   reflectedDamage: 0.5
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+use crate::enums::{MagicType, SpellTarget};
+
+/// What a barrier does to the hostile spells it affects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum BarrierAction {
+    /// The spell is destroyed without doing anything.
+    #[default]
+    Destroy,
+    /// The spell's damage is drawn from the owner's [`Shield`]. The spell is destroyed if the
+    /// shield absorbs all of it, and passes through with the rest otherwise.
+    Absorb,
+    /// The spell bounces off the barrier, and is now cast by the barrier's owner.
+    Reflect,
+}
+
+/// Details about the barrier a spell raises.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct BarrierData {
+    /// What the barrier does to the spells it affects.
+    #[serde(default)]
+    pub action: BarrierAction,
+    /// The types of magic the barrier affects (empty affects all of them).
+    #[serde(default = "Vec::new")]
+    pub magic: Vec<MagicType>,
+    /// The targets of the spells the barrier affects (empty affects all of them).
+    #[serde(default = "Vec::new")]
+    pub targets: Vec<SpellTarget>,
+    /// The damage an absorbing barrier can absorb, added to its owner's [`Shield`].
+    #[serde(default)]
+    pub shield: u32,
+    /// Multiplier for the damage of reflected spells (before the owner's damage reflection).
+    #[serde(default = "barrier_defaults::reflected_damage")]
+    pub reflected_damage: f32,
+    /// How long (in seconds) a barrier shielding the caster lasts. Barriers placed as structures
+    /// last as long as the structure.
+    #[serde(default = "barrier_defaults::duration")]
+    pub duration: f32,
+}
+
+mod barrier_defaults {
+    pub(super) const fn reflected_damage() -> f32 {
+        1.0
+    }
+    pub(super) const fn duration() -> f32 {
+        5.0
+    }
+}
+
+impl Default for BarrierData {
+    fn default() -> Self {
+        Self {
+            action: BarrierAction::default(),
+            magic: Vec::new(),
+            targets: Vec::new(),
+            shield: 0,
+            reflected_damage: barrier_defaults::reflected_damage(),
+            duration: barrier_defaults::duration(),
+        }
+    }
+}
+
+impl BarrierData {
+    /// Returns true if the barrier affects spells of the magic type with the target.
+    #[must_use]
+    pub fn affects(&self, magic: MagicType, target: &SpellTarget) -> bool {
+        (self.magic.is_empty() || self.magic.contains(&magic))
+            && (self.targets.is_empty() || self.targets.contains(target))
+    }
+}
+
+/// A barrier raised by a spell, on a structure or shielding a character.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct Barrier {
+    /// The entity which raised it.
+    pub owner: Entity,
+    /// The unique id of the spell which raised it.
+    pub spell_id: String,
+    /// What the barrier does to the spells it affects.
+    pub data: BarrierData,
+    /// The remaining lifetime in seconds, if the barrier doesn't last as long as the entity it's
+    /// on.
+    pub remaining: Option<f32>,
+}
+
+impl Barrier {
+    /// Creates a barrier raised by `owner` which lasts as long as the entity it's on.
+    #[must_use]
+    pub fn new(owner: Entity, spell_id: impl Into<String>, data: BarrierData) -> Self {
+        Self {
+            owner,
+            spell_id: spell_id.into(),
+            data,
+            remaining: None,
+        }
+    }
+
+    /// Creates a barrier shielding `owner` for the barrier's duration.
+    #[must_use]
+    pub fn shielding(owner: Entity, spell_id: impl Into<String>, data: BarrierData) -> Self {
+        let remaining = Some(data.duration);
+        Self {
+            remaining,
+            ..Self::new(owner, spell_id, data)
+        }
+    }
+
+    /// Returns true if the barrier affects spells of the magic type with the target.
+    #[must_use]
+    pub fn affects(&self, magic: MagicType, target: &SpellTarget) -> bool {
+        self.data.affects(magic, target)
+    }
+
+    /// Counts down the remaining lifetime by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= delta;
+        }
+    }
+
+    /// Returns true if the barrier's lifetime has run out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining.is_some_and(|remaining| remaining <= 0.0)
+    }
+}
+
+/// The damage a character's absorbing barriers can still absorb.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+pub struct Shield {
+    /// How much damage is left in the pool.
+    pub remaining: u32,
+}
+
+impl Shield {
+    /// Creates a shield pool which can absorb `amount` damage.
+    #[must_use]
+    pub const fn new(amount: u32) -> Self {
+        Self { remaining: amount }
+    }
+
+    /// Adds `amount` to the pool.
+    pub fn add(&mut self, amount: u32) {
+        self.remaining = self.remaining.saturating_add(amount);
+    }
+
+    /// Absorbs up to `damage` from the pool, returning how much damage was absorbed.
+    pub fn absorb(&mut self, damage: u32) -> u32 {
+        let absorbed = damage.min(self.remaining);
+        self.remaining -= absorbed;
+        absorbed
+    }
+
+    /// Returns true if the pool is used up.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+}
@@ -0,0 +1,134 @@
+//! Beam data describes spells which fire a continuous beam from the caster (e.g. a flamethrower).
+//!
+//! Beam data is part of the [`crate::SpellData`] (the `beam` in the data files). Instead of
+//! launching a projectile, the spell raycasts from the caster toward where they aim (up to the
+//! spell's range) every frame. The beam stops at the first thing blocking it, and every
+//! `tickInterval` seconds whatever it touches is hit by the spell. The beam lasts for the
+//! spell's `duration`; channeled beams (`castType: channel`) also end when the cast is released.
+//!
+//! ```yaml
+//! castType: channel
+//! range: 96
+//! duration: 4
+//! beam:
+//!   width: 6
+//!   tickInterval: 0.25
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+/// Details about the beam a spell fires.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct BeamData {
+    /// How wide the beam is drawn.
+    #[serde(default = "beam_defaults::width")]
+    pub width: f32,
+    /// How often (in seconds) the beam hits what it touches.
+    #[serde(default = "beam_defaults::tick_interval")]
+    pub tick_interval: f32,
+}
+
+mod beam_defaults {
+    pub(super) const fn width() -> f32 {
+        4.0
+    }
+    pub(super) const fn tick_interval() -> f32 {
+        0.25
+    }
+}
+
+impl Default for BeamData {
+    fn default() -> Self {
+        Self {
+            width: beam_defaults::width(),
+            tick_interval: beam_defaults::tick_interval(),
+        }
+    }
+}
+
+/// A beam fired by a spell.
+///
+/// The beam starts at its caster and is re-aimed and re-cast every frame, so it follows the
+/// caster around.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct Beam {
+    /// The direction the beam is fired in (normalized).
+    pub direction: Vec2,
+    /// The max length of the beam.
+    pub range: f32,
+    /// The current length of the beam (up to the first thing blocking it).
+    pub length: f32,
+    /// What the beam is touching (the first thing blocking it), if anything.
+    pub target: Option<Entity>,
+    /// How wide the beam is drawn.
+    pub width: f32,
+    /// How often the beam hits what it touches, in seconds.
+    pub tick_interval: f32,
+    /// The time until the next hit, in seconds.
+    pub until_tick: f32,
+    /// Whether the beam ends when the cast is released.
+    pub channeled: bool,
+}
+
+impl Beam {
+    /// Creates a beam fired in `direction` which reaches up to `range`.
+    ///
+    /// The beam hits what it touches straight away, and then every `tickInterval` seconds.
+    #[must_use]
+    pub fn new(data: &BeamData, direction: Vec2, range: f32, channeled: bool) -> Self {
+        Self {
+            direction: direction.normalize_or_zero(),
+            range: range.max(0.0),
+            length: 0.0,
+            target: None,
+            width: data.width,
+            tick_interval: data.tick_interval,
+            until_tick: 0.0,
+            channeled,
+        }
+    }
+
+    /// Turns the beam to face `direction`. A zero direction keeps the current one.
+    pub fn aim(&mut self, direction: Vec2) {
+        let direction = direction.normalize_or_zero();
+        if direction != Vec2::ZERO {
+            self.direction = direction;
+        }
+    }
+
+    /// Sets what blocks the beam and how far away it is. When nothing blocks it, the beam
+    /// reaches its full range.
+    pub fn set_blocker(&mut self, blocker: Option<(Entity, f32)>) {
+        if let Some((entity, distance)) = blocker {
+            self.target = Some(entity);
+            self.length = distance.clamp(0.0, self.range);
+        } else {
+            self.target = None;
+            self.length = self.range;
+        }
+    }
+
+    /// Where the beam ends when it starts at `origin`.
+    #[must_use]
+    pub fn end(&self, origin: Vec2) -> Vec2 {
+        origin + self.direction * self.length
+    }
+
+    /// Counts down the time until the next hit by `delta` seconds.
+    ///
+    /// Returns how many times the beam hits (more than once if `delta` is longer than the tick
+    /// interval).
+    pub fn tick(&mut self, delta: f32) -> u32 {
+        if self.tick_interval <= 0.0 {
+            return 0;
+        }
+        self.until_tick -= delta;
+        let mut ticks = 0;
+        while self.until_tick <= 0.0 {
+            self.until_tick += self.tick_interval;
+            ticks += 1;
+        }
+        ticks
+    }
+}
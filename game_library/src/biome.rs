@@ -1,7 +1,7 @@
 //! Defines the `BiomeData` resource.
 use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use rand::{seq::SliceRandom, SeedableRng};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
 use std::hash::Hash;
 
 use crate::{
@@ -29,8 +29,17 @@ pub struct BiomeData {
     pub ground_tilesets: Vec<TilesetDetail>,
     /// Details about the various "single-tile" objects that can be placed in the biome.
     pub simple_objects: Vec<SimpleObjectDetail>,
+    /// The monsters which can spawn in the biome (along with the realm's monsters).
+    #[serde(default)]
+    pub monsters: Vec<MonsterDetail>,
 }
 
+/// The "objective" spawn weights of monsters are weighted against a total of `MONSTER_POOL`.
+///
+/// Each tile of the realm rolls once against the pool, so a monster with a weight of 5 spawns on
+/// about 1 in 200 tiles of its biome.
+pub const MONSTER_POOL: usize = 1000;
+
 impl BiomeData {
     /// Creates a new barren biome
     #[must_use]
@@ -42,6 +51,7 @@ impl BiomeData {
             latitude: Latitude::WarmTemperate,
             ground_tilesets: Vec::new(),
             simple_objects: Vec::new(),
+            monsters: Vec::new(),
         }
     }
     /// Return a random tile from the ground tilesets.
@@ -88,6 +98,25 @@ impl BiomeData {
 
         pool
     }
+
+    /// Return a random monster to spawn on a tile of the biome, if any.
+    ///
+    /// The biome's monsters and the `realm_monsters` (which spawn in every biome) are weighted
+    /// against a total of [`MONSTER_POOL`], so most tiles don't spawn a monster.
+    pub fn random_monster<'a, R: Rng>(
+        &'a self,
+        realm_monsters: &'a [MonsterDetail],
+        rng: &mut R,
+    ) -> Option<&'a str> {
+        let mut roll = rng.gen_range(0..MONSTER_POOL);
+        for monster in realm_monsters.iter().chain(&self.monsters) {
+            if roll < monster.weight {
+                return Some(monster.id.as_str());
+            }
+            roll -= monster.weight;
+        }
+        None
+    }
 }
 
 /// Details about the tileset for the realm.
@@ -129,3 +158,14 @@ pub struct SimpleObjectDetail {
     /// a total of [`crate::noise::OBJECT_POOL`] to be spawned in the world.
     pub weight: usize,
 }
+
+/// Details about a monster which can spawn in the biome (or realm).
+#[derive(Debug, Clone, Reflect, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterDetail {
+    /// The unique identifier for the monster.
+    pub id: String,
+    /// The "objective" weight for the monster. This is some value that is weighted against
+    /// a total of [`MONSTER_POOL`] to be spawned on each tile.
+    pub weight: usize,
+}
@@ -0,0 +1,114 @@
+//! Chain data describes spells which arc from the target they hit to other targets nearby (e.g.
+//! lightning jumping between enemies).
+//!
+//! Chain data is part of the [`crate::SpellData`] (the `chain` in the data files). When the spell
+//! hits a character, it jumps to the closest valid target within `jumpRadius` of the last one,
+//! up to `maxJumps` times. It never hits the same target twice, and each jump does `falloff`
+//! times the damage of the one before.
+//!
+//! ```yaml
+//! chain:
+//!   maxJumps: 4
+//!   jumpRadius: 80
+//!   falloff: 0.75
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+/// Details about how a spell chains between targets.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainData {
+    /// How many times the spell jumps after the first hit.
+    #[serde(default = "chain_defaults::max_jumps")]
+    pub max_jumps: u32,
+    /// How far (in pixels) the spell can jump from one target to the next.
+    #[serde(default = "chain_defaults::jump_radius")]
+    pub jump_radius: f32,
+    /// Multiplier for the damage of each jump (compared to the jump before it).
+    #[serde(default = "chain_defaults::falloff")]
+    pub falloff: f32,
+}
+
+mod chain_defaults {
+    pub(super) const fn max_jumps() -> u32 {
+        3
+    }
+    pub(super) const fn jump_radius() -> f32 {
+        64.0
+    }
+    pub(super) const fn falloff() -> f32 {
+        0.7
+    }
+}
+
+impl Default for ChainData {
+    fn default() -> Self {
+        Self {
+            max_jumps: chain_defaults::max_jumps(),
+            jump_radius: chain_defaults::jump_radius(),
+            falloff: chain_defaults::falloff(),
+        }
+    }
+}
+
+impl ChainData {
+    /// The damage done by the given jump, when the first hit does `damage` (jump 0 is the first
+    /// hit).
+    #[must_use]
+    pub fn jump_damage(&self, damage: f32, jump: u32) -> f32 {
+        let jump = i32::try_from(jump).unwrap_or(i32::MAX);
+        damage * self.falloff.max(0.0).powi(jump)
+    }
+
+    /// Picks the target the spell jumps to from `from`: the closest of the `candidates` within
+    /// the jump radius which hasn't been hit yet.
+    ///
+    /// When several candidates are equally close, the first of them is picked.
+    #[must_use]
+    pub fn next_target(
+        &self,
+        from: Vec2,
+        candidates: &[(Entity, Vec2)],
+        hit: &[Entity],
+    ) -> Option<(Entity, Vec2)> {
+        candidates
+            .iter()
+            .filter(|(entity, _)| !hit.contains(entity))
+            .map(|(entity, position)| (*entity, *position, from.distance(*position)))
+            .filter(|(_, _, distance)| *distance <= self.jump_radius)
+            .fold(
+                None,
+                |closest: Option<(Entity, Vec2, f32)>, candidate| match closest {
+                    Some(closest) if closest.2 <= candidate.2 => Some(closest),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(entity, position, _)| (entity, position))
+    }
+
+    /// Works out the whole chain starting from the first target hit, returning each target the
+    /// spell jumps to (in order) and where it is.
+    ///
+    /// `candidates_near` returns the possible targets near a position.
+    #[must_use]
+    pub fn links(
+        &self,
+        first: (Entity, Vec2),
+        mut candidates_near: impl FnMut(Vec2) -> Vec<(Entity, Vec2)>,
+    ) -> Vec<(Entity, Vec2)> {
+        let mut hit = vec![first.0];
+        let mut from = first.1;
+        let mut links = Vec::new();
+        for _ in 0..self.max_jumps {
+            let candidates = candidates_near(from);
+            let Some((target, position)) = self.next_target(from, &candidates, &hit) else {
+                break;
+            };
+            hit.push(target);
+            links.push((target, position));
+            from = position;
+        }
+        links
+    }
+}
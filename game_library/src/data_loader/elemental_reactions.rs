@@ -0,0 +1,35 @@
+//! Loads elemental reactions from the data files and stores them in the game data.
+use bevy::prelude::*;
+
+use crate::InternalId;
+
+use super::{events::LoadedElementalReactionData, storage::GameData};
+
+/// System to load an elemental reaction.
+pub(super) fn load_elemental_reactions(
+    mut events: EventReader<LoadedElementalReactionData>,
+    mut game_data: ResMut<GameData>,
+) {
+    for event in events.read() {
+        let unique_id = &event.reaction_data.header.unique_id;
+        let mut reaction = event.reaction_data.data.clone();
+        reaction.update_internal_id();
+
+        let (first, second) = reaction.elements;
+        if first == second {
+            tracing::warn!("load_elemental_reactions: {unique_id} reacts {first} with itself");
+        }
+        if let Some((existing, _)) = game_data.elemental_reactions.between(first, second) {
+            if existing != unique_id {
+                tracing::warn!(
+                    "load_elemental_reactions: {unique_id} and {existing} both react {first} with {second}"
+                );
+            }
+        }
+
+        game_data
+            .elemental_reactions
+            .insert(unique_id.clone(), reaction);
+        tracing::debug!("load_elemental_reactions: loaded elemental reaction {unique_id}");
+    }
+}
@@ -9,7 +9,8 @@
 use bevy::ecs::event::Event;
 
 use crate::{
-    particle::Particle, realm_data::Realm, simple_object::SimpleObject, SpellData, Tileset,
+    particle::Particle, realm_data::Realm, simple_object::SimpleObject, ElementalReaction,
+    MonsterData, Perk, SpellData, SpellModifier, Tileset,
 };
 
 use super::DataFile;
@@ -48,3 +49,31 @@ pub struct LoadedSimpleObjectData {
     /// The realm data that was loaded.
     pub object_data: DataFile<SimpleObject>,
 }
+
+#[derive(Event)]
+/// Event that is fired when a spell modifier is loaded.
+pub struct LoadedSpellModifierData {
+    /// The spell modifier data that was loaded.
+    pub spell_modifier_data: DataFile<SpellModifier>,
+}
+
+#[derive(Event)]
+/// Event that is fired when a perk is loaded.
+pub struct LoadedPerkData {
+    /// The perk data that was loaded.
+    pub perk_data: DataFile<Perk>,
+}
+
+#[derive(Event)]
+/// Event that is fired when an elemental reaction is loaded.
+pub struct LoadedElementalReactionData {
+    /// The elemental reaction data that was loaded.
+    pub reaction_data: DataFile<ElementalReaction>,
+}
+
+#[derive(Event)]
+/// Event that is fired when a monster is loaded.
+pub struct LoadedMonsterData {
+    /// The monster data that was loaded.
+    pub monster_data: DataFile<MonsterData>,
+}
@@ -5,12 +5,14 @@ use walkdir::WalkDir;
 
 use crate::{
     data_loader::DATA_FILE_DIR, enums::GameSystem, particle::Particle, realm_data::Realm,
-    simple_object::SimpleObject, InternalId, SpellData, Tileset,
+    simple_object::SimpleObject, ElementalReaction, InternalId, MonsterData, Perk, SpellData,
+    SpellModifier, Tileset,
 };
 
 use super::{
     events::{
-        LoadedParticleData, LoadedRealmData, LoadedSimpleObjectData, LoadedSpellData,
+        LoadedElementalReactionData, LoadedMonsterData, LoadedParticleData, LoadedPerkData,
+        LoadedRealmData, LoadedSimpleObjectData, LoadedSpellData, LoadedSpellModifierData,
         LoadedTilesetData,
     },
     header_def::{DataFile, DataFileHeader},
@@ -74,13 +76,17 @@ pub fn read_data_file<T: serde::de::DeserializeOwned + Hash + InternalId>(
 ///     iv.  Sort the ingest data lists using any specified ordinal constraints
 /// 3. Add the data to the database in system load order (TDB)
 /// 4. Validate skill -> class, magic -> skill,class, and other relationships are valid
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 pub fn load_data_file_dir(
     mut ew_spell_df: EventWriter<LoadedSpellData>,
     mut ew_tileset_df: EventWriter<LoadedTilesetData>,
     mut ew_particle_df: EventWriter<LoadedParticleData>,
     mut ew_realm_df: EventWriter<LoadedRealmData>,
     mut ew_simple_object_df: EventWriter<LoadedSimpleObjectData>,
+    mut ew_spell_modifier_df: EventWriter<LoadedSpellModifierData>,
+    mut ew_perk_df: EventWriter<LoadedPerkData>,
+    mut ew_reaction_df: EventWriter<LoadedElementalReactionData>,
+    mut ew_monster_df: EventWriter<LoadedMonsterData>,
 ) {
     // let start = std::time::Instant::now();
 
@@ -102,6 +108,10 @@ pub fn load_data_file_dir(
     let mut particles_read: usize = 0;
     let mut realms_read: usize = 0;
     let mut simple_objects_read: usize = 0;
+    let mut spell_modifiers_read: usize = 0;
+    let mut perks_read: usize = 0;
+    let mut reactions_read: usize = 0;
+    let mut monsters_read: usize = 0;
 
     for d in &mut possible_ingests {
         let filepath = d.as_str();
@@ -182,16 +192,77 @@ pub fn load_data_file_dir(
                     ew_simple_object_df.send(LoadedSimpleObjectData { object_data });
                     simple_objects_read += 1;
                 }
+                GameSystem::SpellModifier => {
+                    let spell_modifier_data: DataFile<SpellModifier> =
+                        if let Some(d) = read_data_file(filepath) {
+                            d
+                        } else {
+                            tracing::debug!(
+                                "load_data_file_dir: failed to read spell modifier data from {}",
+                                header.unique_id
+                            );
+                            continue;
+                        };
+                    ew_spell_modifier_df.send(LoadedSpellModifierData {
+                        spell_modifier_data,
+                    });
+                    spell_modifiers_read += 1;
+                }
+                GameSystem::Perk => {
+                    let perk_data: DataFile<Perk> = if let Some(d) = read_data_file(filepath) {
+                        d
+                    } else {
+                        tracing::debug!(
+                            "load_data_file_dir: failed to read perk data from {}",
+                            header.unique_id
+                        );
+                        continue;
+                    };
+                    ew_perk_df.send(LoadedPerkData { perk_data });
+                    perks_read += 1;
+                }
+                GameSystem::ElementalReaction => {
+                    let reaction_data: DataFile<ElementalReaction> =
+                        if let Some(d) = read_data_file(filepath) {
+                            d
+                        } else {
+                            tracing::debug!(
+                            "load_data_file_dir: failed to read elemental reaction data from {}",
+                            header.unique_id
+                        );
+                            continue;
+                        };
+                    ew_reaction_df.send(LoadedElementalReactionData { reaction_data });
+                    reactions_read += 1;
+                }
+                GameSystem::Monster => {
+                    let monster_data: DataFile<MonsterData> =
+                        if let Some(d) = read_data_file(filepath) {
+                            d
+                        } else {
+                            tracing::debug!(
+                                "load_data_file_dir: failed to read monster data from {}",
+                                header.unique_id
+                            );
+                            continue;
+                        };
+                    ew_monster_df.send(LoadedMonsterData { monster_data });
+                    monsters_read += 1;
+                }
             }
         }
     }
     // let duration = start.elapsed();
     tracing::info!(
-        "loaded {} spells, {} tilesets, {} particles, {} realms, {} simple objects",
+        "loaded {} spells, {} tilesets, {} particles, {} realms, {} simple objects, {} spell modifiers, {} perks, {} elemental reactions, {} monsters",
         spells_read,
         tilesets_read,
         particles_read,
         realms_read,
-        simple_objects_read
+        simple_objects_read,
+        spell_modifiers_read,
+        perks_read,
+        reactions_read,
+        monsters_read
     );
 }
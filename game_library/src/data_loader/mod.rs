@@ -6,12 +6,16 @@
 pub mod events;
 pub mod storage;
 
+mod elemental_reactions;
 mod header_def;
 mod loader;
+mod monsters;
 mod particles;
+mod perks;
 mod plugin;
 mod realms;
 mod simple_objects;
+mod spell_modifiers;
 mod spells;
 mod tilesets;
 
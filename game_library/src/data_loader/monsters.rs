@@ -0,0 +1,25 @@
+//! Loads monsters from the data files and stores them in the game data.
+use bevy::prelude::*;
+
+use crate::InternalId;
+
+use super::{events::LoadedMonsterData, storage::GameData};
+
+/// System to load a monster.
+pub(super) fn load_monsters(
+    mut events: EventReader<LoadedMonsterData>,
+    mut game_data: ResMut<GameData>,
+) {
+    for event in events.read() {
+        let unique_id = &event.monster_data.header.unique_id;
+        let mut monster = event.monster_data.data.clone();
+        monster.update_internal_id();
+
+        if monster.health == 0 {
+            tracing::warn!("load_monsters: {unique_id} has no health and is defeated on spawn");
+        }
+
+        game_data.monsters.insert(unique_id.clone(), monster);
+        tracing::debug!("load_monsters: loaded monster {unique_id}");
+    }
+}
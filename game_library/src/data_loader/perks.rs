@@ -0,0 +1,22 @@
+//! Loads perks from the data files and stores them in the game data.
+use bevy::prelude::*;
+
+use crate::InternalId;
+
+use super::{events::LoadedPerkData, storage::GameData};
+
+/// System to load a perk.
+pub(super) fn load_perks(mut events: EventReader<LoadedPerkData>, mut game_data: ResMut<GameData>) {
+    for event in events.read() {
+        let unique_id = &event.perk_data.header.unique_id;
+        let mut perk = event.perk_data.data.clone();
+        perk.update_internal_id();
+
+        if perk.prerequisites.contains(unique_id) {
+            tracing::warn!("load_perks: {unique_id} is its own prerequisite");
+        }
+
+        game_data.perks.insert(unique_id.clone(), perk);
+        tracing::debug!("load_perks: loaded perk {unique_id}");
+    }
+}
@@ -1,14 +1,19 @@
 use bevy::prelude::*;
 
 use super::{
+    elemental_reactions::load_elemental_reactions,
     events::{
-        LoadedParticleData, LoadedRealmData, LoadedSimpleObjectData, LoadedSpellData,
+        LoadedElementalReactionData, LoadedMonsterData, LoadedParticleData, LoadedPerkData,
+        LoadedRealmData, LoadedSimpleObjectData, LoadedSpellData, LoadedSpellModifierData,
         LoadedTilesetData,
     },
     load_data_file_dir,
+    monsters::load_monsters,
     particles::load_particle_effects,
+    perks::load_perks,
     realms::load_realms,
     simple_objects::load_simple_objects,
+    spell_modifiers::load_spell_modifiers,
     spells::load_spells,
     storage::GameData,
     tilesets::load_tilesets,
@@ -26,7 +31,11 @@ impl Plugin for DataLoaderPlugin {
             .add_event::<LoadedTilesetData>()
             .add_event::<LoadedParticleData>()
             .add_event::<LoadedRealmData>()
-            .add_event::<LoadedSimpleObjectData>();
+            .add_event::<LoadedSimpleObjectData>()
+            .add_event::<LoadedSpellModifierData>()
+            .add_event::<LoadedPerkData>()
+            .add_event::<LoadedElementalReactionData>()
+            .add_event::<LoadedMonsterData>();
 
         // Set up the resources used and the systems to store the data
         app.init_resource::<GameData>().add_systems(
@@ -37,6 +46,10 @@ impl Plugin for DataLoaderPlugin {
                 load_particle_effects,
                 load_realms,
                 load_spells,
+                load_spell_modifiers,
+                load_perks,
+                load_elemental_reactions,
+                load_monsters,
             ),
         );
 
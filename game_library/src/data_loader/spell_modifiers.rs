@@ -0,0 +1,41 @@
+//! Loads spell modifiers from the data files and stores them in the game data.
+use bevy::prelude::*;
+
+use crate::InternalId;
+
+use super::{events::LoadedSpellModifierData, storage::GameData};
+
+/// System to load a spell modifier.
+pub(super) fn load_spell_modifiers(
+    mut events: EventReader<LoadedSpellModifierData>,
+    mut game_data: ResMut<GameData>,
+) {
+    for event in events.read() {
+        let unique_id = &event.spell_modifier_data.header.unique_id;
+        let mut modifier = event.spell_modifier_data.data.clone();
+        modifier.update_internal_id();
+
+        if let Some(split) = &modifier.split {
+            if split.count == 0 {
+                tracing::warn!(
+                    "load_spell_modifiers: {unique_id} splits into 0 spells, so it won't split"
+                );
+            }
+        }
+
+        // spells loaded after the modifier are checked when they load
+        for (spell_id, spell) in game_data.spells.iter() {
+            if modifier.homing_ignored_by(spell) {
+                tracing::warn!(
+                    "load_spell_modifiers: {unique_id} adds homing to {spell_id}, but its {} trajectory can't home",
+                    spell.trajectory
+                );
+            }
+        }
+
+        game_data
+            .spell_modifiers
+            .insert(unique_id.clone(), modifier);
+        tracing::debug!("load_spell_modifiers: loaded spell modifier {unique_id}");
+    }
+}
@@ -18,6 +18,16 @@ pub(super) fn load_spells(
         let mut spell = event.spell_data.data.clone();
         spell.update_internal_id();
 
+        // modifiers loaded after the spell are checked when they load
+        for (modifier_id, modifier) in game_data.spell_modifiers.iter() {
+            if modifier.homing_ignored_by(&spell) {
+                tracing::warn!(
+                    "load_spells: {modifier_id} adds homing to {unique_id}, but its {} trajectory can't home",
+                    spell.trajectory
+                );
+            }
+        }
+
         game_data.spells.insert(unique_id.clone(), spell);
         tracing::debug!(
             "load_spells: loaded spell {} as {}",
@@ -2,7 +2,11 @@
 use bevy::{prelude::*, utils::hashbrown::HashMap};
 use bevy_hanabi::EffectAsset;
 
-use crate::{realm_data::Realm, SimpleObject, SpellData};
+use crate::{
+    enums::{MagicType, SpellTag},
+    realm_data::Realm,
+    ElementalReaction, MonsterData, Perk, SimpleObject, SpellCondition, SpellData, SpellModifier,
+};
 
 /// The vault resource is a generic resource that holds data that is stored by a unique id.
 ///
@@ -45,6 +49,37 @@ impl<T> Vault<T> {
     pub fn iter_data(&self) -> impl Iterator<Item = &T> {
         self.data.values()
     }
+    /// Returns an iterator over the unique ids and data that have been loaded into the game.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &T)> {
+        self.data.iter()
+    }
+}
+
+impl Vault<SpellData> {
+    /// Returns an iterator over the spells (and their unique ids) which have the given tag.
+    pub fn with_tag(&self, tag: SpellTag) -> impl Iterator<Item = (&String, &SpellData)> {
+        self.iter().filter(move |(_, spell)| spell.has_tag(tag))
+    }
+    /// Returns an iterator over the spells (and their unique ids) which match the given condition.
+    pub fn matching<'a>(
+        &'a self,
+        condition: &'a SpellCondition,
+    ) -> impl Iterator<Item = (&'a String, &'a SpellData)> {
+        self.iter().filter(|(_, spell)| condition.matches(spell))
+    }
+}
+
+impl Vault<ElementalReaction> {
+    /// Returns the reaction (and its unique id) between the two types of magic (in either order).
+    #[must_use]
+    pub fn between(
+        &self,
+        first: MagicType,
+        second: MagicType,
+    ) -> Option<(&String, &ElementalReaction)> {
+        self.iter()
+            .find(|(_, reaction)| reaction.is_between(first, second))
+    }
 }
 
 /// The loaded game data resource holds all of the data that has been loaded into the game.
@@ -64,4 +99,12 @@ pub struct GameData {
     pub realms: Vault<Realm>,
     /// Loaded simple objects
     pub simple_objects: Vault<SimpleObject>,
+    /// Loaded spell modifiers
+    pub spell_modifiers: Vault<SpellModifier>,
+    /// Loaded perks
+    pub perks: Vault<Perk>,
+    /// Loaded elemental reactions
+    pub elemental_reactions: Vault<ElementalReaction>,
+    /// Loaded monsters
+    pub monsters: Vault<MonsterData>,
 }
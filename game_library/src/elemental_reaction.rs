@@ -0,0 +1,149 @@
+//! Elemental reactions happen when a spell hits a target carrying the element of another school
+//! of magic (see [`crate::ElementalStatus`]).
+//!
+//! The reactions are a data-driven table: each reaction names the two [`MagicType`]s which cause
+//! it (in either order), and what it does: apply [`StatEffect`]s to the target, multiply the
+//! damage of the hit, and/or spawn a spell where the target was hit. For example, Water and
+//! Lightning make Electrocute, and Fire and Ice make Steam.
+//!
+//! You can describe elemental reactions using YAML or JSON using the schema:
+//!
+//! ```yaml
+//! # $schema: "https://schemas.nwest.one/games/elementalist/elemental_reaction.json"
+//! ```
+use bevy::{prelude::*, reflect::Reflect, utils::HashMap};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, hash::Hash};
+
+use crate::{
+    data_loader::DataFile,
+    enums::{GameSystem, MagicType},
+    InternalId, StatEffect,
+};
+
+/// Details about an elemental reaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct ElementalReaction {
+    /// The internal ID of the reaction.
+    pub internal_id: Option<String>,
+    /// The name of the reaction.
+    pub name: String,
+    /// A short description of the reaction.
+    pub description: String,
+    /// The two types of magic which cause the reaction (in either order).
+    pub elements: (MagicType, MagicType),
+    /// Multiplier for the damage of the hit which caused the reaction (1.0 is no change).
+    #[serde(default = "reaction_defaults::damage_multiplier")]
+    pub damage_multiplier: f32,
+    /// Effects applied to the target.
+    #[serde(default = "Vec::new")]
+    pub effects: Vec<StatEffect>,
+    /// The unique id of a spell to spawn where the target was hit.
+    #[serde(default)]
+    pub spell: Option<String>,
+    /// Whether the reaction uses up the element the target was carrying.
+    #[serde(default = "reaction_defaults::consumes_element")]
+    pub consumes_element: bool,
+}
+
+mod reaction_defaults {
+    pub(super) const fn damage_multiplier() -> f32 {
+        1.0
+    }
+    pub(super) const fn consumes_element() -> bool {
+        true
+    }
+}
+
+impl ElementalReaction {
+    /// Returns true if the two types of magic (in either order) cause this reaction.
+    #[must_use]
+    pub fn is_between(&self, first: MagicType, second: MagicType) -> bool {
+        self.elements == (first, second) || self.elements == (second, first)
+    }
+}
+
+/// The damage multipliers of the elemental reactions caused this frame, by the spell entity whose
+/// impact caused the reaction and the entity it hit.
+///
+/// Each multiplier scales the damage of one impact (the one which caused the reaction), so other
+/// impacts of the same spell on the same target (e.g. further ticks of a beam) aren't scaled.
+#[derive(Debug, Default, Clone)]
+pub struct ReactionMultipliers(HashMap<(Entity, Entity), f32>);
+
+impl ReactionMultipliers {
+    /// Adds the damage multiplier of a reaction caused by `spell` hitting `target`.
+    pub fn add(&mut self, spell: Entity, target: Entity, multiplier: f32) {
+        self.0.insert((spell, target), multiplier);
+    }
+    /// The damage of `spell` hitting `target`, scaled by (and using up) the multiplier of the
+    /// reaction it caused.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn damage(&mut self, spell: Entity, target: Entity, damage: f32) -> u32 {
+        let multiplier = self.0.remove(&(spell, target)).unwrap_or(1.0);
+        (damage * multiplier).max(0.0).round() as u32
+    }
+}
+
+impl Default for ElementalReaction {
+    fn default() -> Self {
+        Self {
+            internal_id: None,
+            name: "Unnamed Reaction".to_string(),
+            description: "No description provided.".to_string(),
+            elements: (MagicType::Arcane, MagicType::Arcane),
+            damage_multiplier: reaction_defaults::damage_multiplier(),
+            effects: Vec::new(),
+            spell: None,
+            consumes_element: reaction_defaults::consumes_element(),
+        }
+    }
+}
+
+impl Hash for ElementalReaction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.elements.hash(state);
+    }
+}
+
+impl InternalId for ElementalReaction {
+    /// Update the reaction's internal ID.
+    fn update_internal_id(&mut self) {
+        self.internal_id = Some(self.get_internal_id());
+    }
+    /// Get the reaction's internal ID.
+    #[must_use]
+    fn get_internal_id(&self) -> String {
+        if self.internal_id.is_some() {
+            let id = self.internal_id.clone().unwrap_or_default();
+            if !id.is_empty() {
+                return id;
+            }
+        }
+
+        format!(
+            "reaction{}{}{}",
+            self.elements.0,
+            self.elements.1,
+            self.name.replace(' ', "")
+        )
+    }
+}
+
+impl<D: Hash + InternalId + 'static> TryFrom<&DataFile<D>> for ElementalReaction {
+    type Error = ();
+
+    fn try_from(data_file: &DataFile<D>) -> Result<Self, Self::Error> {
+        if data_file.header.system != GameSystem::ElementalReaction {
+            return Err(());
+        }
+
+        (&data_file.data as &dyn Any)
+            .downcast_ref::<Self>()
+            .cloned()
+            .ok_or(())
+    }
+}
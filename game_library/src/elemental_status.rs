@@ -0,0 +1,116 @@
+//! The elements lingering on an entity after it was hit by spells.
+//!
+//! Spells leave their type of magic on the targets they hit (e.g. a target hit by a water spell
+//! is soaked) for the spell's [`crate::SpellData::status_duration`]. When a spell of another
+//! school hits a target carrying an element, they may cause an [`ElementalReaction`].
+
+use bevy::prelude::*;
+
+use crate::{data_loader::storage::Vault, enums::MagicType, ElementalReaction};
+
+/// An element lingering on an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct LingeringElement {
+    /// The type of magic.
+    pub magic: MagicType,
+    /// The remaining duration of the element in seconds.
+    pub remaining: f32,
+}
+
+/// The elements lingering on an entity, in the order they were applied.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct ElementalStatus {
+    /// The lingering elements.
+    elements: Vec<LingeringElement>,
+}
+
+impl ElementalStatus {
+    /// Returns true if the element is lingering on the entity.
+    #[must_use]
+    pub fn has(&self, magic: MagicType) -> bool {
+        self.elements.iter().any(|element| element.magic == magic)
+    }
+
+    /// Returns an iterator over the lingering elements.
+    pub fn iter(&self) -> impl Iterator<Item = &LingeringElement> {
+        self.elements.iter()
+    }
+
+    /// Returns true if there are no lingering elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Apply an element for `duration` seconds. If the element is already lingering, its
+    /// duration is refreshed (if the new duration is longer).
+    pub fn apply(&mut self, magic: MagicType, duration: f32) {
+        if duration <= 0.0 {
+            return;
+        }
+        if let Some(element) = self.elements.iter_mut().find(|e| e.magic == magic) {
+            element.remaining = element.remaining.max(duration);
+        } else {
+            self.elements.push(LingeringElement {
+                magic,
+                remaining: duration,
+            });
+        }
+    }
+
+    /// Remove an element, returning true if it was lingering.
+    pub fn remove(&mut self, magic: MagicType) -> bool {
+        let before = self.elements.len();
+        self.elements.retain(|element| element.magic != magic);
+        self.elements.len() != before
+    }
+
+    /// Count down the remaining duration of all elements by `delta` seconds.
+    ///
+    /// Any elements which run out are removed and returned.
+    pub fn tick(&mut self, delta: f32) -> Vec<MagicType> {
+        let mut expired = Vec::new();
+        self.elements.retain_mut(|element| {
+            element.remaining -= delta;
+            if element.remaining <= 0.0 {
+                expired.push(element.magic);
+                false
+            } else {
+                true
+            }
+        });
+        expired
+    }
+
+    /// The entity is hit by a spell of the given type of magic.
+    ///
+    /// If a lingering element of another school reacts with the magic, that element and the
+    /// reaction (and its unique id) are returned. The first lingering element (in the order they were applied) with
+    /// a reaction is used, and it is removed if the reaction consumes it. Otherwise, the magic is
+    /// applied as a lingering element for `duration` seconds.
+    pub fn hit<'a>(
+        &mut self,
+        magic: MagicType,
+        duration: f32,
+        reactions: &'a Vault<ElementalReaction>,
+    ) -> Option<(MagicType, &'a String, &'a ElementalReaction)> {
+        let reaction = self
+            .elements
+            .iter()
+            .filter(|element| element.magic != magic)
+            .find_map(|element| {
+                reactions
+                    .between(element.magic, magic)
+                    .map(|found| (element.magic, found))
+            });
+
+        let Some((lingering, (reaction_id, reaction))) = reaction else {
+            self.apply(magic, duration);
+            return None;
+        };
+        if reaction.consumes_element {
+            self.remove(lingering);
+        }
+        Some((lingering, reaction_id, reaction))
+    }
+}
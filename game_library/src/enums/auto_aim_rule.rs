@@ -0,0 +1,42 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// How auto-aim picks which target to aim spells at.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum AutoAimRule {
+    /// Aim at the closest hostile.
+    #[default]
+    Nearest,
+    /// Aim at the hostile with the lowest health (as a percentage of their max health).
+    LowestHealth,
+}
+
+impl AutoAimRule {
+    /// Rotates to the next rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use game_library::enums::AutoAimRule;
+    ///
+    /// assert_eq!(AutoAimRule::Nearest.next(), AutoAimRule::LowestHealth);
+    /// assert_eq!(AutoAimRule::LowestHealth.next(), AutoAimRule::Nearest);
+    /// ```
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Nearest => Self::LowestHealth,
+            Self::LowestHealth => Self::Nearest,
+        }
+    }
+}
+
+impl std::fmt::Display for AutoAimRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nearest => write!(f, "Nearest"),
+            Self::LowestHealth => write!(f, "Lowest Health"),
+        }
+    }
+}
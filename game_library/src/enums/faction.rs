@@ -0,0 +1,59 @@
+use bevy::{ecs::component::Component, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+use super::SpellTarget;
+
+/// Which side a character is on.
+///
+/// Spells use this (with their [`SpellTarget`]) to decide who they affect. Characters without a
+/// faction are treated as enemies.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Serialize, Deserialize, Reflect,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Faction {
+    /// The player.
+    Player,
+    /// Fighting for the player (e.g. summoned minions).
+    Ally,
+    /// Fighting against the player.
+    #[default]
+    Enemy,
+}
+
+impl Faction {
+    /// Returns true if the faction is on the player's side.
+    #[must_use]
+    pub const fn is_friendly(self) -> bool {
+        matches!(self, Self::Player | Self::Ally)
+    }
+
+    /// Returns true if the factions are on different sides.
+    #[must_use]
+    pub const fn is_hostile_to(self, other: Self) -> bool {
+        self.is_friendly() != other.is_friendly()
+    }
+
+    /// Returns true if a spell with the given target, cast by this faction, can affect a
+    /// character of the `other` faction.
+    ///
+    /// `Ally` and `Enemy` are relative to the caster (an enemy's `Enemy` spells affect the
+    /// player's side), while `Player` only ever affects the player.
+    #[must_use]
+    pub const fn can_affect(self, other: Self, target: &SpellTarget) -> bool {
+        match target {
+            SpellTarget::Any => true,
+            SpellTarget::Player => matches!(other, Self::Player),
+            SpellTarget::Ally => !self.is_hostile_to(other),
+            SpellTarget::Enemy => self.is_hostile_to(other),
+            SpellTarget::None => false,
+        }
+    }
+
+    /// Returns true if a spell with the given target, cast by the player's side, can affect a
+    /// character of this faction.
+    #[must_use]
+    pub const fn is_targeted_by(self, target: &SpellTarget) -> bool {
+        Self::Player.can_affect(self, target)
+    }
+}
@@ -15,6 +15,14 @@ pub enum GameSystem {
     Realm,
     /// The simple object system is responsible for loading simple objects
     SimpleObject,
+    /// Spell modifiers which change how spells behave when cast
+    SpellModifier,
+    /// Perks which can be allocated in a skill's perk tree
+    Perk,
+    /// Reactions between two types of magic
+    ElementalReaction,
+    /// Monsters which spawn in the realms
+    Monster,
 }
 
 /// The order in which the game systems should be loaded.
@@ -23,14 +31,16 @@ pub enum GameSystem {
 ///
 /// 1. Tilesets are loaded. This is because spells may reference tilesets.
 /// 2. Spells are loaded.
+/// 3. Spell modifiers are loaded. This is because they may reference spells.
+/// 4. Perks are loaded. This is because their spell modifiers match spells.
+/// 5. Elemental reactions are loaded. This is because they may reference spells.
+/// 6. Monsters are loaded. This is because they reference tilesets and spells.
 ///
 /// Upcoming systems that would influence this list:
 ///
 /// - Sprites
-/// - Skill perks / trees
-/// - Monsters
 #[allow(dead_code)]
-pub const ORDERED: [GameSystem; 5] = [
+pub const ORDERED: [GameSystem; 9] = [
     GameSystem::Tileset,
     // Simple objects can reference tilesets
     GameSystem::SimpleObject,
@@ -39,4 +49,12 @@ pub const ORDERED: [GameSystem; 5] = [
     GameSystem::Particle,
     // Spells reference tilesets
     GameSystem::Spell,
+    // Spell modifiers can reference spells (to split into)
+    GameSystem::SpellModifier,
+    // Perks can modify spells
+    GameSystem::Perk,
+    // Elemental reactions can spawn spells
+    GameSystem::ElementalReaction,
+    // Monsters reference tilesets and cast spells
+    GameSystem::Monster,
 ];
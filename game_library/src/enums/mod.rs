@@ -2,24 +2,34 @@
 
 pub mod biome;
 
+mod auto_aim_rule;
 mod cast_category;
 mod cast_slot;
 mod cast_type;
+mod faction;
 mod game_systems;
 mod magic;
 mod particle_attachment;
 mod skill;
 mod spell_collision;
+mod spell_tag;
 mod spell_target;
+mod stacking_rule;
 mod stat;
+mod trajectory;
 
+pub use auto_aim_rule::AutoAimRule;
 pub use cast_category::CastCategory;
 pub use cast_slot::CastSlot;
 pub use cast_type::CastType;
+pub use faction::Faction;
 pub use game_systems::GameSystem;
 pub use magic::MagicType;
 pub use particle_attachment::ParticleAttachment;
 pub use skill::Skill;
 pub use spell_collision::SpellCollision;
+pub use spell_tag::SpellTag;
 pub use spell_target::SpellTarget;
+pub use stacking_rule::StackingRule;
 pub use stat::StatEnum;
+pub use trajectory::Trajectory;
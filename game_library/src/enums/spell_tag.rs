@@ -0,0 +1,119 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// Tags (the `flags` in spell data files) which describe the general behavior of a spell.
+///
+/// These are used to query spells, and as match conditions for things which should only
+/// apply to some spells (e.g. "all projectile spells get +10% speed").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub enum SpellTag {
+    /// The spell affects an area.
+    Aoe,
+    /// The spell is cast on the caster.
+    #[serde(rename = "self")]
+    OnSelf,
+    /// The spell is cast at a target.
+    Target,
+    /// The spell launches a projectile.
+    Projectile,
+    /// The spell takes effect instantly.
+    Instant,
+    /// The spell seeks out its target.
+    Homing,
+    /// The spell is cone shaped.
+    Cone,
+    /// The spell is line shaped.
+    Line,
+    /// The spell is a continuous beam.
+    Beam,
+    /// The spell creates a wall.
+    Wall,
+    /// The spell is placed on the ground.
+    Ground,
+    /// The spell summons something.
+    Summon,
+    /// The spell applies a buff.
+    Buff,
+    /// The spell applies a debuff.
+    Debuff,
+    /// The spell heals.
+    Heal,
+    /// The spell deals damage.
+    Damage,
+    /// The spell moves the caster or its target.
+    Movement,
+    /// The spell is cast by touching the target.
+    Touch,
+    /// The spell is sustained over time.
+    Sustained,
+    /// The spell deals damage over time.
+    Dot,
+    /// The spell has a circular shape.
+    Circle,
+    /// The spell has a square shape.
+    Square,
+}
+
+impl SpellTag {
+    /// Returns an iterator over all the variants of `SpellTag`
+    pub fn variants() -> impl Iterator<Item = Self> {
+        [
+            Self::Aoe,
+            Self::OnSelf,
+            Self::Target,
+            Self::Projectile,
+            Self::Instant,
+            Self::Homing,
+            Self::Cone,
+            Self::Line,
+            Self::Beam,
+            Self::Wall,
+            Self::Ground,
+            Self::Summon,
+            Self::Buff,
+            Self::Debuff,
+            Self::Heal,
+            Self::Damage,
+            Self::Movement,
+            Self::Touch,
+            Self::Sustained,
+            Self::Dot,
+            Self::Circle,
+            Self::Square,
+        ]
+        .iter()
+        .copied()
+    }
+}
+
+impl std::fmt::Display for SpellTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Aoe => "Area of Effect",
+            Self::OnSelf => "Self",
+            Self::Target => "Target",
+            Self::Projectile => "Projectile",
+            Self::Instant => "Instant",
+            Self::Homing => "Homing",
+            Self::Cone => "Cone",
+            Self::Line => "Line",
+            Self::Beam => "Beam",
+            Self::Wall => "Wall",
+            Self::Ground => "Ground",
+            Self::Summon => "Summon",
+            Self::Buff => "Buff",
+            Self::Debuff => "Debuff",
+            Self::Heal => "Heal",
+            Self::Damage => "Damage",
+            Self::Movement => "Movement",
+            Self::Touch => "Touch",
+            Self::Sustained => "Sustained",
+            Self::Dot => "Damage over Time",
+            Self::Circle => "Circle",
+            Self::Square => "Square",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// How a [`crate::StatEffect`] behaves when it is applied to an entity which already
+/// has that effect active.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum StackingRule {
+    /// Add a stack (up to the max stacks) and reset the remaining duration to the
+    /// effect's full duration.
+    #[default]
+    Refresh,
+    /// Add a stack (up to the max stacks) and add the effect's full duration onto the
+    /// remaining duration.
+    Extend,
+}
@@ -0,0 +1,115 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// How a spell moves after it's cast.
+///
+/// In the data files this is an object with a `type` and the options for that type, e.g.
+///
+/// ```yaml
+/// trajectory:
+///   type: sineWave
+///   amplitude: 12
+///   frequency: 2
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Trajectory {
+    /// The spell flies straight along the direction it was aimed, speeding up as it goes.
+    #[default]
+    Straight,
+    /// The spell arcs through the air and lands on the ground where it was aimed (up to its
+    /// range).
+    Arc {
+        /// How high the spell arcs at the peak of its flight.
+        height: f32,
+    },
+    /// The spell turns towards the nearest target it can affect.
+    Homing {
+        /// How quickly the spell turns, in degrees per second.
+        turn_rate: f32,
+    },
+    /// The spell circles around its caster.
+    Orbit {
+        /// How far from the caster the spell circles.
+        radius: f32,
+        /// How quickly the spell circles, in degrees per second (negative is clockwise).
+        angular_speed: f32,
+    },
+    /// The spell flies out to its range, then comes back to its caster.
+    Boomerang,
+    /// The spell weaves from side to side along the direction it was aimed.
+    SineWave {
+        /// How far the spell weaves to each side.
+        amplitude: f32,
+        /// How many times the spell weaves back and forth per second.
+        frequency: f32,
+    },
+    /// The spell speeds up along the direction it was aimed, then stops in place.
+    AccelerateThenStop {
+        /// How much the spell speeds up per second.
+        acceleration: f32,
+        /// How long (in seconds) the spell moves before it stops.
+        stop_after: f32,
+    },
+}
+
+impl Trajectory {
+    /// Adds homing (in degrees per second) to the trajectory. Straight spells become homing
+    /// spells, homing spells turn faster, and other trajectories are unchanged (see
+    /// [`Trajectory::supports_homing`]).
+    #[must_use]
+    pub fn with_homing(self, turn_rate: f32) -> Self {
+        if turn_rate <= 0.0 {
+            return self;
+        }
+        match self {
+            Self::Straight => Self::Homing { turn_rate },
+            Self::Homing { turn_rate: base } => Self::Homing {
+                turn_rate: base + turn_rate,
+            },
+            other => other,
+        }
+    }
+
+    /// Returns true if homing can be added to the trajectory (see [`Trajectory::with_homing`]).
+    #[must_use]
+    pub const fn supports_homing(self) -> bool {
+        matches!(self, Self::Straight | Self::Homing { .. })
+    }
+
+    /// Returns true if the spell's velocity is driven by its acceleration (and capped by a max
+    /// speed). Other trajectories control the spell's velocity entirely.
+    #[must_use]
+    pub const fn is_accelerated(self) -> bool {
+        matches!(self, Self::Straight | Self::Homing { .. })
+    }
+
+    /// Returns true if the spell expires when it has travelled its full range. Arcs and
+    /// boomerangs use the range to decide where they land or turn around, and orbits last for
+    /// the spell's lifetime instead.
+    #[must_use]
+    pub const fn is_range_limited(self) -> bool {
+        !matches!(
+            self,
+            Self::Arc { .. } | Self::Orbit { .. } | Self::Boomerang
+        )
+    }
+}
+
+impl std::fmt::Display for Trajectory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Straight => write!(f, "Straight"),
+            Self::Arc { .. } => write!(f, "Arc"),
+            Self::Homing { .. } => write!(f, "Homing"),
+            Self::Orbit { .. } => write!(f, "Orbit"),
+            Self::Boomerang => write!(f, "Boomerang"),
+            Self::SineWave { .. } => write!(f, "Sine Wave"),
+            Self::AccelerateThenStop { .. } => write!(f, "Accelerate Then Stop"),
+        }
+    }
+}
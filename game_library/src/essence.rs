@@ -0,0 +1,82 @@
+//! Essence is the elemental resource gathered in the realms (see the game design document).
+//!
+//! Defeated monsters drop essence (see [`crate::MonsterData`]), which is collected in
+//! [`CollectedEssence`] until the avatar's run ends.
+//!
+//! ```yaml
+//! essence:
+//!   - magic: fire
+//!     amount: 3
+//!   - magic: nature
+//!     amount: 1
+//!     chance: 0.25
+//! ```
+use bevy::{prelude::*, reflect::Reflect, utils::HashMap};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::MagicType;
+
+/// Essence dropped when a monster is defeated.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct EssenceDrop {
+    /// The type of magic of the essence.
+    pub magic: MagicType,
+    /// How much essence is dropped.
+    pub amount: u32,
+    /// The chance (between 0 and 1) that the essence is dropped at all.
+    #[serde(default = "essence_defaults::chance")]
+    pub chance: f32,
+}
+
+mod essence_defaults {
+    pub(super) const fn chance() -> f32 {
+        1.0
+    }
+}
+
+impl EssenceDrop {
+    /// Rolls for the drop, returning the amount of essence dropped (0 if it isn't dropped).
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> u32 {
+        if self.chance >= 1.0 || rng.gen::<f32>() < self.chance {
+            self.amount
+        } else {
+            0
+        }
+    }
+}
+
+/// The essence collected by the player's avatar during its run in the realm.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+pub struct CollectedEssence {
+    /// The amount of essence collected of each type of magic.
+    essence: HashMap<MagicType, u32>,
+}
+
+impl CollectedEssence {
+    /// Adds collected essence.
+    pub fn add(&mut self, magic: MagicType, amount: u32) {
+        if amount == 0 {
+            return;
+        }
+        let collected = self.essence.entry(magic).or_insert(0);
+        *collected = collected.saturating_add(amount);
+    }
+    /// The amount of essence collected of the type of magic.
+    #[must_use]
+    pub fn get(&self, magic: MagicType) -> u32 {
+        self.essence.get(&magic).copied().unwrap_or_default()
+    }
+    /// The total amount of essence collected, of any type of magic.
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        self.essence
+            .values()
+            .fold(0, |total, amount| total.saturating_add(*amount))
+    }
+    /// Empties the collected essence (e.g. when a new run starts).
+    pub fn clear(&mut self) {
+        self.essence.clear();
+    }
+}
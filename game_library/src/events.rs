@@ -6,6 +6,11 @@
 
 use bevy::prelude::*;
 
+use crate::{
+    enums::{MagicType, ParticleAttachment, Skill},
+    KnockbackData, StatEffect,
+};
+
 /// Cast a spell. Sending this even will cause a spell to be cast.
 ///
 /// # Internal Value
@@ -21,3 +26,205 @@ use bevy::prelude::*;
 /// ```
 #[derive(Event)]
 pub struct CastSpell(pub String);
+
+/// Stop channeling a spell (e.g. because its cast button was released).
+///
+/// Channeled spells (like the beams of [`crate::SpellData::beam`] with a `channel` cast type) last
+/// until this is sent or their duration runs out. Spells which aren't channeled ignore this.
+///
+/// # Internal Value
+///
+/// * `String` - The `get_internal_id()` of the spell
+#[derive(Event)]
+pub struct StopChanneling(pub String);
+
+/// Apply a [`game_library::StatEffect`] to an entity.
+///
+/// The effect is added to the target's [`game_library::ActiveEffects`] (which is inserted if
+/// the target doesn't have it yet) and then applied to the target's [`game_library::StatBundle`].
+#[derive(Event, Debug, Clone)]
+pub struct ApplyStatEffect {
+    /// The entity to apply the effect to.
+    pub target: Entity,
+    /// The effect to apply.
+    pub effect: StatEffect,
+}
+
+/// Sent when a [`game_library::StatEffect`] has been applied (or re-applied) to an entity.
+#[derive(Event, Debug, Clone)]
+pub struct StatEffectApplied {
+    /// The entity the effect was applied to.
+    pub target: Entity,
+    /// The id of the effect.
+    pub effect_id: String,
+    /// The number of stacks of the effect after it was applied.
+    pub stacks: u32,
+}
+
+/// Sent when a [`game_library::StatEffect`] expires on an entity.
+#[derive(Event, Debug, Clone)]
+pub struct StatEffectExpired {
+    /// The entity the effect expired on.
+    pub target: Entity,
+    /// The id of the effect.
+    pub effect_id: String,
+}
+
+/// Damage an entity's [`game_library::Health`].
+#[derive(Event, Debug, Clone)]
+pub struct ApplyDamage {
+    /// The entity to damage.
+    pub target: Entity,
+    /// The entity responsible for the damage (e.g. the caster of the spell).
+    pub source: Entity,
+    /// How much damage is done.
+    pub amount: u32,
+    /// The type of magic doing the damage.
+    pub magic: MagicType,
+}
+
+/// Restore an entity's [`game_library::Health`].
+#[derive(Event, Debug, Clone)]
+pub struct ApplyHealing {
+    /// The entity to heal.
+    pub target: Entity,
+    /// How much health is restored.
+    pub amount: u32,
+}
+
+/// Knock back an entity (see [`crate::Knockback`]).
+#[derive(Event, Debug, Clone)]
+pub struct ApplyKnockback {
+    /// The entity to knock back.
+    pub target: Entity,
+    /// Where the knockback comes from (e.g. the caster of the spell or the center of a zone).
+    pub origin: Vec2,
+    /// How the entity is knocked back (before its stun resistance).
+    pub knockback: KnockbackData,
+}
+
+/// Sent when a polymorph ends and an entity changes back to its original form.
+#[derive(Event, Debug, Clone)]
+pub struct PolymorphEnded {
+    /// The entity which changed back.
+    pub target: Entity,
+    /// The unique id of the polymorph spell.
+    pub spell_id: String,
+}
+
+/// Sent when a spell hits a valid target (or an obstacle which stops it).
+///
+/// Entities which the spell can't affect (see [`crate::SpellData::target`]) don't cause
+/// impacts. A projectile only hits each target once, while a beam hits what it touches every
+/// tick.
+#[derive(Event, Debug, Clone)]
+pub struct SpellImpact {
+    /// The spell entity which hit the target.
+    pub spell: Entity,
+    /// The unique id of the spell.
+    pub spell_id: String,
+    /// The entity which cast the spell.
+    pub caster: Entity,
+    /// The entity which was hit.
+    pub target: Entity,
+    /// Where the spell hit the target.
+    pub point: Vec2,
+    /// The damage of the hit (after the caster's stats and the spell's modifiers).
+    pub damage: f32,
+    /// How many times a chaining spell jumped before this hit (0 for the hit which started the
+    /// chain, and for spells which don't chain).
+    pub jump: u32,
+}
+
+/// Why a spell expired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SpellExpiryReason {
+    /// The spell's [`game_library::SpellLifetime`] ran out.
+    Lifetime,
+    /// The spell travelled its full [`game_library::SpellTravel`] range.
+    Range,
+    /// The spell reached the end of its [`crate::enums::Trajectory`] (e.g. an arc landed or a
+    /// boomerang was caught).
+    Arrived,
+}
+
+/// Sent when a spell expires without hitting anything (i.e. it ran out of time or range).
+///
+/// This is distinct from the spell impacting something, so effects that happen when a spell
+/// fizzles out (like a fireball detonating at the end of its flight) can be triggered from it.
+/// The spell entity is despawned in the same frame this is sent.
+#[derive(Event, Debug, Clone)]
+pub struct SpellExpired {
+    /// The spell entity which expired.
+    pub spell: Entity,
+    /// The unique id of the spell.
+    pub spell_id: String,
+    /// The entity which cast the spell.
+    pub caster: Entity,
+    /// Where the spell was when it expired.
+    pub position: Vec2,
+    /// The damage the spell would have done (after the caster's stats and the spell's modifiers).
+    pub damage: f32,
+    /// Why the spell expired.
+    pub reason: SpellExpiryReason,
+}
+
+/// Where a spell's particle effect is spawned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticleAnchor {
+    /// The effect is attached to (and follows) the entity. It is despawned with the entity.
+    Entity(Entity),
+    /// The effect is placed in the world at the position.
+    Position(Vec2),
+}
+
+/// Spawn the particle effects of a spell which use the given [`ParticleAttachment`].
+///
+/// All of the spell's particles with a matching attachment are spawned at the anchor. If the
+/// spell has no particles for the attachment, nothing happens.
+#[derive(Event, Debug, Clone)]
+pub struct SpawnSpellParticles {
+    /// The unique id of the spell.
+    pub spell_id: String,
+    /// Which of the spell's particles to spawn.
+    pub attachment: ParticleAttachment,
+    /// Where to spawn them.
+    pub anchor: ParticleAnchor,
+}
+
+/// Sent when one of the player's [`game_library::Skills`] gains a level.
+///
+/// When a skill gains several levels at once, one event is sent for each level (in order).
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillLeveledUp {
+    /// The skill which leveled up.
+    pub skill: Skill,
+    /// The skill's new level.
+    pub new_level: u32,
+}
+
+/// Sent when a spell hitting a target causes an [`crate::ElementalReaction`].
+///
+/// The reaction's effects are applied to the target and its spell is spawned when this is sent;
+/// systems dealing damage for the hit should scale the damage of that impact (the one from the
+/// same `spell` on the same `target`) by the `damage_multiplier` (see
+/// [`crate::ReactionMultipliers`]).
+#[derive(Event, Debug, Clone)]
+pub struct ElementalReactionTriggered {
+    /// The unique id of the reaction.
+    pub reaction_id: String,
+    /// The element which was lingering on the target.
+    pub lingering: MagicType,
+    /// The magic of the spell which hit the target.
+    pub magic: MagicType,
+    /// The spell entity whose impact caused the reaction.
+    pub spell: Entity,
+    /// The entity which cast the spell.
+    pub caster: Entity,
+    /// The entity which was hit.
+    pub target: Entity,
+    /// Where the spell hit the target.
+    pub point: Vec2,
+    /// Multiplier for the damage of the hit.
+    pub damage_multiplier: f32,
+}
@@ -238,6 +238,18 @@ impl Xp {
         self.current_level += 1;
     }
 
+    /// Level up the entity as many times as its xp allows (any leftover xp rolls over to the
+    /// next level). Returns the number of levels gained.
+    pub fn level_up_pending(&mut self) -> u32 {
+        let mut levels = 0;
+        while self.total_xp_to_next_level() > 0 && self.can_level_up() {
+            self.value -= self.total_xp_to_next_level();
+            self.current_level += 1;
+            levels += 1;
+        }
+        levels
+    }
+
     /// Add some amount to the xp value
     pub fn add(&mut self, amount: u32) {
         self.value += amount;
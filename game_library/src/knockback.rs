@@ -0,0 +1,139 @@
+//! Knockback data describes how a spell pushes (or pulls) what it hits (e.g. a gust of wind).
+//!
+//! Knockback data is part of the [`crate::SpellData`] (the `knockback` in the data files), where
+//! it applies to the characters the spell hits, and of [`crate::ZoneEffects`], where it applies to
+//! the characters in the zone. Characters are pushed away from (or pulled toward) the caster, or
+//! the center of the zone, a total of `force` pixels. The push starts fast and slows down at the
+//! rate of `decay`.
+//!
+//! The force is reduced by the character's stun resistance: a stun resistance of 0.25 reduces it
+//! by 25%, and a stun resistance of 1 (or more) stops it entirely.
+//!
+//! ```yaml
+//! knockback:
+//!   force: 48
+//!   direction: toward
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+/// Which way knockback moves the characters it affects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum KnockbackDirection {
+    /// Pushed away from where the knockback came from.
+    #[default]
+    Away,
+    /// Pulled toward where the knockback came from.
+    Toward,
+}
+
+/// Details about how a spell knocks back what it affects.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct KnockbackData {
+    /// How far (in pixels) the knockback moves the characters it affects.
+    pub force: f32,
+    /// Which way the knockback moves them.
+    #[serde(default)]
+    pub direction: KnockbackDirection,
+    /// How quickly the knockback slows down (the fraction of its speed lost per second is
+    /// `1 - e^-decay`).
+    #[serde(default = "knockback_defaults::decay")]
+    pub decay: f32,
+}
+
+mod knockback_defaults {
+    pub(super) const fn decay() -> f32 {
+        8.0
+    }
+}
+
+impl Default for KnockbackData {
+    fn default() -> Self {
+        Self {
+            force: 0.0,
+            direction: KnockbackDirection::default(),
+            decay: knockback_defaults::decay(),
+        }
+    }
+}
+
+impl KnockbackData {
+    /// The force of the knockback after the affected character's stun resistance.
+    #[must_use]
+    pub fn mitigated_force(&self, stun_resistance: f32) -> f32 {
+        (self.force * (1.0 - stun_resistance.clamp(0.0, 1.0))).max(0.0)
+    }
+
+    /// The direction a character at `position` is moved by knockback coming from `origin`.
+    ///
+    /// Characters right at the origin aren't moved (the direction is zero).
+    #[must_use]
+    pub fn direction_from(&self, origin: Vec2, position: Vec2) -> Vec2 {
+        let away = (position - origin).normalize_or_zero();
+        match self.direction {
+            KnockbackDirection::Away => away,
+            KnockbackDirection::Toward => -away,
+        }
+    }
+
+    /// The initial velocity (in pixels per second) of a knockback with `force` (after stun
+    /// resistance) in `direction`, so that it moves the character `force` pixels in total.
+    #[must_use]
+    pub fn velocity(&self, direction: Vec2, force: f32) -> Vec2 {
+        direction.normalize_or_zero() * force * self.decay.max(0.0)
+    }
+}
+
+/// The knockback moving a kinematic character, slowing down until it stops.
+///
+/// Dynamic bodies are knocked back with an impulse instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+pub struct Knockback {
+    /// The current velocity (in pixels per second).
+    pub velocity: Vec2,
+    /// How quickly the knockback slows down (see [`KnockbackData::decay`]).
+    pub decay: f32,
+}
+
+impl Knockback {
+    /// The speed (in pixels per second) below which the knockback is finished.
+    pub const MIN_SPEED: f32 = 1.0;
+
+    /// Creates a knockback starting at `velocity`.
+    #[must_use]
+    pub const fn new(velocity: Vec2, decay: f32) -> Self {
+        Self { velocity, decay }
+    }
+
+    /// Adds another knockback to this one. The velocities add up, and the knockback slows down at
+    /// the rate of the stronger of the two.
+    pub fn add(&mut self, other: Self) {
+        if other.velocity.length_squared() > self.velocity.length_squared() {
+            self.decay = other.decay;
+        }
+        self.velocity += other.velocity;
+    }
+
+    /// Moves the knockback on by `delta` seconds, returning how far (in pixels) the character is
+    /// moved during that time.
+    pub fn step(&mut self, delta: f32) -> Vec2 {
+        if self.decay <= 0.0 {
+            // without any decay the knockback would never stop, so it's all done at once
+            let displacement = self.velocity * delta;
+            self.velocity = Vec2::ZERO;
+            return displacement;
+        }
+        let remaining = (-self.decay * delta).exp();
+        let displacement = self.velocity * (1.0 - remaining) / self.decay;
+        self.velocity *= remaining;
+        displacement
+    }
+
+    /// Returns true if the knockback has (all but) stopped.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.velocity.length() < Self::MIN_SPEED
+    }
+}
@@ -32,19 +32,35 @@ pub mod settings;
 pub mod state;
 
 mod acceleration;
+mod active_effects;
+mod area_shape;
 mod attribute;
+mod auto_aim_target;
+mod barrier_data;
+mod beam_data;
 mod biome;
 mod camera_scale;
+mod chain_data;
 mod cursor_position;
 mod depth_2d;
+mod elemental_reaction;
+mod elemental_status;
+mod essence;
 mod experience;
 mod health;
+mod knockback;
 mod mana;
 mod markers_to_biomes;
+mod max_speed;
+mod monster_behaviour;
+mod monster_data;
 mod movement_bundle;
 mod noise;
 mod particle;
+mod perk;
+mod perk_state;
 mod physics;
+mod polymorph;
 mod realm_data;
 mod schedule;
 mod shared_traits;
@@ -52,42 +68,87 @@ mod simple_object;
 mod skill;
 mod spell_bundle;
 mod spell_choices;
+mod spell_condition;
+mod spell_cooldowns;
 mod spell_data;
+mod spell_hits;
 mod spell_lifetime;
+mod spell_modifier;
+mod spell_modifier_choices;
+mod spell_motion;
+mod spell_requirements;
+mod spell_snapshot;
+mod spell_travel;
 mod stat;
 mod stat_bonus;
 mod stat_bundle;
 mod stat_effect;
+mod structure_data;
+mod summon_data;
 mod tileset;
+mod unlocked_spells;
 mod volume;
+mod zone_data;
 
 pub use acceleration::Acceleration;
+pub use active_effects::{ActiveEffect, ActiveEffects, ActiveEffectsPlugin};
+pub use area_shape::AreaShape;
 pub use attribute::Attribute;
-pub use biome::BiomeData;
+pub use auto_aim_target::{AutoAimCandidate, AutoAimTarget};
+pub use barrier_data::{Barrier, BarrierAction, BarrierData, Shield};
+pub use beam_data::{Beam, BeamData};
+pub use biome::{BiomeData, MonsterDetail, MONSTER_POOL};
 pub use camera_scale::CameraScaleLevel;
+pub use chain_data::ChainData;
 pub use cursor_position::CursorPosition;
 pub use depth_2d::{Layer, LayerPlugin};
+pub use elemental_reaction::{ElementalReaction, ReactionMultipliers};
+pub use elemental_status::{ElementalStatus, LingeringElement};
+pub use essence::{CollectedEssence, EssenceDrop};
 pub use experience::Xp;
 pub use health::Health;
+pub use knockback::{Knockback, KnockbackData, KnockbackDirection};
 pub use mana::Mana;
 pub use markers_to_biomes::MarkersToBiomes;
+pub use max_speed::MaxSpeed;
+pub use monster_behaviour::{
+    BehaviourAction, BehaviourData, BehaviourState, CombatBehaviour, IdleBehaviour, MonsterBrain,
+    Senses,
+};
+pub use monster_data::{Monster, MonsterData};
 pub use movement_bundle::MovementBundle;
 pub use noise::GeneratedMaps;
 pub use noise::GenerationSeed;
 pub use noise::NoisePlugin;
-pub use physics::PhysicsPlugin;
+pub use perk::Perk;
+pub use perk_state::{PerkError, PerkState};
+pub use physics::{collision_groups, PhysicsPlugin};
+pub use polymorph::{AnimationSet, PolymorphData, Polymorphed};
 pub use realm_data::Realm;
 pub use schedule::*;
 pub use shared_traits::InternalId;
 pub use simple_object::SimpleObject;
 pub use skill::Skills;
 pub use spell_bundle::SpellBundle;
-pub use spell_choices::SpellChoices;
+pub use spell_choices::{SpellChoiceError, SpellChoices};
+pub use spell_condition::{SpellCondition, SpellStatModifier, SpellStatModifiers};
+pub use spell_cooldowns::SpellCooldowns;
 pub use spell_data::SpellData;
+pub use spell_hits::{SpellCaster, SpellHits};
 pub use spell_lifetime::SpellLifetime;
+pub use spell_modifier::{SpellModifier, SpellSplit};
+pub use spell_modifier_choices::SpellModifierChoices;
+pub use spell_motion::{turn_towards, SpellMotion};
+pub use spell_requirements::SpellRequirements;
+pub use spell_snapshot::{spread_directions, SpellSnapshot};
+pub use spell_travel::SpellTravel;
 pub use stat::Stat;
 pub use stat_bonus::StatBonus;
 pub use stat_bundle::StatBundle;
-pub use stat_effect::StatEffect;
+pub use stat_effect::{PeriodicEffect, StatEffect};
+pub use structure_data::{ground_target, Structure, StructureData};
+pub use summon_data::{SummonData, Summoned};
 pub use tileset::Tileset;
+pub use unlocked_spells::{SpellUnlockError, UnlockedSpells};
 pub use volume::Volume;
+pub use zone_data::{Zone, ZoneData, ZoneEffects};
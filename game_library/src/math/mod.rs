@@ -1,5 +1,7 @@
 //! Math utilities for Elementalist and this game library.
 
+mod reflect;
 mod slope_vec;
 
+pub use reflect::reflect;
 pub use slope_vec::slope_vec;
@@ -0,0 +1,17 @@
+use bevy::math::Vec2;
+
+/// Reflects a vector off a surface with the given normal (like a ball bouncing off a wall)
+///
+/// # Arguments
+///
+/// * `vector` - The vector to reflect (e.g. a velocity)
+/// * `normal` - The normal of the surface (it doesn't need to be normalized)
+///
+/// # Returns
+///
+/// * `Vec2` - The reflected vector (the same vector if the normal is zero)
+#[must_use]
+pub fn reflect(vector: Vec2, normal: Vec2) -> Vec2 {
+    let normal = normal.normalize_or_zero();
+    vector - 2.0 * vector.dot(normal) * normal
+}
@@ -0,0 +1,36 @@
+//! Max speed component
+//!
+//! Caps the speed of an entity which is moved by a [`game_library::MovementBundle`]. Without
+//! it, an entity with a non-zero [`game_library::Acceleration`] keeps speeding up forever.
+
+use bevy::prelude::*;
+use bevy_inspector_egui::prelude::*;
+
+/// The maximum speed (length of the velocity) an entity can reach by accelerating
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default, Reflect, InspectorOptions)]
+#[reflect(InspectorOptions)]
+pub struct MaxSpeed {
+    /// The maximum speed
+    pub value: f32,
+}
+
+impl MaxSpeed {
+    /// Creates a new max speed component with the given value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use game_library::MaxSpeed;
+    ///
+    /// let max_speed = MaxSpeed::new(200.0);
+    /// ```
+    #[must_use]
+    pub const fn new(value: f32) -> Self {
+        Self { value }
+    }
+    /// Returns the velocity limited to the max speed (keeping its direction)
+    #[must_use]
+    pub fn clamp(&self, velocity: Vec2) -> Vec2 {
+        velocity.clamp_length_max(self.value.max(0.0))
+    }
+}
@@ -0,0 +1,336 @@
+//! Monster behaviour is a small state machine deciding how a monster moves and when it attacks.
+//!
+//! Each monster's data selects its behaviour (the `behaviour` in the monster data files): what it
+//! does while nothing hostile is around (wander near where it spawned, patrol a route, or stand
+//! still), how it fights once something hostile comes within its aggro radius (chase it down, or
+//! keep its distance and cast), and the health below which it flees instead.
+//!
+//! The [`MonsterBrain`] component holds the monster's current [`BehaviourState`] and its own
+//! seeded random number generator, so a monster spawned with the same seed always behaves the
+//! same way. [`MonsterBrain::tick`] doesn't touch the world: it takes what the monster senses and
+//! returns what it wants to do, which the game then applies.
+//!
+//! ```yaml
+//! behaviour:
+//!   idle: patrol
+//!   patrol:
+//!     - [32, 0]
+//!     - [0, 32]
+//!   combat: keepDistance
+//!   aggroRadius: 128
+//!   fleeBelow: 0.25
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// What a monster does while there is nothing hostile nearby.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum IdleBehaviour {
+    /// Wanders to random spots near where it spawned, pausing in between.
+    #[default]
+    Wander,
+    /// Walks its patrol route (monsters without a route wander instead).
+    Patrol,
+    /// Stands still.
+    Stand,
+}
+
+/// How a monster fights something hostile within its aggro radius.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub enum CombatBehaviour {
+    /// Chases its target until it is in range to attack.
+    #[default]
+    Chase,
+    /// Stays between its keep distance and its attack range, backing off if its target gets too
+    /// close.
+    KeepDistance,
+}
+
+/// Details about how a monster behaves.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct BehaviourData {
+    /// What the monster does while there is nothing hostile nearby.
+    #[serde(default)]
+    pub idle: IdleBehaviour,
+    /// How the monster fights.
+    #[serde(default)]
+    pub combat: CombatBehaviour,
+    /// How close (in pixels) something hostile has to be for the monster to notice it. Monsters
+    /// lose interest once their target is half again as far away.
+    #[serde(default = "behaviour_defaults::aggro_radius")]
+    pub aggro_radius: f32,
+    /// How close (in pixels) the monster has to be to attack its target.
+    #[serde(default = "behaviour_defaults::attack_range")]
+    pub attack_range: f32,
+    /// How close (in pixels) a monster which keeps its distance lets its target get.
+    #[serde(default = "behaviour_defaults::keep_distance")]
+    pub keep_distance: f32,
+    /// The fraction of health (between 0 and 1) below which the monster flees. 0 never flees.
+    #[serde(default)]
+    pub flee_below: f32,
+    /// How far (in pixels) from where it spawned the monster wanders.
+    #[serde(default = "behaviour_defaults::wander_radius")]
+    pub wander_radius: f32,
+    /// How long (in seconds, on average) the monster pauses between wandering.
+    #[serde(default = "behaviour_defaults::pause")]
+    pub pause: f32,
+    /// The patrol route, as offsets (in pixels) from where the monster spawned.
+    #[serde(default = "Vec::new")]
+    pub patrol: Vec<(f32, f32)>,
+}
+
+mod behaviour_defaults {
+    pub(super) const fn aggro_radius() -> f32 {
+        128.0
+    }
+    pub(super) const fn attack_range() -> f32 {
+        80.0
+    }
+    pub(super) const fn keep_distance() -> f32 {
+        56.0
+    }
+    pub(super) const fn wander_radius() -> f32 {
+        48.0
+    }
+    pub(super) const fn pause() -> f32 {
+        2.0
+    }
+}
+
+impl Default for BehaviourData {
+    fn default() -> Self {
+        Self {
+            idle: IdleBehaviour::default(),
+            combat: CombatBehaviour::default(),
+            aggro_radius: behaviour_defaults::aggro_radius(),
+            attack_range: behaviour_defaults::attack_range(),
+            keep_distance: behaviour_defaults::keep_distance(),
+            flee_below: 0.0,
+            wander_radius: behaviour_defaults::wander_radius(),
+            pause: behaviour_defaults::pause(),
+            patrol: Vec::new(),
+        }
+    }
+}
+
+/// The state a monster's behaviour is in.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum BehaviourState {
+    /// Pausing for the remaining seconds before wandering again.
+    Idle {
+        /// The seconds left before the monster wanders again.
+        wait: f32,
+    },
+    /// Walking to a random spot near where it spawned.
+    Wandering {
+        /// Where the monster is walking to.
+        destination: Vec2,
+    },
+    /// Walking its patrol route.
+    Patrolling,
+    /// Fighting its target (see [`CombatBehaviour`]).
+    Fighting,
+    /// Running away from its target.
+    Fleeing,
+}
+
+impl Default for BehaviourState {
+    fn default() -> Self {
+        Self::Idle { wait: 0.0 }
+    }
+}
+
+/// What a monster senses about the world, which decides what it does.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Senses {
+    /// Where the monster is.
+    pub position: Vec2,
+    /// The fraction of its health the monster has left (between 0 and 1).
+    pub health: f32,
+    /// Where the closest hostile character is, if there is one.
+    pub target: Option<Vec2>,
+}
+
+/// What a monster wants to do after thinking about what it senses.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BehaviourAction {
+    /// The direction the monster wants to move in (normalized, or zero to stand still).
+    pub movement: Vec2,
+    /// Where the monster wants to attack, if it is in range of its target.
+    pub attack: Option<Vec2>,
+}
+
+/// The state machine driving a monster's behaviour.
+#[derive(Debug, Clone, Component)]
+pub struct MonsterBrain {
+    /// The current state.
+    pub state: BehaviourState,
+    /// Where the monster spawned, which it wanders and patrols around.
+    pub home: Vec2,
+    /// The next waypoint of the patrol route, kept while the monster is busy fighting.
+    pub waypoint: usize,
+    /// The monster's own random number generator (for wandering).
+    rng: SmallRng,
+}
+
+impl MonsterBrain {
+    /// Monsters are considered to have arrived within this distance (in pixels).
+    pub const ARRIVE_DISTANCE: f32 = 2.0;
+    /// Monsters lose interest in targets this many times their aggro radius away.
+    pub const LEASH: f32 = 1.5;
+
+    /// Creates a brain for a monster spawned at `home`, with its random number generator seeded
+    /// by `seed`.
+    #[must_use]
+    pub fn new(home: Vec2, seed: u64) -> Self {
+        Self {
+            state: BehaviourState::default(),
+            home,
+            waypoint: 0,
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Moves the state machine on by `delta` seconds, returning what the monster wants to do.
+    pub fn tick(&mut self, data: &BehaviourData, senses: &Senses, delta: f32) -> BehaviourAction {
+        self.state = self.next_state(data, senses);
+
+        match self.state {
+            BehaviourState::Fighting => senses
+                .target
+                .map_or_else(BehaviourAction::default, |target| {
+                    fight(data, senses.position, target)
+                }),
+            BehaviourState::Fleeing => BehaviourAction {
+                movement: senses.target.map_or(Vec2::ZERO, |target| {
+                    (senses.position - target).normalize_or_zero()
+                }),
+                attack: None,
+            },
+            BehaviourState::Idle { wait } => {
+                let wait = wait - delta;
+                self.state = if wait <= 0.0 && data.idle != IdleBehaviour::Stand {
+                    BehaviourState::Wandering {
+                        destination: self.wander_destination(data),
+                    }
+                } else {
+                    BehaviourState::Idle {
+                        wait: wait.max(0.0),
+                    }
+                };
+                BehaviourAction::default()
+            }
+            BehaviourState::Wandering { destination } => {
+                if senses.position.distance(destination) <= Self::ARRIVE_DISTANCE {
+                    self.state = BehaviourState::Idle {
+                        wait: self.pause(data),
+                    };
+                    return BehaviourAction::default();
+                }
+                move_to(senses.position, destination)
+            }
+            BehaviourState::Patrolling => {
+                let Some(waypoint) = self.patrol_point(data) else {
+                    return BehaviourAction::default();
+                };
+                if senses.position.distance(waypoint) <= Self::ARRIVE_DISTANCE {
+                    self.waypoint = (self.waypoint + 1) % data.patrol.len();
+                }
+                self.patrol_point(data)
+                    .map_or_else(BehaviourAction::default, |waypoint| {
+                        move_to(senses.position, waypoint)
+                    })
+            }
+        }
+    }
+
+    /// Decides which state the monster should be in given what it senses.
+    fn next_state(&self, data: &BehaviourData, senses: &Senses) -> BehaviourState {
+        let in_combat = matches!(
+            self.state,
+            BehaviourState::Fighting | BehaviourState::Fleeing
+        );
+        let range = if in_combat {
+            data.aggro_radius * Self::LEASH
+        } else {
+            data.aggro_radius
+        };
+        let engaged = senses
+            .target
+            .is_some_and(|target| senses.position.distance(target) <= range);
+
+        if engaged {
+            return if senses.health < data.flee_below {
+                BehaviourState::Fleeing
+            } else {
+                BehaviourState::Fighting
+            };
+        }
+        match (self.state, Self::idle_state(data)) {
+            // keep pausing and wandering where they left off
+            (
+                BehaviourState::Idle { .. } | BehaviourState::Wandering { .. },
+                BehaviourState::Idle { .. },
+            ) => self.state,
+            // back to patrolling (or idling) after a fight
+            (_, idle) => idle,
+        }
+    }
+
+    /// The state a monster starts its idle behaviour in.
+    fn idle_state(data: &BehaviourData) -> BehaviourState {
+        if data.idle == IdleBehaviour::Patrol && !data.patrol.is_empty() {
+            BehaviourState::Patrolling
+        } else {
+            BehaviourState::Idle { wait: 0.0 }
+        }
+    }
+
+    /// Where the current waypoint of the patrol route is.
+    fn patrol_point(&self, data: &BehaviourData) -> Option<Vec2> {
+        data.patrol
+            .get(self.waypoint % data.patrol.len().max(1))
+            .map(|(x, y)| self.home + Vec2::new(*x, *y))
+    }
+
+    /// A random spot within the wander radius of home.
+    fn wander_destination(&mut self, data: &BehaviourData) -> Vec2 {
+        let angle = self.rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = self.rng.gen::<f32>().sqrt() * data.wander_radius.max(0.0);
+        self.home + Vec2::from_angle(angle) * distance
+    }
+
+    /// How long to pause after wandering (between half and one and a half times the pause).
+    fn pause(&mut self, data: &BehaviourData) -> f32 {
+        data.pause.max(0.0) * self.rng.gen_range(0.5..1.5)
+    }
+}
+
+/// Moves toward `destination`.
+fn move_to(position: Vec2, destination: Vec2) -> BehaviourAction {
+    BehaviourAction {
+        movement: (destination - position).normalize_or_zero(),
+        attack: None,
+    }
+}
+
+/// Moves into range of `target` (and out of its keep distance), attacking it when in range.
+fn fight(data: &BehaviourData, position: Vec2, target: Vec2) -> BehaviourAction {
+    let to_target = target - position;
+    let distance = to_target.length();
+    let movement = if distance > data.attack_range {
+        to_target.normalize_or_zero()
+    } else if data.combat == CombatBehaviour::KeepDistance && distance < data.keep_distance {
+        -to_target.normalize_or_zero()
+    } else {
+        Vec2::ZERO
+    };
+    BehaviourAction {
+        movement,
+        attack: (distance <= data.attack_range).then_some(target),
+    }
+}
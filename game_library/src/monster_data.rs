@@ -0,0 +1,169 @@
+//! Monsters are the beasts and creatures which are natural denizens of the realms.
+//!
+//! Monsters are defined in their own data files, and realms (and their biomes) reference them by
+//! their unique id with a spawn weight (see [`crate::MonsterDetail`]). Monsters are enemies by
+//! default, fight with their spells (see [`crate::BehaviourData`]), and drop essence when they are
+//! defeated.
+//!
+//! You can describe monsters using YAML or JSON using the schema:
+//!
+//! ```yaml
+//! # $schema: "https://schemas.nwest.one/games/elementalist/monster.json"
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{any::Any, hash::Hash};
+
+use crate::{
+    data_loader::DataFile,
+    enums::{Faction, GameSystem, MagicType, StatEnum},
+    AreaShape, BehaviourData, EssenceDrop, InternalId,
+};
+
+/// Details about a monster.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterData {
+    /// The internal ID of the monster.
+    pub internal_id: Option<String>,
+    /// The name of the monster.
+    pub name: String,
+    /// A short description of the monster.
+    pub description: String,
+    /// The tileset with the monster's sprite.
+    #[serde(default = "monster_defaults::placeholder_png_path")]
+    pub sprite_tileset: String,
+    /// The index of the monster's sprite in the tileset.
+    #[serde(default)]
+    pub sprite_index: usize,
+    /// The monster's max health.
+    #[serde(default = "monster_defaults::health")]
+    pub health: u32,
+    /// The monster's stats (e.g. `movementSpeed`).
+    #[serde(default = "Vec::new")]
+    pub stats: Vec<(StatEnum, f32)>,
+    /// The shape of the monster's collider.
+    #[serde(default = "monster_defaults::collider")]
+    pub collider: AreaShape,
+    /// The unique ids of the spells the monster attacks with.
+    #[serde(default = "Vec::new")]
+    pub spells: Vec<String>,
+    /// How the monster behaves (when it fights, flees, wanders, etc.).
+    #[serde(default)]
+    pub behaviour: BehaviourData,
+    /// Which side the monster is on.
+    #[serde(default)]
+    pub faction: Faction,
+    /// The essence dropped when the monster is defeated.
+    #[serde(default = "Vec::new")]
+    pub essence: Vec<EssenceDrop>,
+}
+
+mod monster_defaults {
+    use crate::AreaShape;
+
+    pub(super) fn placeholder_png_path() -> String {
+        "placeholder.png".to_string()
+    }
+    pub(super) const fn health() -> u32 {
+        10
+    }
+    pub(super) const fn collider() -> AreaShape {
+        AreaShape::Circle { radius: 6.0 }
+    }
+}
+
+impl MonsterData {
+    /// Get the monster's sprite as a texture atlas sprite.
+    #[must_use]
+    pub fn texture_atlas_index(&self) -> TextureAtlasSprite {
+        TextureAtlasSprite::new(self.sprite_index)
+    }
+    /// Rolls for each of the monster's essence drops, returning the essence dropped.
+    pub fn roll_essence<R: Rng>(&self, rng: &mut R) -> Vec<(MagicType, u32)> {
+        self.essence
+            .iter()
+            .map(|drop| (drop.magic, drop.roll(rng)))
+            .filter(|(_, amount)| *amount > 0)
+            .collect()
+    }
+}
+
+impl Default for MonsterData {
+    fn default() -> Self {
+        Self {
+            internal_id: None,
+            name: "Unnamed Monster".to_string(),
+            description: "No description provided.".to_string(),
+            sprite_tileset: monster_defaults::placeholder_png_path(),
+            sprite_index: 0,
+            health: monster_defaults::health(),
+            stats: Vec::new(),
+            collider: monster_defaults::collider(),
+            spells: Vec::new(),
+            behaviour: BehaviourData::default(),
+            faction: Faction::default(),
+            essence: Vec::new(),
+        }
+    }
+}
+
+impl Hash for MonsterData {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.description.hash(state);
+        self.health.hash(state);
+    }
+}
+
+impl InternalId for MonsterData {
+    /// Update the monster's internal ID.
+    fn update_internal_id(&mut self) {
+        self.internal_id = Some(self.get_internal_id());
+    }
+    /// Get the monster's internal ID.
+    #[must_use]
+    fn get_internal_id(&self) -> String {
+        if self.internal_id.is_some() {
+            let id = self.internal_id.clone().unwrap_or_default();
+            if !id.is_empty() {
+                return id;
+            }
+        }
+
+        format!("monster{}{}", self.name.replace(' ', ""), self.health)
+    }
+}
+
+impl<D: Hash + InternalId + 'static> TryFrom<&DataFile<D>> for MonsterData {
+    type Error = ();
+
+    fn try_from(data_file: &DataFile<D>) -> Result<Self, Self::Error> {
+        if data_file.header.system != GameSystem::Monster {
+            return Err(());
+        }
+
+        (&data_file.data as &dyn Any)
+            .downcast_ref::<Self>()
+            .cloned()
+            .ok_or(())
+    }
+}
+
+/// A monster spawned in the realm.
+#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+pub struct Monster {
+    /// The unique id of the monster's data.
+    pub monster_id: String,
+}
+
+impl Monster {
+    /// Creates a monster spawned from the data with the unique id.
+    #[must_use]
+    pub fn new(monster_id: &str) -> Self {
+        Self {
+            monster_id: monster_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Perks are passive bonuses unlocked in a skill's perk tree.
+//!
+//! Each level gained in a skill grants a perk point for that skill, which can be spent on the
+//! skill's perks (see [`crate::PerkState`]). Perks form a tree through their prerequisites, and
+//! are gated by a minimum level in their skill. Once allocated, a perk is active regardless of the
+//! spells chosen: its stat bonuses apply to the player's [`crate::StatBundle`] and its spell
+//! modifiers apply to every matching spell the player casts.
+//!
+//! You can describe perks using YAML or JSON using the schema:
+//!
+//! ```yaml
+//! # $schema: "https://schemas.nwest.one/games/elementalist/perk.json"
+//! ```
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use std::{any::Any, hash::Hash};
+
+use crate::{
+    data_loader::DataFile,
+    enums::{GameSystem, Skill, StatEnum},
+    InternalId, SpellStatModifier, StatBonus,
+};
+
+/// Details about a perk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct Perk {
+    /// The internal ID of the perk.
+    pub internal_id: Option<String>,
+    /// The name of the perk.
+    pub name: String,
+    /// A short description of the perk.
+    pub description: String,
+    /// The skill whose perk tree the perk belongs to (and whose perk points it costs).
+    pub skill: Skill,
+    /// The minimum level the player needs in the skill.
+    #[serde(default)]
+    pub skill_level: u32,
+    /// The unique ids of perks which have to be allocated first.
+    #[serde(default = "Vec::new")]
+    pub prerequisites: Vec<String>,
+    /// How many of the skill's perk points the perk costs.
+    #[serde(default = "perk_defaults::cost")]
+    pub cost: u32,
+    /// Stat bonuses for the player (e.g. `movementSpeed` 1.1 is +10%).
+    #[serde(default = "Vec::new")]
+    pub effects: Vec<(StatEnum, StatBonus)>,
+    /// Stat bonuses for the spells the player casts which match the modifier's condition.
+    #[serde(default = "Vec::new")]
+    pub spell_modifiers: Vec<SpellStatModifier>,
+}
+
+mod perk_defaults {
+    pub(super) const fn cost() -> u32 {
+        1
+    }
+}
+
+impl Default for Perk {
+    fn default() -> Self {
+        Self {
+            internal_id: None,
+            name: "Unnamed Perk".to_string(),
+            description: "No description provided.".to_string(),
+            skill: Skill::Arcanomancy,
+            skill_level: 0,
+            prerequisites: Vec::new(),
+            cost: perk_defaults::cost(),
+            effects: Vec::new(),
+            spell_modifiers: Vec::new(),
+        }
+    }
+}
+
+impl Hash for Perk {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.skill.hash(state);
+        self.skill_level.hash(state);
+    }
+}
+
+impl InternalId for Perk {
+    /// Update the perk's internal ID.
+    fn update_internal_id(&mut self) {
+        self.internal_id = Some(self.get_internal_id());
+    }
+    /// Get the perk's internal ID.
+    #[must_use]
+    fn get_internal_id(&self) -> String {
+        if self.internal_id.is_some() {
+            let id = self.internal_id.clone().unwrap_or_default();
+            if !id.is_empty() {
+                return id;
+            }
+        }
+
+        format!("perk{}{}", self.skill, self.name.replace(' ', ""))
+    }
+}
+
+impl<D: Hash + InternalId + 'static> TryFrom<&DataFile<D>> for Perk {
+    type Error = ();
+
+    fn try_from(data_file: &DataFile<D>) -> Result<Self, Self::Error> {
+        if data_file.header.system != GameSystem::Perk {
+            return Err(());
+        }
+
+        (&data_file.data as &dyn Any)
+            .downcast_ref::<Self>()
+            .cloned()
+            .ok_or(())
+    }
+}
@@ -0,0 +1,232 @@
+//! A resource that stores which perks the player has allocated.
+//!
+//! Every level gained in a skill (i.e. every level past 1) grants a perk point for that skill.
+//! Perks are allocated with [`PerkState::allocate`], which checks the perk's skill level,
+//! prerequisites and cost. The allocated perks are applied to the player's [`StatBundle`] with
+//! [`PerkState::sync_stats`], which (like [`crate::ActiveEffects`]) remembers what it changed so
+//! the perks can be reverted exactly.
+
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data_loader::storage::Vault,
+    enums::{Skill, StatEnum},
+    Perk, Skills, SpellStatModifier, Stat, StatBundle,
+};
+
+/// The perks the player has allocated.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct PerkState {
+    /// The unique ids of the allocated perks, in the order they were allocated.
+    allocated: Vec<String>,
+    /// The change currently applied to each stat's bonus by the allocated perks.
+    #[serde(skip)]
+    applied: HashMap<StatEnum, f32>,
+}
+
+/// Why a perk can't be allocated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PerkError {
+    /// There is no perk with the unique id.
+    UnknownPerk(String),
+    /// The perk (by unique id) is already allocated.
+    AlreadyAllocated(String),
+    /// The player's level in the perk's skill is too low.
+    SkillTooLow {
+        /// The perk's skill.
+        skill: Skill,
+        /// The level needed to allocate the perk.
+        required: u32,
+        /// The player's current level.
+        current: u32,
+    },
+    /// A prerequisite perk (by unique id) hasn't been allocated.
+    MissingPrerequisite(String),
+    /// The player doesn't have enough unspent perk points in the perk's skill.
+    NotEnoughPoints {
+        /// The perk's skill.
+        skill: Skill,
+        /// The perk's cost.
+        cost: u32,
+        /// The player's unspent perk points in the skill.
+        available: u32,
+    },
+}
+
+impl std::fmt::Display for PerkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownPerk(perk_id) => write!(f, "{perk_id} is not a known perk"),
+            Self::AlreadyAllocated(perk_id) => write!(f, "{perk_id} is already allocated"),
+            Self::SkillTooLow {
+                skill,
+                required,
+                current,
+            } => write!(f, "requires {skill} level {required} (currently {current})"),
+            Self::MissingPrerequisite(perk_id) => write!(f, "requires {perk_id} to be allocated"),
+            Self::NotEnoughPoints {
+                skill,
+                cost,
+                available,
+            } => write!(
+                f,
+                "costs {cost} {skill} perk points ({available} available)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PerkError {}
+
+impl PerkState {
+    /// Returns true if the perk (by unique id) is allocated.
+    #[must_use]
+    pub fn is_allocated(&self, perk_id: &str) -> bool {
+        self.allocated.iter().any(|id| id == perk_id)
+    }
+    /// Returns an iterator over the unique ids of the allocated perks.
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.allocated.iter()
+    }
+    /// Returns an iterator over the allocated perks which exist in `perks`.
+    pub fn active<'a>(&'a self, perks: &'a Vault<Perk>) -> impl Iterator<Item = &'a Perk> {
+        self.allocated.iter().filter_map(|id| perks.get(id))
+    }
+
+    /// The perk points gained in a skill (one for each level past 1).
+    #[must_use]
+    pub fn points_earned(skill: Skill, skills: &Skills) -> u32 {
+        skills.get_level(skill).unwrap_or(1).saturating_sub(1)
+    }
+    /// The perk points spent on the skill's allocated perks.
+    #[must_use]
+    pub fn points_spent(&self, skill: Skill, perks: &Vault<Perk>) -> u32 {
+        self.active(perks)
+            .filter(|perk| perk.skill == skill)
+            .map(|perk| perk.cost)
+            .sum()
+    }
+    /// The unspent perk points in a skill.
+    #[must_use]
+    pub fn points_available(&self, skill: Skill, skills: &Skills, perks: &Vault<Perk>) -> u32 {
+        Self::points_earned(skill, skills).saturating_sub(self.points_spent(skill, perks))
+    }
+
+    /// Checks if the perk can be allocated, returning the first reason it can't.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PerkError`] describing the first requirement which isn't met.
+    pub fn check(
+        &self,
+        perk_id: &str,
+        perks: &Vault<Perk>,
+        skills: &Skills,
+    ) -> Result<(), PerkError> {
+        let Some(perk) = perks.get(perk_id) else {
+            return Err(PerkError::UnknownPerk(perk_id.to_string()));
+        };
+        if self.is_allocated(perk_id) {
+            return Err(PerkError::AlreadyAllocated(perk_id.to_string()));
+        }
+        let current = skills.get_level(perk.skill).unwrap_or_default();
+        if current < perk.skill_level {
+            return Err(PerkError::SkillTooLow {
+                skill: perk.skill,
+                required: perk.skill_level,
+                current,
+            });
+        }
+        if let Some(missing) = perk.prerequisites.iter().find(|id| !self.is_allocated(id)) {
+            return Err(PerkError::MissingPrerequisite(missing.clone()));
+        }
+        let available = self.points_available(perk.skill, skills, perks);
+        if available < perk.cost {
+            return Err(PerkError::NotEnoughPoints {
+                skill: perk.skill,
+                cost: perk.cost,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Allocates the perk (by unique id), spending its cost from the skill's perk points.
+    ///
+    /// # Errors
+    ///
+    /// The perk isn't allocated and a [`PerkError`] is returned if it can't be (see
+    /// [`PerkState::check`]).
+    pub fn allocate(
+        &mut self,
+        perk_id: &str,
+        perks: &Vault<Perk>,
+        skills: &Skills,
+    ) -> Result<(), PerkError> {
+        self.check(perk_id, perks, skills)?;
+        self.allocated.push(perk_id.to_string());
+        Ok(())
+    }
+
+    /// Removes every allocated perk, refunding their perk points. Returns the unique ids of the
+    /// perks which were allocated.
+    ///
+    /// The perks stay applied to any [`StatBundle`] until it is synced again.
+    pub fn reset(&mut self) -> Vec<String> {
+        self.allocated.drain(..).collect()
+    }
+
+    /// The total change to each stat's bonus from the allocated perks.
+    #[must_use]
+    pub fn bonus_totals(&self, perks: &Vault<Perk>) -> HashMap<StatEnum, f32> {
+        let mut totals = HashMap::new();
+        for perk in self.active(perks) {
+            for (stat, bonus) in &perk.effects {
+                *totals.entry(stat.clone()).or_insert(0.0) += bonus.value() - 1.0;
+            }
+        }
+        totals
+    }
+
+    /// The spell modifiers of the allocated perks, in the order the perks were allocated.
+    #[must_use]
+    pub fn spell_modifiers(&self, perks: &Vault<Perk>) -> Vec<SpellStatModifier> {
+        self.active(perks)
+            .flat_map(|perk| perk.spell_modifiers.iter().cloned())
+            .collect()
+    }
+
+    /// Bring the stat bonuses in `stats` in line with the allocated perks.
+    ///
+    /// Whatever was applied by the previous sync is removed first, then the current totals
+    /// are applied.
+    pub fn sync_stats(&mut self, stats: &mut StatBundle, perks: &Vault<Perk>) {
+        self.revert_stats(stats);
+
+        for (stat, total) in self.bonus_totals(perks) {
+            let entry = stats
+                .stats
+                .entry(stat.clone())
+                .or_insert_with(Stat::default);
+            let before = entry.bonus();
+            entry.add_bonus(total);
+            self.applied.insert(stat, entry.bonus() - before);
+        }
+    }
+
+    /// Forget what the perks have applied, without reverting it (e.g. when the [`StatBundle`] it
+    /// was applied to has been despawned and replaced).
+    pub fn forget_applied(&mut self) {
+        self.applied.clear();
+    }
+
+    /// Remove everything the perks have applied to the stat bonuses in `stats`.
+    pub fn revert_stats(&mut self, stats: &mut StatBundle) {
+        for (stat, applied) in self.applied.drain() {
+            if let Some(entry) = stats.stats.get_mut(&stat) {
+                entry.add_bonus(-applied);
+            }
+        }
+    }
+}
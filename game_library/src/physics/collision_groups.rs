@@ -0,0 +1,113 @@
+//! Collision groups generated from [`Faction`]s, which decide what collides with (and is sensed
+//! by) what.
+//!
+//! Characters are members of their side's group. Spells (and zones) only sense the sides they
+//! can affect (see [`Faction::can_affect`]), along with everything which isn't a character or a
+//! spell (obstacles and structures), so they never report touching characters they can't hit.
+//! Characters on the player's side don't push each other around.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    data_loader::storage::GameData,
+    enums::{Faction, SpellTarget},
+    Health, SpellCaster, SpellSnapshot, Structure, Zone,
+};
+
+/// The player and their allies.
+pub const FRIENDLY: Group = Group::GROUP_1;
+/// Enemies.
+pub const HOSTILE: Group = Group::GROUP_2;
+/// Spells and zones.
+pub const SPELLS: Group = Group::GROUP_3;
+
+/// The group of the faction's side.
+#[must_use]
+pub const fn side(faction: Faction) -> Group {
+    if faction.is_friendly() {
+        FRIENDLY
+    } else {
+        HOSTILE
+    }
+}
+
+/// The sides with characters which a spell with the given target, cast by `caster`, can affect.
+#[must_use]
+pub fn affected_sides(caster: Faction, target: &SpellTarget) -> Group {
+    [Faction::Player, Faction::Ally, Faction::Enemy]
+        .into_iter()
+        .filter(|faction| caster.can_affect(*faction, target))
+        .fold(Group::NONE, |sides, faction| sides | side(faction))
+}
+
+/// The collision groups of a character of the faction.
+#[must_use]
+pub const fn character(faction: Faction) -> CollisionGroups {
+    CollisionGroups::new(side(faction), Group::ALL)
+}
+
+/// The solver groups of a character of the faction (the player's side doesn't push each other).
+#[must_use]
+pub fn character_solver(faction: Faction) -> SolverGroups {
+    let filters = if faction.is_friendly() {
+        Group::ALL - FRIENDLY
+    } else {
+        Group::ALL
+    };
+    SolverGroups::new(side(faction), filters)
+}
+
+/// The collision groups of a spell with the given target cast by `caster`.
+#[must_use]
+pub fn spell(caster: Faction, target: &SpellTarget) -> CollisionGroups {
+    let others = Group::ALL - FRIENDLY - HOSTILE - SPELLS;
+    CollisionGroups::new(SPELLS, others | affected_sides(caster, target))
+}
+
+/// Keeps the collision groups of characters, spells and zones in line with their factions.
+///
+/// This runs right before the physics step, so new colliders start with the right groups.
+/// Spells are on the side of their own [`Faction`] (e.g. after being reflected) or their
+/// caster's, and zones are on their owner's. Anything without a faction is an enemy.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub(super) fn sync_collision_groups(
+    mut commands: Commands,
+    characters: Query<
+        (Entity, Option<&Faction>),
+        (
+            With<Health>,
+            Without<Structure>,
+            Or<(Added<Health>, Changed<Faction>)>,
+        ),
+    >,
+    spells: Query<
+        (Entity, &SpellSnapshot, &SpellCaster, Option<&Faction>),
+        Or<(Added<SpellSnapshot>, Changed<SpellCaster>, Changed<Faction>)>,
+    >,
+    zones: Query<(Entity, &Zone), Added<Zone>>,
+    factions: Query<&Faction>,
+    game_data: Res<GameData>,
+) {
+    let faction_of = |entity: Entity| factions.get(entity).copied().unwrap_or_default();
+
+    for (entity, faction) in &characters {
+        let faction = faction.copied().unwrap_or_default();
+        commands
+            .entity(entity)
+            .insert((character(faction), character_solver(faction)));
+    }
+    for (entity, snapshot, caster, faction) in &spells {
+        let faction = faction.copied().unwrap_or_else(|| faction_of(caster.0));
+        commands
+            .entity(entity)
+            .insert(spell(faction, &snapshot.target));
+    }
+    for (entity, zone) in &zones {
+        let Some(zone_spell) = game_data.spells.get(&zone.spell_id) else {
+            continue;
+        };
+        commands
+            .entity(entity)
+            .insert(spell(faction_of(zone.owner), &zone_spell.target));
+    }
+}
@@ -1,5 +1,6 @@
 //! Physics plugin and systems
 
+pub mod collision_groups;
 mod plugin;
 
 #[allow(clippy::module_name_repetitions)]
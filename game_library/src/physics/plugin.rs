@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::state::Game;
+use crate::state::AppState;
+
+use super::collision_groups::sync_collision_groups;
 
 /// Pixels per meter
 pub const PIXELS_PER_METER: f32 = 16.0;
@@ -21,9 +23,15 @@ impl Plugin for PhysicsPlugin {
         .insert_resource(RapierConfiguration {
             gravity: GRAVITY,
             ..default()
-        });
-
-        app.add_systems(Update, display_events.run_if(in_state(Game::Playing)));
+        })
+        // collision groups are applied before the physics step sees any new colliders
+        .add_systems(
+            PostUpdate,
+            (sync_collision_groups, apply_deferred)
+                .chain()
+                .before(PhysicsSet::SyncBackend)
+                .run_if(in_state(AppState::InGame)),
+        );
 
         #[cfg(debug_assertions)]
         {
@@ -47,16 +55,3 @@ fn toggle_debug_rendering(
         debug_render_context.enabled = !debug_render_context.enabled;
     }
 }
-
-fn display_events(
-    mut collision_events: EventReader<CollisionEvent>,
-    mut contact_force_events: EventReader<ContactForceEvent>,
-) {
-    for collision_event in collision_events.read() {
-        info!("Received collision event: {:?}", collision_event);
-    }
-
-    for contact_force_event in contact_force_events.read() {
-        info!("Received contact force event: {:?}", contact_force_event);
-    }
-}
@@ -0,0 +1,218 @@
+//! Polymorph spells temporarily transform their caster into another form.
+//!
+//! A form is part of the [`crate::SpellData`] (the `polymorph` in the data files). While
+//! transformed, the caster uses the form's sprite atlas and animations, its stats are overridden
+//! or multiplied, and it may only be able to cast some spells. Everything is reverted when the
+//! form's duration runs out or the caster dies.
+//!
+//! ```yaml
+//! castCategory: onSelf
+//! polymorph:
+//!   spriteTileset: ogre
+//!   duration: 15
+//!   statOverrides:
+//!     - [movementSpeed, 1.8]
+//!   statMultipliers:
+//!     - [physicalDamageAmplification, 2.0]
+//!   allowedSpells:
+//!     - polymorph-ogre
+//! ```
+use bevy::{prelude::*, reflect::Reflect, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::StatEnum, Stat, StatBundle};
+
+/// The sprite indices of each animation of a character.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationSet {
+    /// Frames while standing still.
+    pub idle: Vec<usize>,
+    /// Frames while walking.
+    pub walking: Vec<usize>,
+    /// Frames while casting and standing still.
+    pub casting_idle: Vec<usize>,
+    /// Frames while casting and walking.
+    pub casting_walking: Vec<usize>,
+}
+
+impl Default for AnimationSet {
+    /// The animations of the wizard tileset.
+    fn default() -> Self {
+        Self {
+            idle: vec![3],
+            walking: vec![0, 1, 0, 2],
+            casting_idle: vec![4],
+            casting_walking: vec![5, 6, 7, 8],
+        }
+    }
+}
+
+/// Details about the form a polymorph spell transforms its caster into.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct PolymorphData {
+    /// The tileset with the form's sprites.
+    pub sprite_tileset: String,
+    /// The form's animations (indices in the tileset).
+    #[serde(default)]
+    pub animations: AnimationSet,
+    /// Multiplier for the size of the caster's sprite.
+    #[serde(default = "polymorph_defaults::scale")]
+    pub scale: f32,
+    /// How long (in seconds) the form lasts.
+    #[serde(default = "polymorph_defaults::duration")]
+    pub duration: f32,
+    /// Stats which are set to a value while transformed.
+    #[serde(default = "Vec::new")]
+    pub stat_overrides: Vec<(StatEnum, f32)>,
+    /// Stats which are multiplied while transformed (applied after the overrides).
+    #[serde(default = "Vec::new")]
+    pub stat_multipliers: Vec<(StatEnum, f32)>,
+    /// The unique ids of the only spells which can be cast while transformed. Empty allows all.
+    #[serde(default = "Vec::new")]
+    pub allowed_spells: Vec<String>,
+}
+
+mod polymorph_defaults {
+    pub(super) const fn scale() -> f32 {
+        1.0
+    }
+    pub(super) const fn duration() -> f32 {
+        10.0
+    }
+}
+
+impl Default for PolymorphData {
+    fn default() -> Self {
+        Self {
+            sprite_tileset: "placeholder.png".to_string(),
+            animations: AnimationSet::default(),
+            scale: polymorph_defaults::scale(),
+            duration: polymorph_defaults::duration(),
+            stat_overrides: Vec::new(),
+            stat_multipliers: Vec::new(),
+            allowed_spells: Vec::new(),
+        }
+    }
+}
+
+impl PolymorphData {
+    /// Returns true if the spell can be cast while in this form.
+    #[must_use]
+    pub fn allows_spell(&self, spell_id: &str) -> bool {
+        self.allowed_spells.is_empty() || self.allowed_spells.iter().any(|id| id == spell_id)
+    }
+}
+
+/// An entity which is transformed by a polymorph spell.
+///
+/// Like [`crate::ActiveEffects`], the actual change made to each stat is remembered, so the
+/// stats are reverted exactly. Polymorphs change the stats' base values (effects and perks change
+/// their bonuses), so they don't interfere with each other.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct Polymorphed {
+    /// The unique id of the polymorph spell.
+    pub spell_id: String,
+    /// The form the entity is transformed into.
+    pub form: PolymorphData,
+    /// The remaining duration in seconds.
+    pub remaining: f32,
+    /// The entity's sprite atlas before it was transformed.
+    pub original_atlas: Handle<TextureAtlas>,
+    /// The entity's scale before it was transformed.
+    pub original_scale: Vec3,
+    /// The change currently applied to each stat's base value by the form.
+    applied: HashMap<StatEnum, f32>,
+}
+
+impl Polymorphed {
+    /// Creates a new polymorph into `form`, remembering the entity's original sprite.
+    #[must_use]
+    pub fn new(
+        spell_id: impl Into<String>,
+        form: PolymorphData,
+        original_atlas: Handle<TextureAtlas>,
+        original_scale: Vec3,
+    ) -> Self {
+        Self {
+            spell_id: spell_id.into(),
+            remaining: form.duration,
+            form,
+            original_atlas,
+            original_scale,
+            applied: HashMap::new(),
+        }
+    }
+
+    /// Counts down the remaining duration by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining -= delta;
+    }
+
+    /// Returns true if the form has run out of time.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Returns true if the spell can be cast while transformed.
+    #[must_use]
+    pub fn allows_spell(&self, spell_id: &str) -> bool {
+        self.form.allows_spell(spell_id)
+    }
+
+    /// Applies the form's stat overrides and multipliers to `stats`.
+    ///
+    /// Whatever was applied before is reverted first.
+    pub fn apply_stats(&mut self, stats: &mut StatBundle) {
+        self.revert_stats(stats);
+
+        let form = self.form.clone();
+        for (stat, value) in &form.stat_overrides {
+            self.change_base_value(stats, stat, |entry| entry.set_base_value(*value));
+        }
+        for (stat, multiplier) in &form.stat_multipliers {
+            self.change_base_value(stats, stat, |entry| entry.multiply_base_value(*multiplier));
+        }
+    }
+
+    /// Changes the base value of a stat, remembering the change so it can be reverted.
+    fn change_base_value(
+        &mut self,
+        stats: &mut StatBundle,
+        stat: &StatEnum,
+        change: impl FnOnce(&mut Stat),
+    ) {
+        let entry = stats
+            .stats
+            .entry(stat.clone())
+            .or_insert_with(|| Stat::new(0.0));
+        let before = entry.base_value();
+        change(entry);
+        *self.applied.entry(stat.clone()).or_insert(0.0) += entry.base_value() - before;
+    }
+
+    /// Transforms into a new form (e.g. when another polymorph spell is cast while transformed),
+    /// keeping the original sprite.
+    pub fn transform(
+        &mut self,
+        spell_id: impl Into<String>,
+        form: PolymorphData,
+        stats: &mut StatBundle,
+    ) {
+        self.spell_id = spell_id.into();
+        self.remaining = form.duration;
+        self.form = form;
+        self.apply_stats(stats);
+    }
+
+    /// Remove everything the form has applied to the base values in `stats`.
+    pub fn revert_stats(&mut self, stats: &mut StatBundle) {
+        for (stat, applied) in self.applied.drain() {
+            if let Some(entry) = stats.stats.get_mut(&stat) {
+                entry.add_base_value(-applied);
+            }
+        }
+    }
+}
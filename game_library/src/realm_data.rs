@@ -6,6 +6,8 @@
 //! or possible other elements that can be included in the realm. Since the realm data is what is used to generate the "Elemental Realm" that
 //! the player is able to visit, it will be important to have a good amount of detail about the realm.
 //!
+//! Realms can list monsters which spawn across all of their biomes (on top of the monsters of each biome).
+//!
 //! Additional details we will need are possibly special monsters and any pre-designed structures that can be found in the realm. This will
 //! allow for a more unique experience when visiting the realm. The realm will also need to have a unique name and description to give it some
 //! flavor and make it feel like a unique place to visit.
@@ -21,7 +23,7 @@ use std::hash::Hash;
 use crate::{
     data_loader::DataFile,
     enums::{GameSystem, MagicType},
-    BiomeData, InternalId,
+    BiomeData, InternalId, MonsterDetail,
 };
 
 /// Details about a realm.
@@ -38,6 +40,9 @@ pub struct Realm {
     pub biomes: Vec<BiomeData>,
     /// The primary element of the realm. (for now limited as magic school but should be its own.)
     pub primary_element: MagicType,
+    /// The monsters which can spawn in every biome of the realm.
+    #[serde(default)]
+    pub monsters: Vec<MonsterDetail>,
 }
 
 impl InternalId for Realm {
@@ -72,6 +77,7 @@ impl Default for Realm {
             description: "A realm that was loaded incorrectly!".to_string(),
             biomes: Vec::new(),
             primary_element: MagicType::Arcane,
+            monsters: Vec::new(),
         }
     }
 }
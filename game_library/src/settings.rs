@@ -33,6 +33,7 @@
 //! The gameplay menu should have these options:
 //!
 //! - Auto-Aim
+//! - Auto-Aim Target
 //! - Auto-Cast
 //! - Back
 //!
@@ -53,6 +54,7 @@ use bevy_pkv::PkvStore;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    enums::AutoAimRule,
     font_resource::{FontChoice, FontFamily},
     CameraScaleLevel, Volume,
 };
@@ -116,6 +118,9 @@ pub struct GameplaySettings {
     pub auto_aim: bool,
     /// Auto-cast.
     pub auto_cast: bool,
+    /// How auto-aim picks its target.
+    #[serde(default)]
+    pub auto_aim_rule: AutoAimRule,
 }
 
 /// Accessibility settings.
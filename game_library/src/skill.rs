@@ -5,16 +5,20 @@
 //! Skills are able to be leveled up, and have no level cap but do have a
 //! soft cap that makes it harder to level up the higher the skill is (this is
 //! automatically handled by the [`game_library::Xp`] component).
+//!
+//! Casting spells and hitting targets with them awards xp to the spell's skill (see
+//! [`crate::SpellData::cast_xp`] and [`crate::SpellData::hit_xp`]). Pending levels are
+//! processed with [`Skills::level_up_pending`].
 
 use bevy::{
-    ecs::{component::Component, system::Resource},
+    ecs::{component::Component, entity::Entity, system::Resource},
     reflect::Reflect,
     utils::HashMap,
 };
 use bevy_inspector_egui::inspector_options::{InspectorOptions, ReflectInspectorOptions};
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::Skill, Xp};
+use crate::{enums::Skill, SpellData, Xp};
 
 /// Skills are used to track a meta-progression of a player's abilities.
 ///
@@ -65,8 +69,15 @@ impl Skills {
     }
     /// Add xp to a skill.
     pub fn add_xp(&mut self, skill: Skill, xp: u32) {
-        if let Some(skill_xp) = self.tracks.get_mut(&skill) {
-            skill_xp.add(xp);
+        self.tracks.entry(skill).or_default().add(xp);
+    }
+    /// Add the xp for hitting a target with a spell cast by `caster`.
+    ///
+    /// Only the player's own hits (where `caster` is the `player`) train their skills, not the
+    /// hits of monsters or summons casting the same spells.
+    pub fn add_hit_xp(&mut self, spell: &SpellData, caster: Entity, player: Entity) {
+        if caster == player {
+            self.add_xp(spell.skill(), spell.hit_xp);
         }
     }
     /// Level-up a skill.
@@ -75,4 +86,31 @@ impl Skills {
             skill_xp.level_up();
         }
     }
+    /// Level-up every skill as many times as its xp allows.
+    ///
+    /// Returns each level gained as `(skill, new_level)`, in [`Skill::variants`] order and
+    /// ascending by level.
+    pub fn level_up_pending(&mut self) -> Vec<(Skill, u32)> {
+        let mut leveled_up = Vec::new();
+        for skill in Skill::variants() {
+            let Some(skill_xp) = self.tracks.get_mut(&skill) else {
+                continue;
+            };
+            let previous_level = skill_xp.current_level;
+            let levels = skill_xp.level_up_pending();
+            leveled_up.extend((1..=levels).map(|level| (skill, previous_level + level)));
+        }
+        leveled_up
+    }
+}
+
+impl Default for Skills {
+    /// Starts a track for every skill.
+    fn default() -> Self {
+        Self {
+            tracks: Skill::variants()
+                .map(|skill| (skill, Xp::default()))
+                .collect(),
+        }
+    }
 }
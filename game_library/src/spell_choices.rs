@@ -4,7 +4,7 @@ use bevy::prelude::*;
 use bevy_inspector_egui::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::CastSlot, InternalId, SpellData};
+use crate::{data_loader::storage::Vault, enums::CastSlot, SpellData, UnlockedSpells};
 
 /// A resource that stores the player's spell choices.
 #[derive(
@@ -31,45 +31,175 @@ pub struct SpellChoices {
     pub ultimate: Option<String>,
 }
 
-impl SpellChoices {
-    /// Set the primary spell choice.
-    pub fn set_primary(&mut self, spell: &SpellData) {
-        if spell.cast_slot == CastSlot::Primary {
-            self.primary = Some(spell.get_internal_id());
+/// Why a spell can't be chosen for a slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpellChoiceError {
+    /// There is no spell with the unique id.
+    UnknownSpell(String),
+    /// The spell (by unique id) hasn't been unlocked.
+    Locked(String),
+    /// The spell can't be cast from the slot.
+    WrongSlot {
+        /// The spell's unique id.
+        spell_id: String,
+        /// The slot the spell was chosen for.
+        slot: CastSlot,
+        /// The slot the spell is cast from.
+        spell_slot: CastSlot,
+    },
+}
+
+impl std::fmt::Display for SpellChoiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownSpell(spell_id) => write!(f, "{spell_id} is not a known spell"),
+            Self::Locked(spell_id) => write!(f, "{spell_id} has not been unlocked"),
+            Self::WrongSlot {
+                spell_id,
+                slot,
+                spell_slot,
+            } => write!(
+                f,
+                "{spell_id} is a {spell_slot} spell and can't be used as the {slot} spell"
+            ),
         }
     }
-    /// Set the secondary spell choice.
-    pub fn set_secondary(&mut self, spell: &SpellData) {
-        if spell.cast_slot == CastSlot::Secondary {
-            self.secondary = Some(spell.get_internal_id());
+}
+
+impl std::error::Error for SpellChoiceError {}
+
+impl SpellChoices {
+    /// Get the spell choice for a slot.
+    #[must_use]
+    pub const fn get(&self, slot: CastSlot) -> Option<&String> {
+        match slot {
+            CastSlot::Primary => self.primary.as_ref(),
+            CastSlot::Secondary => self.secondary.as_ref(),
+            CastSlot::Defensive => self.defensive.as_ref(),
+            CastSlot::Ultimate => self.ultimate.as_ref(),
         }
     }
-    /// Set the defensive spell choice.
-    pub fn set_defensive(&mut self, spell: &SpellData) {
-        if spell.cast_slot == CastSlot::Defensive {
-            self.defensive = Some(spell.get_internal_id());
-        }
+    /// Set the spell choice for a slot by spell id.
+    ///
+    /// # Errors
+    ///
+    /// The choice is left unchanged and an error is returned if the spell doesn't exist, hasn't
+    /// been unlocked, or can't be cast from the slot.
+    pub fn set(
+        &mut self,
+        slot: CastSlot,
+        spell_id: String,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        Self::check(slot, &spell_id, spells, unlocked)?;
+        *self.slot_mut(slot) = Some(spell_id);
+        Ok(())
     }
-    /// Set the ultimate spell choice.
-    pub fn set_ultimate(&mut self, spell: &SpellData) {
-        if spell.cast_slot == CastSlot::Ultimate {
-            self.ultimate = Some(spell.get_internal_id());
+    /// Checks every spell choice is still valid (i.e. it exists, is unlocked, and can be cast
+    /// from its slot).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error for the first invalid choice.
+    pub fn validate(
+        &self,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        for slot in [
+            CastSlot::Primary,
+            CastSlot::Secondary,
+            CastSlot::Defensive,
+            CastSlot::Ultimate,
+        ] {
+            if let Some(spell_id) = self.get(slot) {
+                Self::check(slot, spell_id, spells, unlocked)?;
+            }
         }
+        Ok(())
     }
     /// Set the primary spell choice by spell id.
-    pub fn set_primary_by_id(&mut self, spell_id: String) {
-        self.primary = Some(spell_id);
+    ///
+    /// # Errors
+    ///
+    /// See [`SpellChoices::set`].
+    pub fn set_primary_by_id(
+        &mut self,
+        spell_id: String,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        self.set(CastSlot::Primary, spell_id, spells, unlocked)
     }
     /// Set the secondary spell choice by spell id.
-    pub fn set_secondary_by_id(&mut self, spell_id: String) {
-        self.secondary = Some(spell_id);
+    ///
+    /// # Errors
+    ///
+    /// See [`SpellChoices::set`].
+    pub fn set_secondary_by_id(
+        &mut self,
+        spell_id: String,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        self.set(CastSlot::Secondary, spell_id, spells, unlocked)
     }
     /// Set the defensive spell choice by spell id.
-    pub fn set_defensive_by_id(&mut self, spell_id: String) {
-        self.defensive = Some(spell_id);
+    ///
+    /// # Errors
+    ///
+    /// See [`SpellChoices::set`].
+    pub fn set_defensive_by_id(
+        &mut self,
+        spell_id: String,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        self.set(CastSlot::Defensive, spell_id, spells, unlocked)
     }
     /// Set the ultimate spell choice by spell id.
-    pub fn set_ultimate_by_id(&mut self, spell_id: String) {
-        self.ultimate = Some(spell_id);
+    ///
+    /// # Errors
+    ///
+    /// See [`SpellChoices::set`].
+    pub fn set_ultimate_by_id(
+        &mut self,
+        spell_id: String,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        self.set(CastSlot::Ultimate, spell_id, spells, unlocked)
+    }
+
+    fn check(
+        slot: CastSlot,
+        spell_id: &str,
+        spells: &Vault<SpellData>,
+        unlocked: &UnlockedSpells,
+    ) -> Result<(), SpellChoiceError> {
+        let Some(spell) = spells.get(spell_id) else {
+            return Err(SpellChoiceError::UnknownSpell(spell_id.to_string()));
+        };
+        if spell.cast_slot != slot {
+            return Err(SpellChoiceError::WrongSlot {
+                spell_id: spell_id.to_string(),
+                slot,
+                spell_slot: spell.cast_slot,
+            });
+        }
+        if !unlocked.is_unlocked(spell_id) {
+            return Err(SpellChoiceError::Locked(spell_id.to_string()));
+        }
+        Ok(())
+    }
+
+    fn slot_mut(&mut self, slot: CastSlot) -> &mut Option<String> {
+        match slot {
+            CastSlot::Primary => &mut self.primary,
+            CastSlot::Secondary => &mut self.secondary,
+            CastSlot::Defensive => &mut self.defensive,
+            CastSlot::Ultimate => &mut self.ultimate,
+        }
     }
 }
@@ -0,0 +1,122 @@
+//! Conditions which select a subset of spells, and stat modifiers which only apply to those spells.
+//!
+//! These are the building blocks for things like "all projectile spells get +10% speed" or
+//! "fire spells deal +20% damage". A [`SpellCondition`] matches spells by their tags, magic type
+//! and cast slot, and a [`SpellStatModifier`] pairs a condition with the stat bonuses to apply
+//! when a matching spell is cast. A caster's [`SpellStatModifiers`] are folded into each spell
+//! it casts.
+
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{CastSlot, MagicType, SpellTag, StatEnum},
+    SpellData, StatBonus,
+};
+
+/// A condition which a spell must match.
+///
+/// Every part of the condition which is set must match. An empty (default) condition matches
+/// every spell.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellCondition {
+    /// The spell must have all of these tags.
+    #[serde(default = "Vec::new")]
+    pub all_tags: Vec<SpellTag>,
+    /// The spell must have at least one of these tags (ignored if empty).
+    #[serde(default = "Vec::new")]
+    pub any_tags: Vec<SpellTag>,
+    /// The spell must have none of these tags.
+    #[serde(default = "Vec::new")]
+    pub without_tags: Vec<SpellTag>,
+    /// The spell must be of this type of magic.
+    #[serde(default)]
+    pub magic: Option<MagicType>,
+    /// The spell must be cast from this slot.
+    #[serde(default)]
+    pub cast_slot: Option<CastSlot>,
+}
+
+impl SpellCondition {
+    /// A condition which matches any spell with the given tag.
+    #[must_use]
+    pub fn tagged(tag: SpellTag) -> Self {
+        Self {
+            all_tags: vec![tag],
+            ..Self::default()
+        }
+    }
+
+    /// A condition which matches any spell of the given type of magic.
+    #[must_use]
+    pub fn magic(magic: MagicType) -> Self {
+        Self {
+            magic: Some(magic),
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if the spell matches this condition.
+    #[must_use]
+    pub fn matches(&self, spell: &SpellData) -> bool {
+        self.all_tags.iter().all(|tag| spell.has_tag(*tag))
+            && (self.any_tags.is_empty() || self.any_tags.iter().any(|tag| spell.has_tag(*tag)))
+            && !self.without_tags.iter().any(|tag| spell.has_tag(*tag))
+            && self.magic.map_or(true, |magic| spell.magic == magic)
+            && self.cast_slot.map_or(true, |slot| spell.cast_slot == slot)
+    }
+}
+
+/// Stat bonuses which apply to any spell matching a [`SpellCondition`].
+///
+/// The bonuses work the same way as a [`crate::StatEffect`]'s effects: `1.1` is +10%, `0.9` is -10%.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellStatModifier {
+    /// The spells this modifier applies to.
+    #[serde(default)]
+    pub condition: SpellCondition,
+    /// The stat bonuses to apply.
+    pub effects: Vec<(StatEnum, StatBonus)>,
+}
+
+impl SpellStatModifier {
+    /// Returns true if this modifier applies to the spell.
+    #[must_use]
+    pub fn applies_to(&self, spell: &SpellData) -> bool {
+        self.condition.matches(spell)
+    }
+
+    /// The total change to each stat's bonus from the modifiers which apply to the spell.
+    #[must_use]
+    pub fn bonus_totals<'a>(
+        modifiers: impl IntoIterator<Item = &'a Self>,
+        spell: &SpellData,
+    ) -> HashMap<StatEnum, f32> {
+        let mut totals = HashMap::new();
+        for modifier in modifiers {
+            if !modifier.applies_to(spell) {
+                continue;
+            }
+            for (stat, bonus) in &modifier.effects {
+                *totals.entry(stat.clone()).or_insert(0.0) += bonus.value() - 1.0;
+            }
+        }
+        totals
+    }
+}
+
+/// The [`SpellStatModifier`]s which apply to the spells cast by an entity.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+pub struct SpellStatModifiers {
+    /// The modifiers.
+    pub modifiers: Vec<SpellStatModifier>,
+}
+
+impl SpellStatModifiers {
+    /// Returns an iterator over the modifiers.
+    pub fn iter(&self) -> impl Iterator<Item = &SpellStatModifier> {
+        self.modifiers.iter()
+    }
+}
@@ -0,0 +1,39 @@
+//! Spell cooldowns component (or how long until a spell can be cast again)
+
+use bevy::{prelude::*, utils::HashMap};
+
+/// The cooldowns of the spells an entity has cast
+///
+/// When a spell is cast, its cooldown is started. Until the cooldown has run out, the spell
+/// can't be cast again. Spells are tracked by their unique id.
+#[derive(Debug, Clone, PartialEq, Component, Default, Reflect)]
+pub struct SpellCooldowns {
+    /// The remaining cooldown of each spell in seconds
+    pub remaining: HashMap<String, f32>,
+}
+
+impl SpellCooldowns {
+    /// Starts the cooldown for a spell
+    pub fn start(&mut self, spell_id: impl Into<String>, cooldown: f32) {
+        if cooldown > 0.0 {
+            self.remaining.insert(spell_id.into(), cooldown);
+        }
+    }
+    /// Updates the cooldowns using `time.delta_seconds()`, forgetting any which have run out
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining.retain(|_, remaining| {
+            *remaining -= delta;
+            *remaining > 0.0
+        });
+    }
+    /// Returns the remaining cooldown for a spell in seconds (0.0 if it's ready)
+    #[must_use]
+    pub fn get(&self, spell_id: &str) -> f32 {
+        self.remaining.get(spell_id).copied().unwrap_or(0.0)
+    }
+    /// Returns true if the spell is off cooldown
+    #[must_use]
+    pub fn is_ready(&self, spell_id: &str) -> bool {
+        !self.remaining.contains_key(spell_id)
+    }
+}
@@ -13,10 +13,11 @@ use crate::{
     data_loader::DataFile,
     enums::{
         CastCategory, CastSlot, CastType, GameSystem, MagicType, ParticleAttachment, Skill,
-        SpellCollision,
+        SpellCollision, SpellTag, SpellTarget, Trajectory,
     },
     shared_traits::KnownCastSlot,
-    InternalId, StatEffect,
+    BarrierData, BeamData, ChainData, InternalId, KnockbackData, PolymorphData, SpellRequirements,
+    StatEffect, StructureData, SummonData, ZoneData,
 };
 
 /// Details about a spell.
@@ -49,6 +50,14 @@ pub struct SpellData {
     /// How the spell is targeted
     #[serde(default = "spell_defaults::cast_category")]
     pub cast_category: CastCategory,
+    /// Which entities the spell affects when it hits them.
+    ///
+    /// Entities which aren't valid targets are passed through without an impact.
+    #[serde(default = "spell_defaults::target")]
+    pub target: SpellTarget,
+    /// Tags describing the general behavior of the spell (the `flags` in the data files).
+    #[serde(default = "Vec::new", rename = "flags")]
+    pub tags: Vec<SpellTag>,
 
     // #### SPELL ICONS ####
     /// The path to the icon for the spell (relative to the game's asset directory).
@@ -74,7 +83,7 @@ pub struct SpellData {
     /// The mana cost of the spell (mana is an integer value)
     #[serde(default = "default_usize::<0>")]
     pub mana_cost: usize,
-    /// The range of the spell in centimeters.
+    /// The range of the spell in pixels (how far it travels before it expires).
     #[serde(default = "spell_defaults::spell_range")]
     pub range: f32,
     /// The speed of the spell in meters per second.
@@ -89,7 +98,9 @@ pub struct SpellData {
     /// The base healing of the spell, 0 if the spell does not heal.
     #[serde(default = "default_i32::<0>")]
     pub healing: i32,
-    /// Radius of the spell "detonation" in centimeters.
+    /// Radius of the spell "detonation" in pixels.
+    ///
+    /// Projectile spells with a radius detonate when they expire, hitting everything within it.
     ///
     /// Spells which target the ground use this value to determine the radius of the area of effect.
     ///
@@ -101,6 +112,55 @@ pub struct SpellData {
     /// Spells which are cone shaped use this value to determine the angle of the cone.
     #[serde(default = "default_i32::<0>")]
     pub angle: i32,
+    /// How many targets the spell passes through before it stops. 0 stops at the first target.
+    #[serde(default)]
+    pub pierce: u32,
+    /// How many times the spell bounces off obstacles (e.g. walls) before it stops.
+    #[serde(default)]
+    pub bounces: u32,
+    /// How the spell moves after it's cast.
+    #[serde(default)]
+    pub trajectory: Trajectory,
+    /// What the player needs before they can unlock the spell.
+    #[serde(default)]
+    pub requirements: SpellRequirements,
+    /// How long (in seconds) the spell's type of magic lingers on the targets it hits, which lets
+    /// later spells of another school cause an [`crate::ElementalReaction`]. 0 doesn't linger.
+    #[serde(default = "spell_defaults::status_duration")]
+    pub status_duration: f32,
+    /// The allies spawned by the spell (only used by spells with the `summon` cast category).
+    #[serde(default)]
+    pub summon: Option<SummonData>,
+    /// The form the spell transforms its caster into.
+    #[serde(default)]
+    pub polymorph: Option<PolymorphData>,
+    /// The structure the spell places (only used by spells with the `ground` cast category).
+    #[serde(default)]
+    pub structure: Option<StructureData>,
+    /// The zone the spell places (only used by spells with the `ground` cast category).
+    #[serde(default)]
+    pub zone: Option<ZoneData>,
+    /// The beam the spell fires instead of a projectile.
+    #[serde(default)]
+    pub beam: Option<BeamData>,
+    /// How the spell jumps to other targets after hitting a character.
+    #[serde(default)]
+    pub chain: Option<ChainData>,
+    /// The barrier the spell raises (on the structure it places, or around the caster for spells
+    /// with the `onSelf` cast category).
+    #[serde(default)]
+    pub barrier: Option<BarrierData>,
+    /// How the spell knocks back the characters it hits.
+    #[serde(default)]
+    pub knockback: Option<KnockbackData>,
+
+    // #### SKILL EXPERIENCE ####
+    /// Experience awarded to the spell's skill each time the spell is cast.
+    #[serde(default = "spell_defaults::cast_xp")]
+    pub cast_xp: u32,
+    /// Experience awarded to the spell's skill each time the spell hits a target.
+    #[serde(default = "spell_defaults::hit_xp")]
+    pub hit_xp: u32,
 
     // #### SPELL EFFECTS ####
     /// Buffs that the spell can apply to the caster or to the target.
@@ -172,6 +232,29 @@ impl SpellData {
     pub fn skill(&self) -> Skill {
         self.magic.into()
     }
+    /// Returns true if the spell has the given tag.
+    #[must_use]
+    pub fn has_tag(&self, tag: SpellTag) -> bool {
+        self.tags.contains(&tag)
+    }
+    /// Returns true if casting the spell launches a projectile.
+    ///
+    /// Summoning, polymorph, structure, zone and shielding barrier spells do something else
+    /// instead, and beam spells fire a beam.
+    #[must_use]
+    pub fn launches_projectile(&self) -> bool {
+        self.cast_category != CastCategory::Summon
+            && self.polymorph.is_none()
+            && self.structure.is_none()
+            && self.zone.is_none()
+            && self.beam.is_none()
+            && !self.shields_caster()
+    }
+    /// Returns true if the spell raises a barrier around its caster.
+    #[must_use]
+    pub fn shields_caster(&self) -> bool {
+        self.cast_category == CastCategory::OnSelf && self.barrier.is_some()
+    }
     /// Get the spell's sprite as a texture atlas sprite.
     #[must_use]
     pub fn texture_atlas_index(&self) -> bevy::sprite::TextureAtlasSprite {
@@ -181,7 +264,7 @@ impl SpellData {
 
 /// #### DEFAULTS FOR SERDE ####
 mod spell_defaults {
-    use crate::enums::{CastCategory, CastType, SpellCollision};
+    use crate::enums::{CastCategory, CastType, SpellCollision, SpellTarget};
 
     pub(super) const fn collision() -> SpellCollision {
         SpellCollision::Point
@@ -192,6 +275,9 @@ mod spell_defaults {
     pub(super) const fn cast_category() -> CastCategory {
         CastCategory::Projectile
     }
+    pub(super) const fn target() -> SpellTarget {
+        SpellTarget::Enemy
+    }
     pub(super) fn placeholder_png_path() -> String {
         "placeholder.png".to_string()
     }
@@ -202,7 +288,7 @@ mod spell_defaults {
         0.0
     }
     pub(super) const fn spell_range() -> f32 {
-        5.0
+        320.0
     }
     pub(super) const fn spell_speed() -> f32 {
         1.0
@@ -210,6 +296,15 @@ mod spell_defaults {
     pub(super) const fn spell_duration() -> f32 {
         5.0
     }
+    pub(super) const fn status_duration() -> f32 {
+        4.0
+    }
+    pub(super) const fn cast_xp() -> u32 {
+        1
+    }
+    pub(super) const fn hit_xp() -> u32 {
+        2
+    }
 }
 
 impl<D: Hash + InternalId + 'static> TryInto<SpellData> for DataFile<D> {
@@ -255,6 +350,8 @@ impl Default for SpellData {
             collision: SpellCollision::Point,
             cast_type: CastType::Instant,
             cast_category: CastCategory::Projectile,
+            target: spell_defaults::target(),
+            tags: Vec::new(),
             icon_tileset: spell_defaults::placeholder_png_path(),
             icon_index: 0,
             sprite_tileset: spell_defaults::placeholder_png_path(),
@@ -269,6 +366,21 @@ impl Default for SpellData {
             healing: 0,
             radius: 0,
             angle: 0,
+            pierce: 0,
+            bounces: 0,
+            trajectory: Trajectory::Straight,
+            requirements: SpellRequirements::default(),
+            status_duration: spell_defaults::status_duration(),
+            summon: None,
+            polymorph: None,
+            structure: None,
+            zone: None,
+            beam: None,
+            chain: None,
+            barrier: None,
+            knockback: None,
+            cast_xp: spell_defaults::cast_xp(),
+            hit_xp: spell_defaults::hit_xp(),
             buffs: Vec::new(),
             debuffs: Vec::new(),
             particles: Vec::new(),
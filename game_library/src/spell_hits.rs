@@ -0,0 +1,59 @@
+//! Tracks what a spell has hit, and how many more hits it can take before it stops.
+
+use bevy::prelude::*;
+
+/// The entity which cast a spell. This is stored on the spell entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub struct SpellCaster(pub Entity);
+
+/// What a spell has hit so far
+///
+/// A spell can pass through `pierce` targets and bounce off `bounces` obstacles. Once it
+/// hits something with none of those left, the spell is done. A spell never hits the same
+/// target twice.
+#[derive(Debug, Clone, PartialEq, Eq, Component, Default, Reflect)]
+pub struct SpellHits {
+    /// How many more targets the spell can pass through
+    pub pierce: u32,
+    /// How many more times the spell can bounce off an obstacle
+    pub bounces: u32,
+    /// The targets the spell has hit
+    pub hit: Vec<Entity>,
+}
+
+impl SpellHits {
+    /// Creates a new spell hit tracker with the given pierce and bounce counts
+    #[must_use]
+    pub const fn new(pierce: u32, bounces: u32) -> Self {
+        Self {
+            pierce,
+            bounces,
+            hit: Vec::new(),
+        }
+    }
+    /// Returns true if the spell has already hit the target
+    #[must_use]
+    pub fn has_hit(&self, target: Entity) -> bool {
+        self.hit.contains(&target)
+    }
+    /// Records a hit on a target. Returns true if the spell pierces through and continues.
+    pub fn hit_target(&mut self, target: Entity) -> bool {
+        self.hit.push(target);
+        if self.pierce > 0 {
+            self.pierce -= 1;
+            true
+        } else {
+            false
+        }
+    }
+    /// Uses up one of the spell's bounces. Returns true if the spell bounces, or false if it
+    /// had no bounces left (and should stop).
+    pub fn bounce(&mut self) -> bool {
+        if self.bounces > 0 {
+            self.bounces -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
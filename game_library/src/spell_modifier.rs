@@ -0,0 +1,145 @@
+//! Spell modifiers change how a spell behaves when it's cast.
+//!
+//! Modifiers are attached to a loadout slot (see [`crate::SpellModifierChoices`]) and apply to
+//! any spell cast from that slot which matches their [`SpellCondition`]. They can add extra
+//! projectiles, make the spell split on impact, pierce, home in on targets, or trade one stat for
+//! another (e.g. bigger but slower projectiles).
+//!
+//! Modifiers compose into the [`crate::SpellSnapshot`] in the order they are attached, so the same
+//! loadout always produces the same spell.
+//!
+//! You can describe spell modifiers using YAML or JSON using the schema:
+//!
+//! ```yaml
+//! # $schema: "https://schemas.nwest.one/games/elementalist/spell_modifier.json"
+//! ```
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use std::{any::Any, hash::Hash};
+
+use crate::{
+    data_loader::DataFile,
+    enums::{GameSystem, StatEnum},
+    InternalId, SpellCondition, SpellData, SpellStatModifier, StatBonus,
+};
+
+/// Details about a spell modifier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellModifier {
+    /// The internal ID of the modifier.
+    pub internal_id: Option<String>,
+    /// The name of the modifier.
+    pub name: String,
+    /// A short description of the modifier.
+    pub description: String,
+    /// The spells this modifier applies to. The default applies to every spell.
+    #[serde(default)]
+    pub condition: SpellCondition,
+    /// Stat bonuses for the spell (e.g. `projectileSize` 1.5 and `projectileSpeed` 0.8).
+    #[serde(default = "Vec::new")]
+    pub effects: Vec<(StatEnum, StatBonus)>,
+    /// Extra projectiles to launch with each cast.
+    #[serde(default)]
+    pub extra_projectiles: u32,
+    /// The angle (in degrees) the projectiles are spread across when there is more than one.
+    #[serde(default)]
+    pub spread: f32,
+    /// Extra targets the spell passes through before it stops.
+    #[serde(default)]
+    pub pierce: u32,
+    /// Extra times the spell bounces off obstacles before it stops.
+    #[serde(default)]
+    pub bounces: u32,
+    /// How quickly (in degrees per second) the spell turns towards the nearest target.
+    #[serde(default)]
+    pub homing: f32,
+    /// Split the spell into child spells when it hits a target.
+    #[serde(default)]
+    pub split: Option<SpellSplit>,
+}
+
+/// Splitting a spell into child spells when it hits a target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellSplit {
+    /// The unique id of the spell to split into. The default is the spell itself.
+    #[serde(default)]
+    pub spell: Option<String>,
+    /// How many child spells to spawn.
+    pub count: u32,
+    /// The angle (in degrees) the child spells are spread across.
+    #[serde(default = "spell_modifier_defaults::split_spread")]
+    pub spread: f32,
+}
+
+mod spell_modifier_defaults {
+    pub(super) const fn split_spread() -> f32 {
+        90.0
+    }
+}
+
+impl SpellModifier {
+    /// Returns true if this modifier applies to the spell.
+    #[must_use]
+    pub fn applies_to(&self, spell: &SpellData) -> bool {
+        self.condition.matches(spell)
+    }
+
+    /// Returns true if this modifier adds homing to the spell, but the spell's trajectory can't
+    /// home (so the homing is ignored).
+    #[must_use]
+    pub fn homing_ignored_by(&self, spell: &SpellData) -> bool {
+        self.homing > 0.0 && self.applies_to(spell) && !spell.trajectory.supports_homing()
+    }
+
+    /// The stat bonuses of this modifier as a [`SpellStatModifier`].
+    #[must_use]
+    pub fn stat_modifier(&self) -> SpellStatModifier {
+        SpellStatModifier {
+            condition: self.condition.clone(),
+            effects: self.effects.clone(),
+        }
+    }
+}
+
+impl Hash for SpellModifier {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.description.hash(state);
+    }
+}
+
+impl InternalId for SpellModifier {
+    /// Update the modifier's internal ID.
+    fn update_internal_id(&mut self) {
+        self.internal_id = Some(self.get_internal_id());
+    }
+    /// Get the modifier's internal ID.
+    #[must_use]
+    fn get_internal_id(&self) -> String {
+        if self.internal_id.is_some() {
+            let id = self.internal_id.clone().unwrap_or_default();
+            if !id.is_empty() {
+                return id;
+            }
+        }
+
+        format!("spellModifier{}", self.name.replace(' ', ""))
+    }
+}
+
+impl<D: Hash + InternalId + 'static> TryFrom<&DataFile<D>> for SpellModifier {
+    type Error = ();
+
+    fn try_from(data_file: &DataFile<D>) -> Result<Self, Self::Error> {
+        if data_file.header.system != GameSystem::SpellModifier {
+            return Err(());
+        }
+
+        (&data_file.data as &dyn Any)
+            .downcast_ref::<Self>()
+            .cloned()
+            .ok_or(())
+    }
+}
@@ -0,0 +1,55 @@
+//! A resource that stores the spell modifiers attached to each of the player's loadout slots.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::CastSlot;
+
+/// The spell modifiers attached to each cast slot, by their unique id.
+///
+/// Modifiers apply (in order) to the spells cast from the slot they are attached to.
+#[derive(Resource, Default, Debug, Reflect, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpellModifierChoices {
+    /// The modifiers attached to the primary slot.
+    pub primary: Vec<String>,
+    /// The modifiers attached to the secondary slot.
+    pub secondary: Vec<String>,
+    /// The modifiers attached to the defensive slot.
+    pub defensive: Vec<String>,
+    /// The modifiers attached to the ultimate slot.
+    pub ultimate: Vec<String>,
+}
+
+impl SpellModifierChoices {
+    /// The modifiers attached to a slot.
+    #[must_use]
+    pub fn get(&self, slot: CastSlot) -> &[String] {
+        match slot {
+            CastSlot::Primary => &self.primary,
+            CastSlot::Secondary => &self.secondary,
+            CastSlot::Defensive => &self.defensive,
+            CastSlot::Ultimate => &self.ultimate,
+        }
+    }
+    /// Attach a modifier (by unique id) to the end of a slot's modifiers.
+    pub fn attach(&mut self, slot: CastSlot, modifier_id: impl Into<String>) {
+        self.slot_mut(slot).push(modifier_id.into());
+    }
+    /// Detach every instance of a modifier (by unique id) from a slot.
+    pub fn detach(&mut self, slot: CastSlot, modifier_id: &str) {
+        self.slot_mut(slot).retain(|id| id != modifier_id);
+    }
+    /// Detach all of the modifiers from a slot.
+    pub fn clear(&mut self, slot: CastSlot) {
+        self.slot_mut(slot).clear();
+    }
+
+    fn slot_mut(&mut self, slot: CastSlot) -> &mut Vec<String> {
+        match slot {
+            CastSlot::Primary => &mut self.primary,
+            CastSlot::Secondary => &mut self.secondary,
+            CastSlot::Defensive => &mut self.defensive,
+            CastSlot::Ultimate => &mut self.ultimate,
+        }
+    }
+}
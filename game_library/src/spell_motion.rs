@@ -0,0 +1,187 @@
+//! Spell motion component (or how a spell moves along its trajectory)
+
+use bevy::prelude::*;
+
+use crate::enums::Trajectory;
+
+/// How close a returning boomerang has to get to its caster to be caught
+const BOOMERANG_CATCH_DISTANCE: f32 = 8.0;
+
+/// The state of a spell moving along its [`Trajectory`]
+///
+/// Each frame, [`SpellMotion::step`] is given how long the frame was and where the spell is, and
+/// returns the velocity the spell should have for the frame. Since it only depends on what it's
+/// given, it can be stepped with a fixed timestep (e.g. in tests) and give the same path every
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct SpellMotion {
+    /// The trajectory the spell follows
+    pub trajectory: Trajectory,
+    /// Where the spell was cast from
+    pub origin: Vec2,
+    /// The direction the spell was aimed (normalized)
+    pub direction: Vec2,
+    /// The speed the spell was launched with (in world units per second)
+    pub speed: f32,
+    /// How far the spell goes before an arc lands or a boomerang turns back
+    pub reach: f32,
+    /// How long the spell has been moving, in seconds
+    pub elapsed: f32,
+    /// True once a boomerang has turned back towards its caster
+    pub returning: bool,
+    /// True once the spell has reached the end of its trajectory
+    pub finished: bool,
+}
+
+impl SpellMotion {
+    /// Creates the motion for a spell cast from `origin` in `direction` at `speed`.
+    ///
+    /// Arcs land `aim_distance` away (but no further than `range`), and boomerangs turn back at
+    /// `range`. A range of 0.0 or less is unlimited.
+    #[must_use]
+    pub fn new(
+        trajectory: Trajectory,
+        origin: Vec2,
+        direction: Vec2,
+        speed: f32,
+        aim_distance: f32,
+        range: f32,
+    ) -> Self {
+        let range = if range > 0.0 { range } else { f32::INFINITY };
+        let reach = match trajectory {
+            Trajectory::Arc { .. } => aim_distance.clamp(0.0, range),
+            _ => range,
+        };
+        Self {
+            trajectory,
+            origin,
+            direction: direction.normalize_or_zero(),
+            speed,
+            reach,
+            elapsed: 0.0,
+            returning: false,
+            finished: false,
+        }
+    }
+
+    /// Sends the spell off in a new direction from `position` (e.g. when it bounces off a wall),
+    /// as if it had been cast from there. Sine waves start a new wave, but everything else keeps
+    /// its elapsed time (so spells which stop after a while still stop on time).
+    pub fn redirect(&mut self, position: Vec2, direction: Vec2) {
+        self.origin = position;
+        self.direction = direction.normalize_or_zero();
+        if matches!(self.trajectory, Trajectory::SineWave { .. }) {
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Where an arcing spell lands
+    #[must_use]
+    pub fn landing_point(&self) -> Vec2 {
+        self.origin + self.direction * self.reach
+    }
+
+    /// True once an arcing spell has reached its landing point (where it hits what it lands on)
+    #[must_use]
+    pub const fn has_landed(&self) -> bool {
+        self.finished && matches!(self.trajectory, Trajectory::Arc { .. })
+    }
+
+    /// Advances the motion by `delta` seconds and returns the velocity the spell should have.
+    ///
+    /// * `position` is where the spell is now
+    /// * `velocity` is the spell's current velocity (straight spells keep it, homing spells turn it)
+    /// * `caster` is where the spell's caster is now (for orbits and boomerangs)
+    /// * `target` is where the nearest target is, if there is one (for homing spells)
+    pub fn step(
+        &mut self,
+        delta: f32,
+        position: Vec2,
+        velocity: Vec2,
+        caster: Vec2,
+        target: Option<Vec2>,
+    ) -> Vec2 {
+        if delta <= 0.0 {
+            return velocity;
+        }
+        if self.finished {
+            return Vec2::ZERO;
+        }
+        self.elapsed += delta;
+        let elapsed = self.elapsed;
+        // Velocity to get from the current position to `next` over this step
+        let towards = |next: Vec2| (next - position) / delta;
+
+        match self.trajectory {
+            Trajectory::Straight => velocity,
+            Trajectory::Homing { turn_rate } => target.map_or(velocity, |target| {
+                turn_towards(velocity, target - position, turn_rate.to_radians() * delta)
+            }),
+            Trajectory::Arc { height } => {
+                let flight_time = if self.speed > 0.0 {
+                    self.reach / self.speed
+                } else {
+                    0.0
+                };
+                if elapsed >= flight_time {
+                    self.finished = true;
+                    return towards(self.landing_point());
+                }
+                let progress = elapsed / flight_time;
+                let lift = height * 4.0 * progress * (1.0 - progress);
+                towards(self.origin.lerp(self.landing_point(), progress) + Vec2::Y * lift)
+            }
+            Trajectory::Orbit {
+                radius,
+                angular_speed,
+            } => {
+                let start = self.direction.y.atan2(self.direction.x);
+                let angle = angular_speed.to_radians().mul_add(elapsed, start);
+                towards(caster + Vec2::from_angle(angle) * radius)
+            }
+            Trajectory::Boomerang => {
+                if !self.returning && position.distance(self.origin) < self.reach {
+                    return self.direction * self.speed;
+                }
+                self.returning = true;
+                let to_caster = caster - position;
+                if to_caster.length() <= BOOMERANG_CATCH_DISTANCE.max(self.speed * delta) {
+                    self.finished = true;
+                    return to_caster / delta;
+                }
+                to_caster.normalize_or_zero() * self.speed
+            }
+            Trajectory::SineWave {
+                amplitude,
+                frequency,
+            } => {
+                let weave = amplitude * (std::f32::consts::TAU * frequency * elapsed).sin();
+                towards(
+                    self.origin
+                        + self.direction * self.speed * elapsed
+                        + self.direction.perp() * weave,
+                )
+            }
+            Trajectory::AccelerateThenStop {
+                acceleration,
+                stop_after,
+            } => {
+                if elapsed > stop_after {
+                    Vec2::ZERO
+                } else {
+                    self.direction * acceleration.mul_add(elapsed, self.speed).max(0.0)
+                }
+            }
+        }
+    }
+}
+
+/// Rotates `current` towards `desired` by at most `max_angle` radians (keeping its length).
+#[must_use]
+pub fn turn_towards(current: Vec2, desired: Vec2, max_angle: f32) -> Vec2 {
+    let angle = current.angle_between(desired);
+    if angle.is_nan() {
+        return current;
+    }
+    Vec2::from_angle(angle.clamp(-max_angle, max_angle)).rotate(current)
+}
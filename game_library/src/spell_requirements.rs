@@ -0,0 +1,40 @@
+//! Requirements a player has to meet before they can unlock a spell.
+//!
+//! Requirements are part of the [`crate::SpellData`] (the `requirements` in the data files) and
+//! are checked when unlocking spells with [`crate::UnlockedSpells`].
+//!
+//! ```yaml
+//! requirements:
+//!   skillLevel: 3
+//!   prerequisites:
+//!     - firebolt
+//!   research:
+//!     - combustion
+//! ```
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// Requirements a player has to meet before they can unlock a spell.
+///
+/// The default has no requirements, so the spell can be unlocked right away.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellRequirements {
+    /// The minimum level the player needs in the spell's skill (see [`crate::SpellData::skill`]).
+    #[serde(default)]
+    pub skill_level: u32,
+    /// The unique ids of spells which have to be unlocked first.
+    #[serde(default = "Vec::new")]
+    pub prerequisites: Vec<String>,
+    /// Research flags which have to be completed first.
+    #[serde(default = "Vec::new")]
+    pub research: Vec<String>,
+}
+
+impl SpellRequirements {
+    /// Returns true if there are no requirements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.skill_level == 0 && self.prerequisites.is_empty() && self.research.is_empty()
+    }
+}
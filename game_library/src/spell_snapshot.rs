@@ -0,0 +1,153 @@
+//! A snapshot of a spell's stats at the moment it was cast.
+//!
+//! The base values come from the [`SpellData`], which are then scaled by the caster's stats
+//! (e.g. [`StatEnum::ProjectileSpeed`]) and any [`SpellStatModifier`]s the caster has which
+//! apply to the spell. Since this is a snapshot, changes to the caster's stats after the spell
+//! was cast don't affect the spell.
+//!
+//! [`SpellModifier`]s attached to the slot the spell was cast from are folded in with
+//! [`SpellSnapshot::with_modifiers`] (their stat bonuses should be passed to [`SpellSnapshot::new`]
+//! along with the caster's other modifiers).
+
+use bevy::prelude::*;
+
+use crate::{
+    enums::{MagicType, SpellTarget, StatEnum, Trajectory},
+    SpellData, SpellModifier, SpellSplit, SpellStatModifier, StatBundle,
+};
+
+/// The stats of a spell as it was cast. This is stored on the spell entity.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+pub struct SpellSnapshot {
+    /// The unique id of the spell which was cast.
+    pub spell_id: String,
+    /// The type of magic of the spell.
+    pub magic: MagicType,
+    /// The speed of the spell (in the same units as [`SpellData::speed`]).
+    pub speed: f32,
+    /// The scale of the spell's sprite and collider (1.0 is the normal size).
+    pub scale: f32,
+    /// How long the spell lasts, in seconds.
+    pub lifetime: f32,
+    /// How far the spell can travel (in world units).
+    pub range: f32,
+    /// The damage the spell deals.
+    pub damage: f32,
+    /// The healing the spell does.
+    pub healing: f32,
+    /// Which entities the spell affects.
+    pub target: SpellTarget,
+    /// How many targets the spell passes through before it stops.
+    pub pierce: u32,
+    /// How many times the spell bounces off obstacles before it stops.
+    pub bounces: u32,
+    /// How many projectiles are launched with each cast.
+    pub projectiles: u32,
+    /// The angle (in degrees) the projectiles are spread across.
+    pub spread: f32,
+    /// How the spell moves (including any homing added by modifiers).
+    pub trajectory: Trajectory,
+    /// How the spell splits into child spells when it hits a target, if it does.
+    pub split: Option<SpellSplit>,
+}
+
+impl SpellSnapshot {
+    /// Create a snapshot of a spell as cast by a caster with the given stats and spell modifiers.
+    ///
+    /// Stats the caster doesn't have are treated as `1.0` (no change).
+    ///
+    /// * speed is scaled by [`StatEnum::ProjectileSpeed`]
+    /// * scale is scaled by [`StatEnum::ProjectileSize`]
+    /// * lifetime is scaled by [`StatEnum::ProjectileLifetime`]
+    /// * range is scaled by [`StatEnum::SpellRange`]
+    /// * damage is scaled by [`StatEnum::MagicDamage`]
+    #[must_use]
+    pub fn new<'a>(
+        spell_id: impl Into<String>,
+        spell: &SpellData,
+        caster_stats: Option<&StatBundle>,
+        modifiers: impl IntoIterator<Item = &'a SpellStatModifier>,
+    ) -> Self {
+        let modifier_totals = SpellStatModifier::bonus_totals(modifiers, spell);
+        let factor = |stat: StatEnum| {
+            let base = caster_stats
+                .and_then(|stats| stats.get_stat(&stat))
+                .map_or(1.0, crate::Stat::value);
+            let modifier = modifier_totals.get(&stat).copied().unwrap_or(0.0);
+            (base * (1.0 + modifier)).max(0.0)
+        };
+
+        Self {
+            spell_id: spell_id.into(),
+            magic: spell.magic,
+            speed: spell.speed * factor(StatEnum::ProjectileSpeed),
+            scale: factor(StatEnum::ProjectileSize),
+            lifetime: spell.duration * factor(StatEnum::ProjectileLifetime),
+            range: spell.range * factor(StatEnum::SpellRange),
+            damage: spell.damage as f32 * factor(StatEnum::MagicDamage),
+            healing: spell.healing as f32,
+            target: spell.target.clone(),
+            pierce: spell.pierce,
+            bounces: spell.bounces,
+            projectiles: 1,
+            spread: 0.0,
+            trajectory: spell.trajectory,
+            split: None,
+        }
+    }
+
+    /// Fold spell modifiers into the snapshot, in order. Modifiers which don't apply to the spell
+    /// are skipped.
+    ///
+    /// * extra projectiles, spread, pierce and bounces add up
+    /// * homing is added to the trajectory (see [`Trajectory::with_homing`])
+    /// * the first modifier which splits the spell picks the child spell and spread, and the
+    ///   split counts of any others are added to it
+    #[must_use]
+    pub fn with_modifiers<'a>(
+        mut self,
+        spell: &SpellData,
+        modifiers: impl IntoIterator<Item = &'a SpellModifier>,
+    ) -> Self {
+        for modifier in modifiers {
+            if !modifier.applies_to(spell) {
+                continue;
+            }
+            self.projectiles += modifier.extra_projectiles;
+            self.spread += modifier.spread;
+            self.pierce += modifier.pierce;
+            self.bounces += modifier.bounces;
+            self.trajectory = self.trajectory.with_homing(modifier.homing);
+            if let Some(split) = &modifier.split {
+                match self.split.as_mut() {
+                    Some(existing) => existing.count += split.count,
+                    None => self.split = Some(split.clone()),
+                }
+            }
+        }
+        self
+    }
+
+    /// The directions to launch the spell's projectiles in, spread evenly across the spread
+    /// angle and centered on `direction`.
+    #[must_use]
+    pub fn projectile_directions(&self, direction: Vec2) -> Vec<Vec2> {
+        spread_directions(direction, self.projectiles, self.spread)
+    }
+}
+
+/// Returns `count` directions spread evenly across `spread` degrees, centered on `direction`.
+/// A `count` of 0 returns no directions.
+#[must_use]
+pub fn spread_directions(direction: Vec2, count: u32, spread: f32) -> Vec<Vec2> {
+    match count {
+        0 => return Vec::new(),
+        1 => return vec![direction],
+        _ => {}
+    }
+    let step = spread.to_radians() / (count - 1) as f32;
+    let start = -spread.to_radians() / 2.0;
+    (0..count)
+        .map(|i| Vec2::from_angle(step.mul_add(i as f32, start)).rotate(direction))
+        .collect()
+}
@@ -0,0 +1,57 @@
+//! Spell travel component (or how far a spell has gone)
+
+use bevy::prelude::*;
+
+/// How far a spell has travelled from where it was cast
+///
+/// When a spell is spawned, it is given a maximum range (from the [`crate::SpellSnapshot`]).
+/// The distance is tracked along the path the spell actually took, so spells which curve
+/// or bounce still run out of range. When the range is used up, the spell expires.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default, Reflect)]
+pub struct SpellTravel {
+    /// Where the spell was cast from
+    pub origin: Vec2,
+    /// The position of the spell when it was last updated
+    pub last_position: Vec2,
+    /// The total distance the spell has travelled
+    pub distance: f32,
+    /// The maximum distance the spell can travel (0.0 or less means unlimited)
+    pub max_range: f32,
+}
+
+impl SpellTravel {
+    /// Creates a new spell travel tracker starting at `origin` with the given maximum range
+    #[must_use]
+    pub const fn new(origin: Vec2, max_range: f32) -> Self {
+        Self {
+            origin,
+            last_position: origin,
+            distance: 0.0,
+            max_range,
+        }
+    }
+    /// Updates the distance travelled with the spell's current position
+    pub fn update(&mut self, position: Vec2) {
+        self.distance += self.last_position.distance(position);
+        self.last_position = position;
+    }
+    /// Returns the straight-line distance between the origin and the spell's last position
+    #[must_use]
+    pub fn displacement(&self) -> f32 {
+        self.origin.distance(self.last_position)
+    }
+    /// Returns the distance the spell can still travel (infinite if the range is unlimited)
+    #[must_use]
+    pub fn remaining(&self) -> f32 {
+        if self.max_range > 0.0 {
+            (self.max_range - self.distance).max(0.0)
+        } else {
+            f32::INFINITY
+        }
+    }
+    /// Returns true if the spell has travelled its full range
+    #[must_use]
+    pub fn is_out_of_range(&self) -> bool {
+        self.max_range > 0.0 && self.distance >= self.max_range
+    }
+}
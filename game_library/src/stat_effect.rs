@@ -1,10 +1,51 @@
 //! Stat effects from a spell or other item.
 //!
 //! If a spell causes entities to move slower, this would have an effect that reduces the movement speed stat.
+//! Effects can also do damage or healing over time (e.g. a burn doing 2 damage every second).
 
 use bevy::reflect::Reflect;
 
-use crate::{enums::SpellTarget, enums::StatEnum, StatBonus};
+use crate::{
+    enums::{MagicType, SpellTarget, StackingRule, StatEnum},
+    StatBonus,
+};
+
+/// Damage (or healing) done periodically by a [`StatEffect`] while it's active.
+///
+/// Each tick does `amount` for every stack of the effect, through the same
+/// [`crate::events::ApplyDamage`] and [`crate::events::ApplyHealing`] events as spell hits.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Reflect,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodicEffect {
+    /// How often the effect ticks.
+    ///
+    /// This is measured in centiseconds.
+    #[serde(default = "periodic_defaults::interval")]
+    pub interval: u32,
+    /// The damage (or healing) done each tick, per stack.
+    pub amount: u32,
+    /// The type of magic doing the damage.
+    pub magic: MagicType,
+    /// Whether the effect restores health instead of doing damage.
+    #[serde(default)]
+    pub heals: bool,
+}
+
+mod periodic_defaults {
+    pub(super) const fn interval() -> u32 {
+        100
+    }
+}
+
+impl PeriodicEffect {
+    /// How often the effect ticks in seconds.
+    #[must_use]
+    pub fn interval_seconds(&self) -> f32 {
+        self.interval as f32 / 100.0
+    }
+}
 
 /// Effects from a spell or other item.
 ///
@@ -27,10 +68,35 @@ pub struct StatEffect {
     ///
     /// A value of `0` means that there is no limit.
     pub max_stacks: u32,
+    /// What happens when this effect is applied to a target which already has it active.
+    #[serde(default)]
+    pub stacking: StackingRule,
     /// Which entities this effect can be applied to. If the choice here is [`SpellTarget::Any`],
     /// then the effect can affect any entity, as long as they are "hit" by the spell.
     pub target: SpellTarget,
 
     /// The stat effects of this spell.
+    ///
+    /// Each [`StatBonus`] is applied (per stack) to the bonus of the matching stat in the
+    /// target's [`crate::StatBundle`], where `1.0` is no change, `1.2` is +20% and `0.8` is -20%.
     pub effects: Vec<(StatEnum, StatBonus)>,
+    /// Damage or healing the effect does periodically while it's active.
+    #[serde(default)]
+    pub periodic: Option<PeriodicEffect>,
+
+    /// The tileset with the effect's status icon, shown over the entities it's active on.
+    /// Effects without one aren't shown.
+    #[serde(default)]
+    pub icon_tileset: Option<String>,
+    /// The index of the status icon in the tileset.
+    #[serde(default)]
+    pub icon_index: usize,
+}
+
+impl StatEffect {
+    /// The base duration of the effect in seconds.
+    #[must_use]
+    pub fn duration_seconds(&self) -> f32 {
+        self.duration as f32 / 100.0
+    }
 }
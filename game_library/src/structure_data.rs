@@ -0,0 +1,123 @@
+//! Structure data describes the walls, pillars and other obstacles a spell places on the ground.
+//!
+//! Structure data is part of the [`crate::SpellData`] (the `structure` in the data files). The
+//! structure is placed where the spell is aimed (up to the spell's range), blocks movement and
+//! the spells of the other side, and is destroyed when its health or lifetime runs out.
+//!
+//! ```yaml
+//! castCategory: ground
+//! structure:
+//!   spriteTileset: rock
+//!   shape:
+//!     rectangle:
+//!       width: 12
+//!       height: 24
+//!   health: 40
+//!   lifetime: 8
+//!   destructionSpell: flame_burst
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+use crate::AreaShape;
+
+/// Details about the structure a spell places.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct StructureData {
+    /// The tileset with the structure's sprite.
+    #[serde(default = "structure_defaults::placeholder_png_path")]
+    pub sprite_tileset: String,
+    /// The index of the structure's sprite in the tileset.
+    #[serde(default)]
+    pub sprite_index: usize,
+    /// The shape of the structure's collider.
+    #[serde(default)]
+    pub shape: AreaShape,
+    /// The structure's max health. Each spell which hits it does at least 1 damage.
+    #[serde(default = "structure_defaults::health")]
+    pub health: u32,
+    /// How long (in seconds) the structure lasts.
+    #[serde(default = "structure_defaults::lifetime")]
+    pub lifetime: f32,
+    /// The unique id of a spell to spawn where the structure is destroyed (not when it expires).
+    #[serde(default)]
+    pub destruction_spell: Option<String>,
+}
+
+mod structure_defaults {
+    pub(super) fn placeholder_png_path() -> String {
+        "placeholder.png".to_string()
+    }
+    pub(super) const fn health() -> u32 {
+        30
+    }
+    pub(super) const fn lifetime() -> f32 {
+        6.0
+    }
+}
+
+impl Default for StructureData {
+    fn default() -> Self {
+        Self {
+            sprite_tileset: structure_defaults::placeholder_png_path(),
+            sprite_index: 0,
+            shape: AreaShape::default(),
+            health: structure_defaults::health(),
+            lifetime: structure_defaults::lifetime(),
+            destruction_spell: None,
+        }
+    }
+}
+
+impl StructureData {
+    /// Get the structure's sprite as a texture atlas sprite.
+    #[must_use]
+    pub fn texture_atlas_index(&self) -> TextureAtlasSprite {
+        TextureAtlasSprite::new(self.sprite_index)
+    }
+}
+
+/// Where a ground-placed spell lands: at the aim position, but no further than `range` from the
+/// caster (a range of 0.0 or less is unlimited).
+#[must_use]
+pub fn ground_target(origin: Vec2, aim: Vec2, range: f32) -> Vec2 {
+    let offset = aim - origin;
+    if range > 0.0 {
+        origin + offset.clamp_length_max(range)
+    } else {
+        aim
+    }
+}
+
+/// A structure placed by a spell.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct Structure {
+    /// The entity which placed it.
+    pub owner: Entity,
+    /// The unique id of the spell which placed it.
+    pub spell_id: String,
+    /// The remaining lifetime in seconds.
+    pub remaining: f32,
+}
+
+impl Structure {
+    /// Creates a structure placed by `owner`, lasting `lifetime` seconds.
+    #[must_use]
+    pub fn new(owner: Entity, spell_id: impl Into<String>, lifetime: f32) -> Self {
+        Self {
+            owner,
+            spell_id: spell_id.into(),
+            remaining: lifetime,
+        }
+    }
+    /// Counts down the remaining lifetime by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining -= delta;
+    }
+    /// Returns true if the structure's lifetime has run out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
@@ -0,0 +1,143 @@
+//! Summon data describes the allied entities a summoning spell spawns.
+//!
+//! Summon data is part of the [`crate::SpellData`] (the `summon` in the data files) and is used by
+//! spells with the `summon` cast category.
+//!
+//! ```yaml
+//! castCategory: summon
+//! summon:
+//!   spriteTileset: imp
+//!   health: 20
+//!   stats:
+//!     - [movementSpeed, 80]
+//!   lifetime: 30
+//!   maxCount: 2
+//!   attackSpell: fire_bolt
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+use crate::enums::StatEnum;
+
+/// Details about the entities a summoning spell spawns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonData {
+    /// The tileset with the summon's sprite.
+    #[serde(default = "summon_defaults::placeholder_png_path")]
+    pub sprite_tileset: String,
+    /// The index of the summon's sprite in the tileset.
+    #[serde(default)]
+    pub sprite_index: usize,
+    /// The summon's max health.
+    #[serde(default = "summon_defaults::health")]
+    pub health: u32,
+    /// The summon's stats (e.g. `movementSpeed`).
+    #[serde(default = "Vec::new")]
+    pub stats: Vec<(StatEnum, f32)>,
+    /// How long (in seconds) the summon lasts. 0 lasts until the caster's run ends.
+    #[serde(default = "summon_defaults::lifetime")]
+    pub lifetime: f32,
+    /// How many of these summons a caster can have at once. Summoning more replaces the oldest.
+    #[serde(default = "summon_defaults::max_count")]
+    pub max_count: usize,
+    /// How many summons are spawned each cast.
+    #[serde(default = "summon_defaults::count")]
+    pub count: usize,
+    /// The unique id of the spell the summon attacks enemies with.
+    #[serde(default)]
+    pub attack_spell: Option<String>,
+}
+
+mod summon_defaults {
+    pub(super) fn placeholder_png_path() -> String {
+        "placeholder.png".to_string()
+    }
+    pub(super) const fn health() -> u32 {
+        10
+    }
+    pub(super) const fn lifetime() -> f32 {
+        30.0
+    }
+    pub(super) const fn max_count() -> usize {
+        1
+    }
+    pub(super) const fn count() -> usize {
+        1
+    }
+}
+
+impl SummonData {
+    /// Get the summon's sprite as a texture atlas sprite.
+    #[must_use]
+    pub fn texture_atlas_index(&self) -> TextureAtlasSprite {
+        TextureAtlasSprite::new(self.sprite_index)
+    }
+    /// Returns how many summons can be spawned for a cast, given how many the caster already has.
+    ///
+    /// The second value is how many of the existing summons (the oldest) have to be despawned to
+    /// stay within [`Self::max_count`].
+    #[must_use]
+    pub fn plan_spawns(&self, existing: usize) -> (usize, usize) {
+        let spawned = self.count.min(self.max_count);
+        let replaced = (existing + spawned).saturating_sub(self.max_count);
+        (spawned, replaced.min(existing))
+    }
+}
+
+impl Default for SummonData {
+    fn default() -> Self {
+        Self {
+            sprite_tileset: summon_defaults::placeholder_png_path(),
+            sprite_index: 0,
+            health: summon_defaults::health(),
+            stats: Vec::new(),
+            lifetime: summon_defaults::lifetime(),
+            max_count: summon_defaults::max_count(),
+            count: summon_defaults::count(),
+            attack_spell: None,
+        }
+    }
+}
+
+/// An entity summoned by a spell, fighting for its caster.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct Summoned {
+    /// The entity which summoned it.
+    pub owner: Entity,
+    /// The unique id of the spell which summoned it.
+    pub spell_id: String,
+    /// When it was summoned (elapsed seconds), used to replace the oldest summons first.
+    pub summoned_at: f32,
+    /// The remaining lifetime in seconds (`None` lasts until the caster's run ends).
+    pub remaining: Option<f32>,
+}
+
+impl Summoned {
+    /// Creates a summon for `owner`, with the lifetime from its [`SummonData`].
+    #[must_use]
+    pub fn new(
+        owner: Entity,
+        spell_id: impl Into<String>,
+        summoned_at: f32,
+        lifetime: f32,
+    ) -> Self {
+        Self {
+            owner,
+            spell_id: spell_id.into(),
+            summoned_at,
+            remaining: (lifetime > 0.0).then_some(lifetime),
+        }
+    }
+    /// Counts down the remaining lifetime by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= delta;
+        }
+    }
+    /// Returns true if the summon's lifetime has run out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining.is_some_and(|remaining| remaining <= 0.0)
+    }
+}
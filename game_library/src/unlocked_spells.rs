@@ -0,0 +1,150 @@
+//! A resource that stores which spells the player has unlocked.
+//!
+//! Spells are unlocked once the player meets their [`SpellRequirements`]: a high enough level in
+//! the spell's skill, the prerequisite spells unlocked, and the research flags completed. Only
+//! unlocked spells can be chosen in the [`crate::SpellChoices`].
+
+use bevy::{prelude::*, utils::HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::{data_loader::storage::Vault, enums::Skill, Skills, SpellData, SpellRequirements};
+
+/// The spells the player has unlocked, and the research they've completed.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub struct UnlockedSpells {
+    /// The unique ids of the unlocked spells.
+    spells: HashSet<String>,
+    /// The completed research flags.
+    research: HashSet<String>,
+}
+
+/// Why a spell can't be unlocked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpellUnlockError {
+    /// The player's level in the spell's skill is too low.
+    SkillTooLow {
+        /// The spell's skill.
+        skill: Skill,
+        /// The level needed to unlock the spell.
+        required: u32,
+        /// The player's current level.
+        current: u32,
+    },
+    /// A prerequisite spell (by unique id) hasn't been unlocked.
+    MissingPrerequisite(String),
+    /// A research flag hasn't been completed.
+    MissingResearch(String),
+}
+
+impl std::fmt::Display for SpellUnlockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SkillTooLow {
+                skill,
+                required,
+                current,
+            } => write!(f, "requires {skill} level {required} (currently {current})"),
+            Self::MissingPrerequisite(spell_id) => write!(f, "requires {spell_id} to be unlocked"),
+            Self::MissingResearch(flag) => write!(f, "requires {flag} to be researched"),
+        }
+    }
+}
+
+impl std::error::Error for SpellUnlockError {}
+
+impl UnlockedSpells {
+    /// Returns true if the spell (by unique id) is unlocked.
+    #[must_use]
+    pub fn is_unlocked(&self, spell_id: &str) -> bool {
+        self.spells.contains(spell_id)
+    }
+    /// Returns true if the research flag has been completed.
+    #[must_use]
+    pub fn has_research(&self, flag: &str) -> bool {
+        self.research.contains(flag)
+    }
+    /// Marks a research flag as completed.
+    pub fn complete_research(&mut self, flag: impl Into<String>) {
+        self.research.insert(flag.into());
+    }
+    /// Returns an iterator over the unique ids of the unlocked spells.
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.spells.iter()
+    }
+
+    /// Checks if the player meets the spell's requirements, returning the first one they don't.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SpellUnlockError`] describing the first requirement which isn't met.
+    pub fn check_requirements(
+        &self,
+        spell: &SpellData,
+        skills: &Skills,
+    ) -> Result<(), SpellUnlockError> {
+        let SpellRequirements {
+            skill_level,
+            prerequisites,
+            research,
+        } = &spell.requirements;
+
+        let skill = spell.skill();
+        let current = skills.get_level(skill).unwrap_or_default();
+        if current < *skill_level {
+            return Err(SpellUnlockError::SkillTooLow {
+                skill,
+                required: *skill_level,
+                current,
+            });
+        }
+        if let Some(missing) = prerequisites.iter().find(|id| !self.is_unlocked(id)) {
+            return Err(SpellUnlockError::MissingPrerequisite(missing.clone()));
+        }
+        if let Some(missing) = research.iter().find(|flag| !self.has_research(flag)) {
+            return Err(SpellUnlockError::MissingResearch(missing.clone()));
+        }
+        Ok(())
+    }
+
+    /// Unlocks the spell (by unique id) if the player meets its requirements. Unlocking a spell
+    /// which is already unlocked does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SpellUnlockError`] describing the first requirement which isn't met.
+    pub fn unlock(
+        &mut self,
+        spell_id: &str,
+        spell: &SpellData,
+        skills: &Skills,
+    ) -> Result<(), SpellUnlockError> {
+        if self.is_unlocked(spell_id) {
+            return Ok(());
+        }
+        self.check_requirements(spell, skills)?;
+        self.spells.insert(spell_id.to_string());
+        Ok(())
+    }
+
+    /// Unlocks every spell whose requirements are met (including spells whose prerequisites are
+    /// unlocked along the way). Returns the unique ids of the newly unlocked spells, sorted.
+    pub fn unlock_available(&mut self, spells: &Vault<SpellData>, skills: &Skills) -> Vec<String> {
+        let mut newly_unlocked = Vec::new();
+        loop {
+            let available: Vec<String> = spells
+                .iter()
+                .filter(|(id, spell)| {
+                    !self.is_unlocked(id) && self.check_requirements(spell, skills).is_ok()
+                })
+                .map(|(id, _)| id.clone())
+                .collect();
+            if available.is_empty() {
+                break;
+            }
+            self.spells.extend(available.iter().cloned());
+            newly_unlocked.extend(available);
+        }
+        newly_unlocked.sort();
+        newly_unlocked
+    }
+}
@@ -0,0 +1,256 @@
+//! Zone data describes the lingering areas a spell places on the ground (e.g. burning coals or
+//! quicksand).
+//!
+//! Zone data is part of the [`crate::SpellData`] (the `zone` in the data files). The zone is
+//! placed where the spell is aimed (up to the spell's range) and keeps track of the characters
+//! inside it. Its effects are applied when a character enters, every `tickInterval` seconds to
+//! everyone inside, and when a character leaves. Characters inside the zone also have their
+//! movement speed multiplied by `movementSpeed`.
+//!
+//! ```yaml
+//! castCategory: ground
+//! zone:
+//!   shape:
+//!     circle:
+//!       radius: 32
+//!   lifetime: 6
+//!   tickInterval: 0.5
+//!   movementSpeed: 0.6
+//!   onTick:
+//!     damage: 2
+//! ```
+use bevy::{prelude::*, reflect::Reflect};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{SpellTarget, StackingRule, StatEnum},
+    AreaShape, KnockbackData, StatBonus, StatEffect,
+};
+
+/// What a zone does to a character at one of its triggers (entering, ticking or leaving).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneEffects {
+    /// Damage done to the character.
+    #[serde(default)]
+    pub damage: u32,
+    /// Health restored to the character.
+    #[serde(default)]
+    pub healing: u32,
+    /// Effects applied to the character.
+    #[serde(default = "Vec::new")]
+    pub effects: Vec<StatEffect>,
+    /// Knockback from the center of the zone.
+    #[serde(default)]
+    pub knockback: Option<KnockbackData>,
+}
+
+impl ZoneEffects {
+    /// Returns true if the trigger does nothing.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.damage == 0 && self.healing == 0 && self.effects.is_empty() && self.knockback.is_none()
+    }
+}
+
+/// Details about the zone a spell places.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneData {
+    /// The tileset with the zone's sprite.
+    #[serde(default = "zone_defaults::placeholder_png_path")]
+    pub sprite_tileset: String,
+    /// The index of the zone's sprite in the tileset.
+    #[serde(default)]
+    pub sprite_index: usize,
+    /// The shape of the zone.
+    #[serde(default = "zone_defaults::shape")]
+    pub shape: AreaShape,
+    /// How long (in seconds) the zone lasts.
+    #[serde(default = "zone_defaults::lifetime")]
+    pub lifetime: f32,
+    /// How often (in seconds) the `onTick` effects are applied to everyone inside.
+    #[serde(default = "zone_defaults::tick_interval")]
+    pub tick_interval: f32,
+    /// Multiplier for the movement speed of characters inside the zone (1.0 is no change).
+    #[serde(default = "zone_defaults::movement_speed")]
+    pub movement_speed: f32,
+    /// Effects applied when a character enters the zone.
+    #[serde(default)]
+    pub on_enter: ZoneEffects,
+    /// Effects applied to everyone inside the zone every `tickInterval` seconds.
+    #[serde(default)]
+    pub on_tick: ZoneEffects,
+    /// Effects applied when a character leaves the zone (not when the zone expires).
+    #[serde(default)]
+    pub on_exit: ZoneEffects,
+}
+
+mod zone_defaults {
+    use crate::AreaShape;
+
+    pub(super) fn placeholder_png_path() -> String {
+        "placeholder.png".to_string()
+    }
+    pub(super) const fn shape() -> AreaShape {
+        AreaShape::Circle { radius: 24.0 }
+    }
+    pub(super) const fn lifetime() -> f32 {
+        5.0
+    }
+    pub(super) const fn tick_interval() -> f32 {
+        1.0
+    }
+    pub(super) const fn movement_speed() -> f32 {
+        1.0
+    }
+}
+
+impl Default for ZoneData {
+    fn default() -> Self {
+        Self {
+            sprite_tileset: zone_defaults::placeholder_png_path(),
+            sprite_index: 0,
+            shape: zone_defaults::shape(),
+            lifetime: zone_defaults::lifetime(),
+            tick_interval: zone_defaults::tick_interval(),
+            movement_speed: zone_defaults::movement_speed(),
+            on_enter: ZoneEffects::default(),
+            on_tick: ZoneEffects::default(),
+            on_exit: ZoneEffects::default(),
+        }
+    }
+}
+
+impl ZoneData {
+    /// Get the zone's sprite as a texture atlas sprite.
+    #[must_use]
+    pub fn texture_atlas_index(&self) -> TextureAtlasSprite {
+        TextureAtlasSprite::new(self.sprite_index)
+    }
+
+    /// The effect which changes the movement speed of characters inside the zone (if it does).
+    ///
+    /// It lasts as long as the zone, and is removed when they leave.
+    #[must_use]
+    pub fn movement_effect(&self, spell_id: &str) -> Option<StatEffect> {
+        if (self.movement_speed - 1.0).abs() < f32::EPSILON {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = (self.lifetime.max(0.0) * 100.0).ceil() as u32;
+        Some(StatEffect {
+            id: Self::movement_effect_id(spell_id),
+            name: "Zone".to_string(),
+            description: "Standing in a zone.".to_string(),
+            duration,
+            max_stacks: 1,
+            stacking: StackingRule::Refresh,
+            target: SpellTarget::Any,
+            effects: vec![(StatEnum::MovementSpeed, StatBonus::new(self.movement_speed))],
+            ..StatEffect::default()
+        })
+    }
+
+    /// The id of the movement speed effect of zones placed by the spell.
+    #[must_use]
+    pub fn movement_effect_id(spell_id: &str) -> String {
+        format!("zone:{spell_id}")
+    }
+}
+
+/// A zone placed by a spell, tracking the characters inside it.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct Zone {
+    /// The entity which placed it.
+    pub owner: Entity,
+    /// The unique id of the spell which placed it.
+    pub spell_id: String,
+    /// The remaining lifetime in seconds.
+    pub remaining: f32,
+    /// How often the zone ticks, in seconds.
+    pub tick_interval: f32,
+    /// The time until the next tick, in seconds.
+    pub until_tick: f32,
+    /// The characters inside the zone, in the order they entered.
+    occupants: Vec<Entity>,
+}
+
+impl Zone {
+    /// Creates a zone placed by `owner`.
+    #[must_use]
+    pub fn new(owner: Entity, spell_id: impl Into<String>, data: &ZoneData) -> Self {
+        Self {
+            owner,
+            spell_id: spell_id.into(),
+            remaining: data.lifetime,
+            tick_interval: data.tick_interval,
+            until_tick: data.tick_interval,
+            occupants: Vec::new(),
+        }
+    }
+
+    /// A character entered the zone. Returns false if it was already inside.
+    pub fn enter(&mut self, entity: Entity) -> bool {
+        if self.contains(entity) {
+            return false;
+        }
+        self.occupants.push(entity);
+        true
+    }
+
+    /// A character left the zone. Returns false if it wasn't inside.
+    pub fn exit(&mut self, entity: Entity) -> bool {
+        let before = self.occupants.len();
+        self.occupants.retain(|occupant| *occupant != entity);
+        self.occupants.len() != before
+    }
+
+    /// Returns true if the character is inside the zone.
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.occupants.contains(&entity)
+    }
+
+    /// Returns an iterator over the characters inside the zone.
+    pub fn occupants(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.occupants.iter().copied()
+    }
+
+    /// Forget the characters which no longer exist (or can't be affected), returning them.
+    pub fn retain_occupants(&mut self, mut keep: impl FnMut(Entity) -> bool) -> Vec<Entity> {
+        let mut removed = Vec::new();
+        self.occupants.retain(|occupant| {
+            let kept = keep(*occupant);
+            if !kept {
+                removed.push(*occupant);
+            }
+            kept
+        });
+        removed
+    }
+
+    /// Counts down the lifetime and the time until the next tick by `delta` seconds.
+    ///
+    /// Returns how many times the zone ticked (more than once if `delta` is longer than the
+    /// tick interval).
+    pub fn tick(&mut self, delta: f32) -> u32 {
+        self.remaining -= delta;
+        if self.tick_interval <= 0.0 {
+            return 0;
+        }
+        self.until_tick -= delta;
+        let mut ticks = 0;
+        while self.until_tick <= 0.0 {
+            self.until_tick += self.tick_interval;
+            ticks += 1;
+        }
+        ticks
+    }
+
+    /// Returns true if the zone's lifetime has run out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
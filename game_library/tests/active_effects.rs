@@ -0,0 +1,195 @@
+use game_library::{
+    enums::{MagicType, StackingRule, StatEnum},
+    ActiveEffects, PeriodicEffect, StatBonus, StatBundle, StatEffect,
+};
+
+fn haste(max_stacks: u32, stacking: StackingRule) -> StatEffect {
+    StatEffect {
+        id: "haste".to_string(),
+        name: "Haste".to_string(),
+        duration: 200,
+        max_stacks,
+        stacking,
+        effects: vec![(StatEnum::MovementSpeed, StatBonus::new(1.5))],
+        ..StatEffect::default()
+    }
+}
+
+fn burn(max_stacks: u32) -> StatEffect {
+    StatEffect {
+        id: "burn".to_string(),
+        name: "Burn".to_string(),
+        duration: 300,
+        max_stacks,
+        periodic: Some(PeriodicEffect {
+            interval: 100,
+            amount: 2,
+            magic: MagicType::Fire,
+            heals: false,
+        }),
+        ..StatEffect::default()
+    }
+}
+
+fn movement_speed(stats: &StatBundle) -> f32 {
+    stats
+        .get_stat(&StatEnum::MovementSpeed)
+        .map_or(0.0, game_library::Stat::value)
+}
+
+#[test]
+fn apply_and_stack() {
+    let mut effects = ActiveEffects::default();
+    let effect = haste(2, StackingRule::Refresh);
+
+    assert_eq!(effects.apply(&effect), 1);
+    assert_eq!(effects.apply(&effect), 2);
+    // Max stacks is 2
+    assert_eq!(effects.apply(&effect), 2);
+    assert_eq!(effects.stacks("haste"), 2);
+    assert_eq!(effects.stacks("slow"), 0);
+}
+
+#[test]
+fn unlimited_stacks() {
+    let mut effects = ActiveEffects::default();
+    let effect = haste(0, StackingRule::Refresh);
+
+    for _ in 0..10 {
+        effects.apply(&effect);
+    }
+    assert_eq!(effects.stacks("haste"), 10);
+}
+
+#[test]
+fn refresh_duration() {
+    let mut effects = ActiveEffects::default();
+    let effect = haste(0, StackingRule::Refresh);
+
+    effects.apply(&effect);
+    effects.tick(1.5);
+    effects.apply(&effect);
+
+    let remaining = effects.get("haste").map_or(0.0, |e| e.remaining);
+    assert!((remaining - 2.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn extend_duration() {
+    let mut effects = ActiveEffects::default();
+    let effect = haste(0, StackingRule::Extend);
+
+    effects.apply(&effect);
+    effects.tick(1.5);
+    effects.apply(&effect);
+
+    let remaining = effects.get("haste").map_or(0.0, |e| e.remaining);
+    assert!((remaining - 2.5).abs() < f32::EPSILON);
+}
+
+#[test]
+fn expire() {
+    let mut effects = ActiveEffects::default();
+    effects.apply(&haste(0, StackingRule::Refresh));
+
+    assert!(effects.tick(1.0).is_empty());
+    let expired = effects.tick(1.0);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, "haste");
+    assert!(effects.is_empty());
+}
+
+#[test]
+fn sync_and_revert_stats() {
+    let mut stats = StatBundle::new(vec![(StatEnum::MovementSpeed, 2.0)]);
+    let mut effects = ActiveEffects::default();
+    let effect = haste(0, StackingRule::Refresh);
+
+    effects.apply(&effect);
+    assert!(effects.needs_sync());
+    effects.sync_stats(&mut stats);
+    assert!(!effects.needs_sync());
+    assert!((movement_speed(&stats) - 3.0).abs() < f32::EPSILON);
+
+    effects.apply(&effect);
+    effects.sync_stats(&mut stats);
+    assert!((movement_speed(&stats) - 4.0).abs() < f32::EPSILON);
+
+    effects.tick(2.0);
+    effects.sync_stats(&mut stats);
+    assert!((movement_speed(&stats) - 2.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn revert_clamped_stats() {
+    let mut stats = StatBundle::new(vec![(StatEnum::MovementSpeed, 2.0)]);
+    let mut effects = ActiveEffects::default();
+    let slow = StatEffect {
+        id: "slow".to_string(),
+        duration: 100,
+        effects: vec![(StatEnum::MovementSpeed, StatBonus::new(0.4))],
+        ..StatEffect::default()
+    };
+
+    // Two stacks would bring the bonus to -0.2, which is clamped to 0
+    effects.apply(&slow);
+    effects.apply(&slow);
+    effects.sync_stats(&mut stats);
+    assert!(movement_speed(&stats).abs() < f32::EPSILON);
+
+    effects.tick(1.0);
+    effects.sync_stats(&mut stats);
+    assert!((movement_speed(&stats) - 2.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn periodic_ticks_each_interval() {
+    let mut effects = ActiveEffects::default();
+    effects.apply(&burn(1));
+
+    assert!(effects.tick_periodic(0.5).is_empty());
+    let ticked = effects.tick_periodic(0.5);
+    assert_eq!(ticked.len(), 1);
+    assert_eq!(ticked[0].0.magic, MagicType::Fire);
+    assert_eq!(ticked[0].1, 2);
+
+    // a long frame ticks several times at once
+    assert_eq!(effects.tick_periodic(2.0)[0].1, 4);
+}
+
+#[test]
+fn periodic_scales_with_stacks() {
+    let mut effects = ActiveEffects::default();
+    let effect = burn(3);
+    effects.apply(&effect);
+    effects.apply(&effect);
+
+    assert_eq!(effects.tick_periodic(1.0)[0].1, 4);
+    assert_eq!(effects.tick_periodic(2.0)[0].1, 8);
+}
+
+#[test]
+fn periodic_stops_when_expired() {
+    let mut effects = ActiveEffects::default();
+    effects.apply(&burn(1));
+
+    // the effect lasts 3 seconds, so it ticks 3 times however long the frames are
+    let total: u32 = (0..10)
+        .flat_map(|_| {
+            let ticked = effects.tick_periodic(0.7);
+            effects.tick(0.7);
+            ticked
+        })
+        .map(|(_, amount)| amount)
+        .sum();
+    assert_eq!(total, 6);
+    assert!(effects.is_empty());
+}
+
+#[test]
+fn effects_without_periodic_never_tick() {
+    let mut effects = ActiveEffects::default();
+    effects.apply(&haste(1, StackingRule::Refresh));
+
+    assert!(effects.tick_periodic(5.0).is_empty());
+}
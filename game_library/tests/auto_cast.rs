@@ -0,0 +1,69 @@
+use bevy::{ecs::entity::Entity, math::Vec2};
+use game_library::{enums::AutoAimRule, AutoAimCandidate, SpellCooldowns};
+
+const fn candidate(id: u32, position: Vec2, health: f32) -> AutoAimCandidate {
+    AutoAimCandidate {
+        entity: Entity::from_raw(id),
+        position,
+        health,
+    }
+}
+
+#[test]
+fn cooldowns() {
+    let mut cooldowns = SpellCooldowns::default();
+    assert!(cooldowns.is_ready("fire_bolt"));
+
+    cooldowns.start("fire_bolt", 1.0);
+    // A spell without a cooldown is always ready
+    cooldowns.start("spark", 0.0);
+    assert!(!cooldowns.is_ready("fire_bolt"));
+    assert!(cooldowns.is_ready("spark"));
+
+    cooldowns.tick(0.75);
+    assert!((cooldowns.get("fire_bolt") - 0.25).abs() < f32::EPSILON);
+    assert!(!cooldowns.is_ready("fire_bolt"));
+
+    cooldowns.tick(0.25);
+    assert!(cooldowns.is_ready("fire_bolt"));
+    assert!(cooldowns.get("fire_bolt").abs() < f32::EPSILON);
+}
+
+#[test]
+fn auto_aim_nearest() {
+    let candidates = [
+        candidate(1, Vec2::new(50.0, 0.0), 0.1),
+        candidate(2, Vec2::new(0.0, 20.0), 1.0),
+        candidate(3, Vec2::new(500.0, 0.0), 1.0),
+    ];
+
+    let target = AutoAimRule::Nearest.pick(Vec2::ZERO, 100.0, candidates);
+    assert_eq!(target.map(|c| c.entity), Some(Entity::from_raw(2)));
+}
+
+#[test]
+fn auto_aim_lowest_health() {
+    let candidates = [
+        candidate(1, Vec2::new(50.0, 0.0), 0.1),
+        candidate(2, Vec2::new(0.0, 20.0), 1.0),
+        // Lowest health, but out of range
+        candidate(3, Vec2::new(500.0, 0.0), 0.05),
+    ];
+
+    let target = AutoAimRule::LowestHealth.pick(Vec2::ZERO, 100.0, candidates);
+    assert_eq!(target.map(|c| c.entity), Some(Entity::from_raw(1)));
+}
+
+#[test]
+fn auto_aim_ties_are_deterministic() {
+    let candidates = [
+        candidate(2, Vec2::new(10.0, 0.0), 0.5),
+        candidate(1, Vec2::new(-10.0, 0.0), 0.5),
+    ];
+
+    let target = AutoAimRule::LowestHealth.pick(Vec2::ZERO, 100.0, candidates);
+    assert_eq!(target.map(|c| c.entity), Some(Entity::from_raw(1)));
+    assert!(AutoAimRule::Nearest
+        .pick(Vec2::ZERO, 5.0, candidates)
+        .is_none());
+}
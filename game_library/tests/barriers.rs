@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use game_library::{
+    enums::{MagicType, SpellTarget},
+    Barrier, BarrierAction, BarrierData, Shield,
+};
+
+#[test]
+fn barrier_data_defaults() {
+    let barrier: BarrierData = serde_yaml::from_str("shield: 20\n").expect("valid barrier data");
+    assert_eq!(barrier.action, BarrierAction::Destroy);
+    assert_eq!(barrier.shield, 20);
+    assert!((barrier.reflected_damage - 1.0).abs() < f32::EPSILON);
+    // empty lists affect every spell
+    assert!(barrier.affects(MagicType::Fire, &SpellTarget::Enemy));
+    assert!(barrier.affects(MagicType::Arcane, &SpellTarget::Any));
+}
+
+#[test]
+fn barrier_affects_listed_magic_and_targets() {
+    let barrier: BarrierData =
+        serde_yaml::from_str("action: reflect\nmagic: [fire, ice]\ntargets: [player]\n")
+            .expect("valid barrier data");
+    assert_eq!(barrier.action, BarrierAction::Reflect);
+    assert!(barrier.affects(MagicType::Fire, &SpellTarget::Player));
+    assert!(barrier.affects(MagicType::Ice, &SpellTarget::Player));
+    assert!(!barrier.affects(MagicType::Water, &SpellTarget::Player));
+    assert!(!barrier.affects(MagicType::Fire, &SpellTarget::Ally));
+}
+
+#[test]
+fn shielding_barrier_expires() {
+    let data = BarrierData {
+        duration: 2.0,
+        ..Default::default()
+    };
+    let owner = Entity::from_raw(1);
+
+    let mut shield = Barrier::shielding(owner, "arcane-shield", data.clone());
+    shield.tick(1.5);
+    assert!(!shield.is_expired());
+    shield.tick(0.5);
+    assert!(shield.is_expired());
+
+    // barriers on structures last as long as the structure
+    let mut wall = Barrier::new(owner, "magic-mirror", data);
+    wall.tick(10.0);
+    assert!(!wall.is_expired());
+}
+
+#[test]
+fn shield_pool_absorbs_damage() {
+    let mut shield = Shield::new(10);
+    assert_eq!(shield.absorb(4), 4);
+    assert_eq!(shield.remaining, 6);
+    shield.add(2);
+    assert_eq!(shield.absorb(20), 8);
+    assert!(shield.is_empty());
+    assert_eq!(shield.absorb(5), 0);
+}
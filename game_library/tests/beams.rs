@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use game_library::{Beam, BeamData};
+
+#[test]
+fn beam_data_defaults() {
+    let beam: BeamData = serde_yaml::from_str("width: 8\n").expect("valid beam data");
+    assert!((beam.width - 8.0).abs() < f32::EPSILON);
+    assert!((beam.tick_interval - 0.25).abs() < f32::EPSILON);
+}
+
+#[test]
+fn beam_stops_at_blocker() {
+    let mut beam = Beam::new(&BeamData::default(), Vec2::new(0.0, 2.0), 100.0, false);
+    let origin = Vec2::new(10.0, 10.0);
+
+    beam.set_blocker(None);
+    assert_eq!(beam.target, None);
+    assert_eq!(beam.end(origin), Vec2::new(10.0, 110.0));
+
+    let wall = Entity::from_raw(4);
+    beam.set_blocker(Some((wall, 25.0)));
+    assert_eq!(beam.target, Some(wall));
+    assert_eq!(beam.end(origin), Vec2::new(10.0, 35.0));
+}
+
+#[test]
+fn beam_keeps_direction_without_aim() {
+    let mut beam = Beam::new(&BeamData::default(), Vec2::X, 50.0, true);
+    beam.aim(Vec2::ZERO);
+    assert_eq!(beam.direction, Vec2::X);
+    beam.aim(Vec2::new(0.0, -3.0));
+    assert_eq!(beam.direction, Vec2::NEG_Y);
+}
+
+#[test]
+fn beam_hits_every_tick() {
+    let data = BeamData {
+        tick_interval: 0.5,
+        ..Default::default()
+    };
+    let mut beam = Beam::new(&data, Vec2::X, 50.0, false);
+
+    // the beam hits as soon as it's fired
+    assert_eq!(beam.tick(0.1), 1);
+    assert_eq!(beam.tick(0.3), 0);
+    assert_eq!(beam.tick(0.1), 1);
+    // a long frame hits several times
+    assert_eq!(beam.tick(1.0), 2);
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use game_library::ChainData;
+
+fn chain() -> ChainData {
+    ChainData {
+        max_jumps: 3,
+        jump_radius: 10.0,
+        falloff: 0.5,
+    }
+}
+
+#[test]
+fn chain_data_defaults() {
+    let chain: ChainData = serde_yaml::from_str("maxJumps: 4\n").expect("valid chain data");
+    assert_eq!(chain.max_jumps, 4);
+    assert!((chain.jump_radius - 64.0).abs() < f32::EPSILON);
+    assert!((chain.falloff - 0.7).abs() < f32::EPSILON);
+}
+
+#[test]
+fn chain_damage_falls_off_per_jump() {
+    let chain = chain();
+    assert!((chain.jump_damage(8.0, 0) - 8.0).abs() < f32::EPSILON);
+    assert!((chain.jump_damage(8.0, 1) - 4.0).abs() < f32::EPSILON);
+    assert!((chain.jump_damage(8.0, 3) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn chain_picks_closest_new_target_in_radius() {
+    let chain = chain();
+    let (a, b, c) = (
+        Entity::from_raw(1),
+        Entity::from_raw(2),
+        Entity::from_raw(3),
+    );
+    let candidates = [
+        (a, Vec2::new(2.0, 0.0)),
+        (b, Vec2::new(6.0, 0.0)),
+        (c, Vec2::new(20.0, 0.0)),
+    ];
+
+    assert_eq!(
+        chain.next_target(Vec2::ZERO, &candidates, &[]),
+        Some((a, Vec2::new(2.0, 0.0)))
+    );
+    assert_eq!(
+        chain.next_target(Vec2::ZERO, &candidates, &[a]),
+        Some((b, Vec2::new(6.0, 0.0)))
+    );
+    // out of range
+    assert_eq!(chain.next_target(Vec2::ZERO, &candidates, &[a, b]), None);
+}
+
+#[test]
+fn chain_links_never_repeat_targets() {
+    let chain = chain();
+    let first = Entity::from_raw(1);
+    let targets = [
+        (first, Vec2::ZERO),
+        (Entity::from_raw(2), Vec2::new(8.0, 0.0)),
+        (Entity::from_raw(3), Vec2::new(16.0, 0.0)),
+    ];
+
+    // every target is a candidate everywhere; only the radius and the targets already hit
+    // limit the jumps
+    let links = chain.links(targets[0], |_| targets.to_vec());
+    assert_eq!(links, vec![targets[1], targets[2]]);
+}
+
+#[test]
+fn chain_stops_after_max_jumps() {
+    let chain = ChainData {
+        max_jumps: 2,
+        ..chain()
+    };
+    let targets: Vec<(Entity, Vec2)> = [0.0, 5.0, 10.0, 15.0, 20.0]
+        .into_iter()
+        .zip(0..)
+        .map(|(x, id)| (Entity::from_raw(id), Vec2::new(x, 0.0)))
+        .collect();
+
+    let links = chain.links(targets[0], |_| targets.clone());
+    assert_eq!(links, vec![targets[1], targets[2]]);
+}
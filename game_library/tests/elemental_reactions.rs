@@ -0,0 +1,102 @@
+use bevy::ecs::entity::Entity;
+use game_library::{
+    data_loader::storage::Vault, enums::MagicType, ElementalReaction, ElementalStatus,
+    ReactionMultipliers,
+};
+
+fn reactions() -> Vault<ElementalReaction> {
+    let mut reactions = Vault::default();
+    reactions.add(
+        "electrocute".to_string(),
+        ElementalReaction {
+            elements: (MagicType::Water, MagicType::Lightning),
+            damage_multiplier: 1.5,
+            ..ElementalReaction::default()
+        },
+    );
+    reactions.add(
+        "steam".to_string(),
+        ElementalReaction {
+            elements: (MagicType::Ice, MagicType::Fire),
+            consumes_element: false,
+            ..ElementalReaction::default()
+        },
+    );
+    reactions
+}
+
+#[test]
+fn reactions_work_in_either_order() {
+    let reactions = reactions();
+
+    assert!(reactions
+        .between(MagicType::Lightning, MagicType::Water)
+        .is_some_and(|(id, _)| id == "electrocute"));
+    assert!(reactions
+        .between(MagicType::Water, MagicType::Lightning)
+        .is_some_and(|(id, _)| id == "electrocute"));
+    assert!(reactions
+        .between(MagicType::Water, MagicType::Fire)
+        .is_none());
+}
+
+#[test]
+fn hits_trigger_reactions_with_lingering_elements() {
+    let reactions = reactions();
+    let mut status = ElementalStatus::default();
+
+    // the first hit soaks the target
+    assert!(status.hit(MagicType::Water, 4.0, &reactions).is_none());
+    assert!(status.has(MagicType::Water));
+    // the same element doesn't react with itself
+    assert!(status.hit(MagicType::Water, 4.0, &reactions).is_none());
+
+    let (lingering, reaction_id, reaction) = status
+        .hit(MagicType::Lightning, 4.0, &reactions)
+        .expect("electrocute");
+    assert_eq!(lingering, MagicType::Water);
+    assert_eq!(reaction_id, "electrocute");
+    assert!((reaction.damage_multiplier - 1.5).abs() < f32::EPSILON);
+    // the reaction used up the water (and the lightning doesn't linger)
+    assert!(status.is_empty());
+
+    // reactions which don't consume the element leave it lingering
+    status.hit(MagicType::Ice, 4.0, &reactions);
+    assert!(status.hit(MagicType::Fire, 4.0, &reactions).is_some());
+    assert!(status.has(MagicType::Ice));
+}
+
+#[test]
+fn lingering_elements_expire() {
+    let mut status = ElementalStatus::default();
+    status.apply(MagicType::Water, 1.0);
+    status.apply(MagicType::Fire, 3.0);
+    status.apply(MagicType::Water, 0.5);
+
+    assert!(status.tick(0.75).is_empty());
+    assert_eq!(status.tick(0.5), [MagicType::Water]);
+    assert!(status.has(MagicType::Fire));
+    assert_eq!(status.tick(2.0), [MagicType::Fire]);
+    assert!(status.is_empty());
+}
+
+#[test]
+fn reactions_multiply_the_damage_of_their_hit() {
+    let reactions = reactions();
+    let (_, electrocute) = reactions
+        .between(MagicType::Water, MagicType::Lightning)
+        .expect("electrocute");
+    let spell = Entity::from_raw(1);
+    let other_spell = Entity::from_raw(2);
+    let target = Entity::from_raw(3);
+
+    let mut multipliers = ReactionMultipliers::default();
+    multipliers.add(spell, target, electrocute.damage_multiplier);
+
+    // other spells hitting the target aren't scaled
+    assert_eq!(multipliers.damage(other_spell, target, 10.0), 10);
+    // the hit which caused the reaction is
+    assert_eq!(multipliers.damage(spell, target, 10.0), 15);
+    // but only once
+    assert_eq!(multipliers.damage(spell, target, 10.0), 10);
+}
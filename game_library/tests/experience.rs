@@ -155,3 +155,18 @@ fn arbitrary_level_xp_requirements() {
     assert_eq!(xp.xp_required(50), 490);
     assert_eq!(xp.xp_required(51), 510);
 }
+
+#[test]
+fn level_up_pending() {
+    let mut xp = Xp::default();
+
+    assert_eq!(xp.level_up_pending(), 0);
+
+    // 10 xp to level 2, 10 xp to level 3, with 5 left over
+    xp += 25;
+
+    assert_eq!(xp.level_up_pending(), 2);
+    assert_eq!(xp.current_level, 3);
+    assert_eq!(xp.value, 5);
+    assert_eq!(xp.total_xp, 25);
+}
@@ -0,0 +1,76 @@
+use bevy_rapier2d::prelude::Group;
+use game_library::{
+    collision_groups,
+    enums::{Faction, SpellTarget},
+};
+
+#[test]
+fn factions_are_hostile_to_the_other_side() {
+    assert!(Faction::Player.is_hostile_to(Faction::Enemy));
+    assert!(Faction::Enemy.is_hostile_to(Faction::Ally));
+    assert!(!Faction::Player.is_hostile_to(Faction::Ally));
+    assert!(!Faction::Enemy.is_hostile_to(Faction::Enemy));
+}
+
+#[test]
+fn targets_are_relative_to_the_caster() {
+    // the player's side
+    assert!(Faction::Player.can_affect(Faction::Enemy, &SpellTarget::Enemy));
+    assert!(!Faction::Ally.can_affect(Faction::Player, &SpellTarget::Enemy));
+    assert!(Faction::Ally.can_affect(Faction::Player, &SpellTarget::Ally));
+
+    // enemies' spells hit the player's side, and help other enemies
+    assert!(Faction::Enemy.can_affect(Faction::Player, &SpellTarget::Enemy));
+    assert!(Faction::Enemy.can_affect(Faction::Ally, &SpellTarget::Enemy));
+    assert!(!Faction::Enemy.can_affect(Faction::Enemy, &SpellTarget::Enemy));
+    assert!(Faction::Enemy.can_affect(Faction::Enemy, &SpellTarget::Ally));
+
+    // `player` is the player, whoever casts it
+    assert!(Faction::Enemy.can_affect(Faction::Player, &SpellTarget::Player));
+    assert!(!Faction::Player.can_affect(Faction::Ally, &SpellTarget::Player));
+
+    assert!(Faction::Enemy.can_affect(Faction::Enemy, &SpellTarget::Any));
+    assert!(!Faction::Player.can_affect(Faction::Player, &SpellTarget::None));
+}
+
+#[test]
+fn spells_only_sense_sides_they_can_affect() {
+    let fireball = collision_groups::spell(Faction::Player, &SpellTarget::Enemy);
+    assert_eq!(fireball.memberships, collision_groups::SPELLS);
+    assert!(fireball.filters.contains(collision_groups::HOSTILE));
+    assert!(!fireball.filters.contains(collision_groups::FRIENDLY));
+    assert!(!fireball.filters.contains(collision_groups::SPELLS));
+    // obstacles and structures (in the other groups) are still sensed
+    assert!(fireball.filters.contains(Group::GROUP_4));
+
+    let enemy_fireball = collision_groups::spell(Faction::Enemy, &SpellTarget::Enemy);
+    assert!(enemy_fireball.filters.contains(collision_groups::FRIENDLY));
+    assert!(!enemy_fireball.filters.contains(collision_groups::HOSTILE));
+
+    let heal = collision_groups::spell(Faction::Ally, &SpellTarget::Player);
+    assert!(heal.filters.contains(collision_groups::FRIENDLY));
+    assert!(!heal.filters.contains(collision_groups::HOSTILE));
+
+    let nothing = collision_groups::spell(Faction::Player, &SpellTarget::None);
+    assert!(!nothing
+        .filters
+        .intersects(collision_groups::FRIENDLY | collision_groups::HOSTILE));
+}
+
+#[test]
+fn characters_are_in_their_sides_group() {
+    let player = collision_groups::character(Faction::Player);
+    let enemy = collision_groups::character(Faction::Enemy);
+    assert_eq!(player.memberships, collision_groups::FRIENDLY);
+    assert_eq!(enemy.memberships, collision_groups::HOSTILE);
+    assert_eq!(player.filters, Group::ALL);
+
+    // the player's side doesn't push each other around, but enemies do
+    let ally = collision_groups::character_solver(Faction::Ally);
+    assert!(!ally.filters.contains(collision_groups::FRIENDLY));
+    assert!(ally.filters.contains(collision_groups::HOSTILE));
+    assert_eq!(
+        collision_groups::character_solver(Faction::Enemy).filters,
+        Group::ALL
+    );
+}
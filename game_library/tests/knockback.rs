@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use game_library::{Knockback, KnockbackData, KnockbackDirection};
+
+fn push(force: f32) -> KnockbackData {
+    KnockbackData {
+        force,
+        direction: KnockbackDirection::Away,
+        decay: 8.0,
+    }
+}
+
+#[test]
+fn knockback_data_defaults() {
+    let knockback: KnockbackData = serde_yaml::from_str("force: 32\n").expect("valid knockback");
+    assert!((knockback.force - 32.0).abs() < f32::EPSILON);
+    assert_eq!(knockback.direction, KnockbackDirection::Away);
+    assert!((knockback.decay - 8.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn stun_resistance_reduces_force() {
+    let knockback = push(40.0);
+    assert!((knockback.mitigated_force(0.0) - 40.0).abs() < f32::EPSILON);
+    assert!((knockback.mitigated_force(0.25) - 30.0).abs() < f32::EPSILON);
+    assert!(knockback.mitigated_force(1.5).abs() < f32::EPSILON);
+    // negative resistance doesn't make the knockback stronger
+    assert!((knockback.mitigated_force(-1.0) - 40.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn knockback_pushes_away_or_pulls_toward() {
+    let away = push(10.0);
+    let toward = KnockbackData {
+        direction: KnockbackDirection::Toward,
+        ..away
+    };
+    let origin = Vec2::new(1.0, 1.0);
+    let position = Vec2::new(1.0, 5.0);
+
+    assert_eq!(away.direction_from(origin, position), Vec2::Y);
+    assert_eq!(toward.direction_from(origin, position), Vec2::NEG_Y);
+    assert_eq!(away.direction_from(origin, origin), Vec2::ZERO);
+}
+
+#[test]
+fn knockback_moves_its_force_in_total() {
+    let data = push(48.0);
+    let mut knockback = Knockback::new(data.velocity(Vec2::X, data.force), data.decay);
+
+    let mut moved = Vec2::ZERO;
+    let mut frames = 0;
+    while !knockback.is_finished() {
+        moved += knockback.step(1.0 / 60.0);
+        frames += 1;
+        assert!(frames < 600, "knockback never stopped");
+    }
+
+    // the knockback stops just short of its full distance (below the minimum speed)
+    assert!(moved.y.abs() < f32::EPSILON);
+    assert!(moved.x < 48.01);
+    assert!(48.0 - moved.x < Knockback::MIN_SPEED / data.decay + 0.01);
+}
+
+#[test]
+fn knockback_slows_down() {
+    let data = push(16.0);
+    let mut knockback = Knockback::new(data.velocity(Vec2::Y, data.force), data.decay);
+
+    let first = knockback.step(0.1).length();
+    let second = knockback.step(0.1).length();
+    assert!(second < first);
+}
+
+#[test]
+fn knockbacks_add_up() {
+    let mut knockback = Knockback::new(Vec2::new(10.0, 0.0), 8.0);
+    knockback.add(Knockback::new(Vec2::new(0.0, 20.0), 4.0));
+
+    assert_eq!(knockback.velocity, Vec2::new(10.0, 20.0));
+    // the stronger knockback decides how quickly it slows down
+    assert!((knockback.decay - 4.0).abs() < f32::EPSILON);
+}
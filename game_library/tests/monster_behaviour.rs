@@ -0,0 +1,202 @@
+use bevy::math::Vec2;
+use game_library::{
+    BehaviourData, BehaviourState, CombatBehaviour, IdleBehaviour, MonsterBrain, Senses,
+};
+
+const DELTA: f32 = 0.1;
+const SPEED: f32 = 20.0;
+
+fn senses(position: Vec2, target: Option<Vec2>) -> Senses {
+    Senses {
+        position,
+        health: 1.0,
+        target,
+    }
+}
+
+/// Lets the monster wander on its own for `steps` ticks, returning where it went.
+fn wander(brain: &mut MonsterBrain, data: &BehaviourData, steps: usize) -> Vec<Vec2> {
+    let mut position = brain.home;
+    (0..steps)
+        .map(|_| {
+            let action = brain.tick(data, &senses(position, None), DELTA);
+            position += action.movement * SPEED * DELTA;
+            position
+        })
+        .collect()
+}
+
+#[test]
+fn behaviour_data_defaults() {
+    let data: BehaviourData = serde_yaml::from_str("combat: keepDistance\n").expect("valid");
+    assert_eq!(data.idle, IdleBehaviour::Wander);
+    assert_eq!(data.combat, CombatBehaviour::KeepDistance);
+    assert!((data.aggro_radius - 128.0).abs() < f32::EPSILON);
+    assert!(data.flee_below.abs() < f32::EPSILON);
+    assert!(data.patrol.is_empty());
+}
+
+#[test]
+fn monsters_wander_near_home() {
+    let data = BehaviourData::default();
+    let mut brain = MonsterBrain::new(Vec2::new(100.0, -50.0), 11);
+    let path = wander(&mut brain, &data, 600);
+
+    assert!(path.iter().any(|position| *position != brain.home));
+    for position in path {
+        assert!(position.distance(brain.home) <= data.wander_radius + SPEED * DELTA);
+    }
+}
+
+#[test]
+fn wandering_is_deterministic() {
+    let data = BehaviourData::default();
+    let path = |seed| wander(&mut MonsterBrain::new(Vec2::ZERO, seed), &data, 300);
+    assert_eq!(path(5), path(5));
+    assert_ne!(path(5), path(6));
+}
+
+#[test]
+fn standing_monsters_stay_put() {
+    let data = BehaviourData {
+        idle: IdleBehaviour::Stand,
+        ..BehaviourData::default()
+    };
+    let mut brain = MonsterBrain::new(Vec2::ZERO, 1);
+    assert!(wander(&mut brain, &data, 100)
+        .iter()
+        .all(|position| *position == Vec2::ZERO));
+}
+
+#[test]
+fn monsters_chase_targets_in_aggro_radius() {
+    let data = BehaviourData::default();
+    let mut brain = MonsterBrain::new(Vec2::ZERO, 1);
+
+    // too far away to notice
+    let far = Vec2::new(200.0, 0.0);
+    let action = brain.tick(&data, &senses(Vec2::ZERO, Some(far)), DELTA);
+    assert_ne!(brain.state, BehaviourState::Fighting);
+    assert_eq!(action.attack, None);
+
+    // noticed, but out of range to attack
+    let near = Vec2::new(100.0, 0.0);
+    let action = brain.tick(&data, &senses(Vec2::ZERO, Some(near)), DELTA);
+    assert_eq!(brain.state, BehaviourState::Fighting);
+    assert_eq!(action.movement, Vec2::X);
+    assert_eq!(action.attack, None);
+
+    // in range, so it stops and attacks
+    let close = Vec2::new(50.0, 0.0);
+    let action = brain.tick(&data, &senses(Vec2::ZERO, Some(close)), DELTA);
+    assert_eq!(action.movement, Vec2::ZERO);
+    assert_eq!(action.attack, Some(close));
+}
+
+#[test]
+fn monsters_lose_interest_past_their_leash() {
+    let data = BehaviourData::default();
+    let mut brain = MonsterBrain::new(Vec2::ZERO, 1);
+    brain.tick(
+        &data,
+        &senses(Vec2::ZERO, Some(Vec2::new(100.0, 0.0))),
+        DELTA,
+    );
+
+    // further than the aggro radius, but still within the leash
+    brain.tick(
+        &data,
+        &senses(Vec2::ZERO, Some(Vec2::new(180.0, 0.0))),
+        DELTA,
+    );
+    assert_eq!(brain.state, BehaviourState::Fighting);
+
+    // back to wandering about
+    brain.tick(
+        &data,
+        &senses(Vec2::ZERO, Some(Vec2::new(200.0, 0.0))),
+        DELTA,
+    );
+    assert!(matches!(
+        brain.state,
+        BehaviourState::Idle { .. } | BehaviourState::Wandering { .. }
+    ));
+}
+
+#[test]
+fn casters_keep_their_distance() {
+    let data = BehaviourData {
+        combat: CombatBehaviour::KeepDistance,
+        ..BehaviourData::default()
+    };
+    let mut brain = MonsterBrain::new(Vec2::ZERO, 1);
+
+    let too_close = Vec2::new(0.0, 20.0);
+    let action = brain.tick(&data, &senses(Vec2::ZERO, Some(too_close)), DELTA);
+    assert_eq!(action.movement, Vec2::NEG_Y);
+    assert_eq!(action.attack, Some(too_close));
+
+    let just_right = Vec2::new(0.0, 70.0);
+    let action = brain.tick(&data, &senses(Vec2::ZERO, Some(just_right)), DELTA);
+    assert_eq!(action.movement, Vec2::ZERO);
+    assert_eq!(action.attack, Some(just_right));
+}
+
+#[test]
+fn monsters_flee_at_low_health() {
+    let data = BehaviourData {
+        flee_below: 0.25,
+        ..BehaviourData::default()
+    };
+    let mut brain = MonsterBrain::new(Vec2::ZERO, 1);
+    let target = Vec2::new(30.0, 0.0);
+
+    let hurt = Senses {
+        health: 0.2,
+        ..senses(Vec2::ZERO, Some(target))
+    };
+    let action = brain.tick(&data, &hurt, DELTA);
+    assert_eq!(brain.state, BehaviourState::Fleeing);
+    assert_eq!(action.movement, Vec2::NEG_X);
+    assert_eq!(action.attack, None);
+
+    // still healthy enough to fight
+    let healthy = Senses {
+        health: 0.5,
+        ..hurt
+    };
+    brain.tick(&data, &healthy, DELTA);
+    assert_eq!(brain.state, BehaviourState::Fighting);
+}
+
+#[test]
+fn monsters_patrol_their_route() {
+    let data = BehaviourData {
+        idle: IdleBehaviour::Patrol,
+        patrol: vec![(10.0, 0.0), (10.0, 10.0)],
+        ..BehaviourData::default()
+    };
+    let home = Vec2::new(5.0, 5.0);
+    let mut brain = MonsterBrain::new(home, 1);
+
+    let action = brain.tick(&data, &senses(home, None), DELTA);
+    assert_eq!(brain.state, BehaviourState::Patrolling);
+    assert_eq!(action.movement, Vec2::X);
+
+    // arriving at a waypoint heads for the next one
+    let action = brain.tick(&data, &senses(home + Vec2::new(10.0, 0.0), None), DELTA);
+    assert_eq!(brain.waypoint, 1);
+    assert_eq!(action.movement, Vec2::Y);
+
+    // fighting doesn't lose its place on the route
+    let target = Some(home + Vec2::new(10.0, 50.0));
+    brain.tick(&data, &senses(home + Vec2::new(10.0, 0.0), target), DELTA);
+    assert_eq!(brain.state, BehaviourState::Fighting);
+    brain.tick(&data, &senses(home + Vec2::new(10.0, 0.0), None), DELTA);
+    assert_eq!(brain.state, BehaviourState::Patrolling);
+    assert_eq!(brain.waypoint, 1);
+
+    // and the route loops back around
+    brain.tick(&data, &senses(home + Vec2::new(10.0, 10.0), None), DELTA);
+    assert_eq!(brain.waypoint, 0);
+}
@@ -0,0 +1,128 @@
+use game_library::{
+    data_loader::{read_data_file, DataFile},
+    enums::{Faction, MagicType},
+    AreaShape, BiomeData, CollectedEssence, EssenceDrop, MonsterData, MonsterDetail, Realm,
+    MONSTER_POOL,
+};
+use rand::SeedableRng;
+
+fn spawn(id: &str, weight: usize) -> MonsterDetail {
+    MonsterDetail {
+        id: id.to_string(),
+        weight,
+    }
+}
+
+fn rng(seed: u64) -> rand::rngs::SmallRng {
+    rand::rngs::SmallRng::seed_from_u64(seed)
+}
+
+#[test]
+fn monster_data_defaults() {
+    let monster: MonsterData =
+        serde_yaml::from_str("name: Slime\ndescription: It wobbles.\n").expect("valid monster");
+    assert_eq!(monster.health, 10);
+    assert_eq!(monster.faction, Faction::Enemy);
+    assert_eq!(monster.collider, AreaShape::Circle { radius: 6.0 });
+    assert!(monster.spells.is_empty());
+    assert!(monster.essence.is_empty());
+}
+
+#[test]
+fn biomes_spawn_monsters_by_weight() {
+    let biome = BiomeData {
+        monsters: vec![spawn("never", 0), spawn("always", MONSTER_POOL)],
+        ..BiomeData::barren()
+    };
+    let mut rng = rng(7);
+    for _ in 0..100 {
+        assert_eq!(biome.random_monster(&[], &mut rng), Some("always"));
+    }
+
+    let empty = BiomeData::barren();
+    assert_eq!(empty.random_monster(&[], &mut rng), None);
+}
+
+#[test]
+fn realm_monsters_spawn_in_every_biome() {
+    let realm = Realm {
+        monsters: vec![spawn("realm", 100)],
+        ..Realm::default()
+    };
+    let biome = BiomeData {
+        monsters: vec![spawn("biome", 100)],
+        ..BiomeData::barren()
+    };
+
+    let mut rng = rng(42);
+    let rolls: Vec<Option<&str>> = (0..2000)
+        .map(|_| biome.random_monster(&realm.monsters, &mut rng))
+        .collect();
+    let count = |id: &str| rolls.iter().filter(|roll| **roll == Some(id)).count();
+    // each has a 1 in 10 chance, and most tiles spawn nothing
+    assert!((100..300).contains(&count("realm")));
+    assert!((100..300).contains(&count("biome")));
+    assert!(count("realm") + count("biome") < 1000);
+}
+
+#[test]
+fn monster_spawns_are_seeded() {
+    let biome = BiomeData {
+        monsters: vec![spawn("imp", 50), spawn("golem", 20)],
+        ..BiomeData::barren()
+    };
+    let roll = |seed| {
+        let mut rng = rng(seed);
+        (0..500)
+            .map(|_| biome.random_monster(&[], &mut rng).map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(roll(3), roll(3));
+    assert_ne!(roll(3), roll(4));
+}
+
+#[test]
+fn essence_drops_roll_their_chance() {
+    let monster = MonsterData {
+        essence: vec![
+            EssenceDrop {
+                magic: MagicType::Fire,
+                amount: 3,
+                chance: 1.0,
+            },
+            EssenceDrop {
+                magic: MagicType::Water,
+                amount: 5,
+                chance: 0.0,
+            },
+        ],
+        ..MonsterData::default()
+    };
+    let mut rng = rng(1);
+    assert_eq!(monster.roll_essence(&mut rng), vec![(MagicType::Fire, 3)]);
+}
+
+#[test]
+fn collected_essence_adds_up() {
+    let mut collected = CollectedEssence::default();
+    collected.add(MagicType::Fire, 2);
+    collected.add(MagicType::Fire, 3);
+    collected.add(MagicType::Earth, 4);
+
+    assert_eq!(collected.get(MagicType::Fire), 5);
+    assert_eq!(collected.get(MagicType::Water), 0);
+    assert_eq!(collected.total(), 9);
+
+    collected.clear();
+    assert_eq!(collected.total(), 0);
+}
+
+#[test]
+fn all_monster_files_parse() {
+    for entry in std::fs::read_dir("../game_data/monsters").expect("no monsters directory") {
+        let path = entry.expect("bad directory entry").path();
+        let data_file: Option<DataFile<MonsterData>> =
+            read_data_file(path.to_string_lossy().as_ref());
+        assert!(data_file.is_some(), "failed to parse {path:?}");
+    }
+}
@@ -0,0 +1,113 @@
+use game_library::{
+    data_loader::storage::Vault,
+    enums::{Skill, StatEnum},
+    Perk, PerkError, PerkState, Skills, StatBonus, StatBundle,
+};
+
+fn perks() -> Vault<Perk> {
+    let mut perks = Vault::default();
+    perks.add(
+        "kindling".to_string(),
+        Perk {
+            skill: Skill::Pyromancy,
+            effects: vec![(StatEnum::MovementSpeed, StatBonus::new(1.1))],
+            ..Perk::default()
+        },
+    );
+    perks.add(
+        "wildfire".to_string(),
+        Perk {
+            skill: Skill::Pyromancy,
+            skill_level: 3,
+            prerequisites: vec!["kindling".to_string()],
+            cost: 2,
+            effects: vec![(StatEnum::MovementSpeed, StatBonus::new(1.2))],
+            ..Perk::default()
+        },
+    );
+    perks
+}
+
+fn skills_at_level(skill: Skill, level: u32) -> Skills {
+    let mut skills = Skills::default();
+    if let Some(xp) = skills.tracks.get_mut(&skill) {
+        xp.current_level = level;
+    }
+    skills
+}
+
+#[test]
+fn allocations_are_validated() {
+    let perks = perks();
+    let mut state = PerkState::default();
+
+    assert_eq!(
+        state.allocate("kindling", &perks, &Skills::default()),
+        Err(PerkError::NotEnoughPoints {
+            skill: Skill::Pyromancy,
+            cost: 1,
+            available: 0,
+        })
+    );
+
+    let skills = skills_at_level(Skill::Pyromancy, 3);
+    assert_eq!(
+        state.allocate("wildfire", &perks, &skills),
+        Err(PerkError::MissingPrerequisite("kindling".to_string()))
+    );
+    assert!(state.allocate("kindling", &perks, &skills).is_ok());
+    assert_eq!(
+        state.allocate("kindling", &perks, &skills),
+        Err(PerkError::AlreadyAllocated("kindling".to_string()))
+    );
+    assert_eq!(state.points_available(Skill::Pyromancy, &skills, &perks), 1);
+    assert_eq!(
+        state.allocate("wildfire", &perks, &skills),
+        Err(PerkError::NotEnoughPoints {
+            skill: Skill::Pyromancy,
+            cost: 2,
+            available: 1,
+        })
+    );
+    assert_eq!(
+        state.allocate("inferno", &perks, &skills),
+        Err(PerkError::UnknownPerk("inferno".to_string()))
+    );
+
+    let skills = skills_at_level(Skill::Pyromancy, 2);
+    let mut state = PerkState::default();
+    assert!(state.allocate("kindling", &perks, &skills).is_ok());
+    assert_eq!(
+        state.check("wildfire", &perks, &skills),
+        Err(PerkError::SkillTooLow {
+            skill: Skill::Pyromancy,
+            required: 3,
+            current: 2,
+        })
+    );
+}
+
+#[test]
+fn perks_apply_to_stats() {
+    let perks = perks();
+    let skills = skills_at_level(Skill::Pyromancy, 4);
+    let mut state = PerkState::default();
+    let mut stats = StatBundle::new(vec![(StatEnum::MovementSpeed, 10.0)]);
+
+    assert!(state.allocate("kindling", &perks, &skills).is_ok());
+    assert!(state.allocate("wildfire", &perks, &skills).is_ok());
+    state.sync_stats(&mut stats, &perks);
+
+    let speed = stats.get_stat(&StatEnum::MovementSpeed).expect("speed");
+    assert!((speed.bonus() - 1.3).abs() < 0.0001);
+
+    // syncing again doesn't apply the perks twice
+    state.sync_stats(&mut stats, &perks);
+    let speed = stats.get_stat(&StatEnum::MovementSpeed).expect("speed");
+    assert!((speed.bonus() - 1.3).abs() < 0.0001);
+
+    assert_eq!(state.reset(), ["kindling", "wildfire"]);
+    state.sync_stats(&mut stats, &perks);
+    let speed = stats.get_stat(&StatEnum::MovementSpeed).expect("speed");
+    assert!((speed.bonus() - 1.0).abs() < 0.0001);
+}
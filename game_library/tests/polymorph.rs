@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use game_library::{enums::StatEnum, PolymorphData, Polymorphed, StatBundle};
+
+fn ogre() -> PolymorphData {
+    PolymorphData {
+        sprite_tileset: "ogre".to_string(),
+        duration: 2.0,
+        stat_overrides: vec![(StatEnum::MovementSpeed, 1.5)],
+        stat_multipliers: vec![(StatEnum::MagicDamage, 2.0)],
+        allowed_spells: vec!["smash".to_string()],
+        ..PolymorphData::default()
+    }
+}
+
+fn base_value(stats: &StatBundle, stat: &StatEnum) -> f32 {
+    stats.get_stat(stat).map_or(0.0, |stat| stat.base_value())
+}
+
+#[test]
+fn polymorph_changes_and_reverts_stats() {
+    let mut stats = StatBundle::new(vec![
+        (StatEnum::MovementSpeed, 2.4),
+        (StatEnum::MagicDamage, 3.0),
+    ]);
+    let mut polymorphed = Polymorphed::new("polymorph-ogre", ogre(), Handle::default(), Vec3::ONE);
+
+    polymorphed.apply_stats(&mut stats);
+    assert!((base_value(&stats, &StatEnum::MovementSpeed) - 1.5).abs() < f32::EPSILON);
+    assert!((base_value(&stats, &StatEnum::MagicDamage) - 6.0).abs() < f32::EPSILON);
+
+    // applying again doesn't stack
+    polymorphed.apply_stats(&mut stats);
+    assert!((base_value(&stats, &StatEnum::MagicDamage) - 6.0).abs() < f32::EPSILON);
+
+    polymorphed.revert_stats(&mut stats);
+    assert!((base_value(&stats, &StatEnum::MovementSpeed) - 2.4).abs() < f32::EPSILON);
+    assert!((base_value(&stats, &StatEnum::MagicDamage) - 3.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn polymorph_keeps_bonuses_separate() {
+    let mut stats = StatBundle::new(vec![(StatEnum::MovementSpeed, 2.0)]);
+    let mut polymorphed = Polymorphed::new("polymorph-ogre", ogre(), Handle::default(), Vec3::ONE);
+
+    polymorphed.apply_stats(&mut stats);
+    // e.g. a buff applied while transformed
+    stats.update_stat_bonus(StatEnum::MovementSpeed, 2.0);
+    polymorphed.revert_stats(&mut stats);
+
+    let speed = stats.get_stat(&StatEnum::MovementSpeed).expect("speed");
+    assert!((speed.base_value() - 2.0).abs() < f32::EPSILON);
+    assert!((speed.value() - 4.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn polymorph_restricts_spells_and_expires() {
+    let mut polymorphed = Polymorphed::new("polymorph-ogre", ogre(), Handle::default(), Vec3::ONE);
+    assert!(polymorphed.allows_spell("smash"));
+    assert!(!polymorphed.allows_spell("firebolt"));
+    assert!(PolymorphData::default().allows_spell("firebolt"));
+
+    polymorphed.tick(1.5);
+    assert!(!polymorphed.is_expired());
+    polymorphed.tick(0.5);
+    assert!(polymorphed.is_expired());
+}
+
+#[test]
+fn changing_form_reverts_the_old_form() {
+    let mut stats = StatBundle::new(vec![(StatEnum::MagicDamage, 3.0)]);
+    let mut polymorphed = Polymorphed::new("polymorph-ogre", ogre(), Handle::default(), Vec3::ONE);
+    polymorphed.apply_stats(&mut stats);
+
+    let mouse = PolymorphData {
+        stat_multipliers: vec![(StatEnum::MagicDamage, 0.5)],
+        duration: 5.0,
+        ..PolymorphData::default()
+    };
+    polymorphed.transform("polymorph-mouse", mouse, &mut stats);
+    assert!((base_value(&stats, &StatEnum::MagicDamage) - 1.5).abs() < f32::EPSILON);
+    assert!((polymorphed.remaining - 5.0).abs() < f32::EPSILON);
+    assert!(polymorphed.allows_spell("firebolt"));
+
+    polymorphed.revert_stats(&mut stats);
+    assert!((base_value(&stats, &StatEnum::MagicDamage) - 3.0).abs() < f32::EPSILON);
+}
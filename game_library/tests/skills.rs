@@ -0,0 +1,53 @@
+use bevy::ecs::entity::Entity;
+use game_library::{
+    enums::{MagicType, Skill},
+    Skills, SpellData,
+};
+
+#[test]
+fn every_skill_has_a_track() {
+    let skills = Skills::default();
+
+    for skill in Skill::variants() {
+        assert_eq!(skills.get_level(skill), Some(1));
+    }
+}
+
+#[test]
+fn level_up_pending() {
+    let mut skills = Skills::default();
+
+    skills.add_xp(Skill::Pyromancy, 25);
+    skills.add_xp(Skill::Chronomancy, 10);
+    skills.add_xp(Skill::Fulgomancy, 5);
+
+    assert_eq!(
+        skills.level_up_pending(),
+        [
+            (Skill::Pyromancy, 2),
+            (Skill::Pyromancy, 3),
+            (Skill::Chronomancy, 2)
+        ]
+    );
+    assert_eq!(skills.get_level(Skill::Pyromancy), Some(3));
+    assert_eq!(skills.get_level(Skill::Fulgomancy), Some(1));
+    assert!(skills.level_up_pending().is_empty());
+}
+
+#[test]
+fn only_the_players_hits_award_xp() {
+    let mut skills = Skills::default();
+    let spell = SpellData {
+        magic: MagicType::Fire,
+        hit_xp: 3,
+        ..SpellData::default()
+    };
+    let player = Entity::from_raw(1);
+    let monster = Entity::from_raw(2);
+
+    skills.add_hit_xp(&spell, monster, player);
+    assert_eq!(skills.get_xp(Skill::Pyromancy).map(|xp| xp.value), Some(0));
+
+    skills.add_hit_xp(&spell, player, player);
+    assert_eq!(skills.get_xp(Skill::Pyromancy).map(|xp| xp.value), Some(3));
+}
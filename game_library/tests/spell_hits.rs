@@ -0,0 +1,57 @@
+use bevy::{ecs::entity::Entity, math::Vec2};
+use game_library::{math, SpellData, SpellHits};
+
+#[test]
+fn pierce() {
+    let mut hits = SpellHits::new(1, 0);
+    let first = Entity::from_raw(1);
+    let second = Entity::from_raw(2);
+
+    assert!(!hits.has_hit(first));
+    assert!(hits.hit_target(first));
+    assert!(hits.has_hit(first));
+    assert!(!hits.hit_target(second));
+    assert_eq!(hits.hit, vec![first, second]);
+}
+
+#[test]
+fn bounce() {
+    let mut hits = SpellHits::new(0, 2);
+
+    assert!(hits.bounce());
+    assert!(hits.bounce());
+    assert!(!hits.bounce());
+    assert!(!hits.hit_target(Entity::from_raw(1)));
+}
+
+#[test]
+fn reflect() {
+    let reflected = math::reflect(Vec2::new(1.0, -1.0), Vec2::new(0.0, 2.0));
+    assert!((reflected - Vec2::new(1.0, 1.0)).length() < f32::EPSILON);
+
+    let unchanged = math::reflect(Vec2::new(1.0, -1.0), Vec2::ZERO);
+    assert!((unchanged - Vec2::new(1.0, -1.0)).length() < f32::EPSILON);
+}
+
+#[test]
+fn parse_pierce_and_bounces() {
+    let yaml = r"
+name: Test
+description: Test spell
+spellTier: 0
+magic: fire
+castSlot: primary
+target: any
+pierce: 2
+bounces: 1
+";
+    let spell: SpellData = serde_yaml::from_str(yaml).expect("failed to parse spell");
+
+    assert_eq!(spell.pierce, 2);
+    assert_eq!(spell.bounces, 1);
+    assert_eq!(spell.target, game_library::enums::SpellTarget::Any);
+    assert_eq!(
+        SpellData::default().target,
+        game_library::enums::SpellTarget::Enemy
+    );
+}
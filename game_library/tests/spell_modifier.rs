@@ -0,0 +1,160 @@
+use bevy::math::Vec2;
+use game_library::{
+    data_loader::{read_data_file, DataFile},
+    enums::{CastSlot, SpellTag, Trajectory},
+    spread_directions, SpellCondition, SpellData, SpellModifier, SpellModifierChoices,
+    SpellSnapshot, SpellSplit,
+};
+
+fn almost_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.0001
+}
+
+fn projectile_spell() -> SpellData {
+    SpellData {
+        speed: 2.0,
+        pierce: 1,
+        tags: vec![SpellTag::Projectile],
+        ..SpellData::default()
+    }
+}
+
+fn modifier(name: &str) -> SpellModifier {
+    SpellModifier {
+        name: name.to_string(),
+        ..SpellModifier::default()
+    }
+}
+
+#[test]
+fn modifiers_add_up() {
+    let spell = projectile_spell();
+    let double_cast = SpellModifier {
+        extra_projectiles: 1,
+        spread: 20.0,
+        ..modifier("Double Cast")
+    };
+    let piercing = SpellModifier {
+        pierce: 2,
+        homing: 90.0,
+        ..modifier("Piercing")
+    };
+    let snapshot = SpellSnapshot::new("test", &spell, None, [])
+        .with_modifiers(&spell, [&double_cast, &piercing]);
+
+    assert_eq!(snapshot.projectiles, 2);
+    assert!(almost_eq(snapshot.spread, 20.0));
+    assert_eq!(snapshot.pierce, 3);
+    assert_eq!(snapshot.trajectory, Trajectory::Homing { turn_rate: 90.0 });
+    assert!(snapshot.split.is_none());
+}
+
+#[test]
+fn homing_is_ignored_by_trajectories_which_cant_home() {
+    let homing = SpellModifier {
+        homing: 90.0,
+        ..modifier("Homing")
+    };
+    let boomerang = SpellData {
+        trajectory: Trajectory::Boomerang,
+        ..projectile_spell()
+    };
+
+    assert!(!homing.homing_ignored_by(&projectile_spell()));
+    assert!(homing.homing_ignored_by(&boomerang));
+    assert!(!modifier("No Homing").homing_ignored_by(&boomerang));
+}
+
+#[test]
+fn modifiers_skip_unmatched_spells() {
+    let spell = projectile_spell();
+    let beams_only = SpellModifier {
+        condition: SpellCondition::tagged(SpellTag::Beam),
+        extra_projectiles: 3,
+        ..modifier("Beams Only")
+    };
+    let snapshot =
+        SpellSnapshot::new("test", &spell, None, []).with_modifiers(&spell, [&beams_only]);
+
+    assert_eq!(snapshot.projectiles, 1);
+}
+
+#[test]
+fn first_split_wins() {
+    let spell = projectile_spell();
+    let first = SpellModifier {
+        split: Some(SpellSplit {
+            spell: Some("shard".to_string()),
+            count: 2,
+            spread: 45.0,
+        }),
+        ..modifier("First")
+    };
+    let second = SpellModifier {
+        split: Some(SpellSplit {
+            spell: None,
+            count: 3,
+            spread: 180.0,
+        }),
+        ..modifier("Second")
+    };
+    let snapshot =
+        SpellSnapshot::new("test", &spell, None, []).with_modifiers(&spell, [&first, &second]);
+    let split = snapshot.split.expect("spell should split");
+
+    assert_eq!(split.spell.as_deref(), Some("shard"));
+    assert_eq!(split.count, 5);
+    assert!(almost_eq(split.spread, 45.0));
+}
+
+#[test]
+fn no_directions_for_zero_projectiles() {
+    // e.g. a modifier splitting into 0 spells doesn't spawn any children
+    assert!(spread_directions(Vec2::X, 0, 90.0).is_empty());
+}
+
+#[test]
+fn directions_are_spread_evenly() {
+    let single = spread_directions(Vec2::X, 1, 90.0);
+    assert_eq!(single, vec![Vec2::X]);
+
+    let three = spread_directions(Vec2::X, 3, 90.0);
+    assert_eq!(three.len(), 3);
+    assert!(almost_eq(
+        three[0].angle_between(Vec2::X),
+        45_f32.to_radians()
+    ));
+    assert!(almost_eq(three[1].angle_between(Vec2::X), 0.0));
+    assert!(almost_eq(
+        three[2].angle_between(Vec2::X),
+        -45_f32.to_radians()
+    ));
+}
+
+#[test]
+fn choices_attach_and_detach() {
+    let mut choices = SpellModifierChoices::default();
+    choices.attach(CastSlot::Primary, "double-cast");
+    choices.attach(CastSlot::Primary, "homing");
+    choices.attach(CastSlot::Ultimate, "piercing");
+
+    assert_eq!(choices.get(CastSlot::Primary), ["double-cast", "homing"]);
+    assert!(choices.get(CastSlot::Secondary).is_empty());
+
+    choices.detach(CastSlot::Primary, "double-cast");
+    assert_eq!(choices.get(CastSlot::Primary), ["homing"]);
+
+    choices.clear(CastSlot::Ultimate);
+    assert!(choices.get(CastSlot::Ultimate).is_empty());
+}
+
+#[test]
+fn all_spell_modifier_files_parse() {
+    for entry in std::fs::read_dir("../game_data/spell_modifiers").expect("no modifiers directory")
+    {
+        let path = entry.expect("bad directory entry").path();
+        let data_file: Option<DataFile<SpellModifier>> =
+            read_data_file(path.to_string_lossy().as_ref());
+        assert!(data_file.is_some(), "failed to parse {path:?}");
+    }
+}
@@ -0,0 +1,210 @@
+use bevy::math::Vec2;
+use game_library::{enums::Trajectory, SpellMotion};
+
+const STEP: f32 = 1.0 / 60.0;
+
+fn almost_eq(a: f32, b: f32, tolerance: f32) -> bool {
+    (a - b).abs() < tolerance
+}
+
+/// Steps the motion `steps` times with a fixed timestep, returning the final position and velocity.
+fn simulate(
+    motion: &mut SpellMotion,
+    steps: usize,
+    start_velocity: Vec2,
+    caster: Vec2,
+    target: Option<Vec2>,
+) -> (Vec2, Vec2) {
+    let mut position = motion.origin;
+    let mut velocity = start_velocity;
+    for _ in 0..steps {
+        velocity = motion.step(STEP, position, velocity, caster, target);
+        position += velocity * STEP;
+    }
+    (position, velocity)
+}
+
+#[test]
+fn straight_keeps_velocity() {
+    let mut motion = SpellMotion::new(Trajectory::Straight, Vec2::ZERO, Vec2::X, 100.0, 0.0, 0.0);
+    let (position, velocity) = simulate(&mut motion, 60, Vec2::new(100.0, 0.0), Vec2::ZERO, None);
+
+    assert_eq!(velocity, Vec2::new(100.0, 0.0));
+    assert!(almost_eq(position.x, 100.0, 0.01));
+    assert!(!motion.finished);
+}
+
+#[test]
+fn homing_turns_towards_target() {
+    let trajectory = Trajectory::Homing { turn_rate: 90.0 };
+    let mut motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::X, 100.0, 0.0, 0.0);
+    let target = Some(Vec2::new(0.0, 1000.0));
+
+    // half a second at 90 degrees per second is a 45 degree turn
+    let (_, velocity) = simulate(&mut motion, 30, Vec2::new(100.0, 0.0), Vec2::ZERO, target);
+    assert!(almost_eq(velocity.length(), 100.0, 0.01));
+    assert!(almost_eq(
+        velocity.y.atan2(velocity.x),
+        45_f32.to_radians(),
+        0.01
+    ));
+
+    // without a target the spell flies straight
+    let mut motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::X, 100.0, 0.0, 0.0);
+    let (_, velocity) = simulate(&mut motion, 30, Vec2::new(100.0, 0.0), Vec2::ZERO, None);
+    assert_eq!(velocity, Vec2::new(100.0, 0.0));
+}
+
+#[test]
+fn arc_lands_on_aim_point() {
+    let trajectory = Trajectory::Arc { height: 50.0 };
+    let mut motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::X, 100.0, 150.0, 500.0);
+    assert_eq!(motion.landing_point(), Vec2::new(150.0, 0.0));
+
+    // halfway through the flight the spell is at the peak of the arc
+    let (peak, _) = simulate(&mut motion, 45, Vec2::ZERO, Vec2::ZERO, None);
+    assert!(almost_eq(peak.x, 75.0, 0.1));
+    assert!(almost_eq(peak.y, 50.0, 0.1));
+    assert!(!motion.has_landed());
+
+    let mut position = peak;
+    let mut velocity = Vec2::ZERO;
+    for _ in 0..46 {
+        velocity = motion.step(STEP, position, velocity, Vec2::ZERO, None);
+        position += velocity * STEP;
+    }
+    assert!(motion.finished);
+    assert!(motion.has_landed());
+    assert!(position.distance(Vec2::new(150.0, 0.0)) < 0.1);
+}
+
+#[test]
+fn arc_is_limited_by_range() {
+    let trajectory = Trajectory::Arc { height: 10.0 };
+    let motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::Y, 100.0, 900.0, 200.0);
+
+    assert_eq!(motion.landing_point(), Vec2::new(0.0, 200.0));
+}
+
+#[test]
+fn orbit_circles_caster() {
+    let trajectory = Trajectory::Orbit {
+        radius: 40.0,
+        angular_speed: 180.0,
+    };
+    let caster = Vec2::new(10.0, 10.0);
+    let mut motion = SpellMotion::new(trajectory, caster, Vec2::X, 100.0, 0.0, 0.0);
+
+    for steps in [1, 30, 60, 90] {
+        let (position, _) = simulate(&mut motion, steps, Vec2::ZERO, caster, None);
+        assert!(almost_eq(position.distance(caster), 40.0, 0.01));
+        motion.elapsed = 0.0;
+    }
+}
+
+#[test]
+fn boomerang_returns_to_caster() {
+    let mut motion = SpellMotion::new(
+        Trajectory::Boomerang,
+        Vec2::ZERO,
+        Vec2::X,
+        100.0,
+        0.0,
+        100.0,
+    );
+
+    // out for a second (to its range)...
+    let (position, _) = simulate(&mut motion, 60, Vec2::ZERO, Vec2::ZERO, None);
+    assert!(almost_eq(position.x, 100.0, 2.0));
+    assert!(!motion.finished);
+
+    // ...and back for a second
+    let mut position = position;
+    let mut velocity = Vec2::ZERO;
+    for _ in 0..70 {
+        velocity = motion.step(STEP, position, velocity, Vec2::ZERO, None);
+        position += velocity * STEP;
+    }
+    assert!(motion.returning);
+    assert!(motion.finished);
+    assert!(position.length() < 0.1);
+}
+
+#[test]
+fn sine_wave_weaves() {
+    let trajectory = Trajectory::SineWave {
+        amplitude: 20.0,
+        frequency: 1.0,
+    };
+    let mut motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::X, 100.0, 0.0, 0.0);
+
+    // a quarter of a wave in, the spell is all the way to one side
+    let (position, _) = simulate(&mut motion, 15, Vec2::ZERO, Vec2::ZERO, None);
+    assert!(almost_eq(position.x, 25.0, 0.01));
+    assert!(almost_eq(position.y, 20.0, 0.01));
+
+    // and half a wave later it's on the other side
+    let mut position = position;
+    let mut velocity = Vec2::ZERO;
+    for _ in 0..30 {
+        velocity = motion.step(STEP, position, velocity, Vec2::ZERO, None);
+        position += velocity * STEP;
+    }
+    assert!(almost_eq(position.x, 75.0, 0.01));
+    assert!(almost_eq(position.y, -20.0, 0.01));
+}
+
+#[test]
+fn accelerate_then_stop() {
+    let trajectory = Trajectory::AccelerateThenStop {
+        acceleration: 100.0,
+        stop_after: 1.0,
+    };
+    let mut motion = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::X, 50.0, 0.0, 0.0);
+
+    let (_, velocity) = simulate(&mut motion, 30, Vec2::ZERO, Vec2::ZERO, None);
+    assert!(almost_eq(velocity.x, 100.0, 0.01));
+
+    let (position, velocity) = simulate(&mut motion, 60, Vec2::ZERO, Vec2::ZERO, None);
+    assert_eq!(velocity, Vec2::ZERO);
+    // it stays stopped
+    assert_eq!(
+        motion.step(STEP, position, Vec2::X, Vec2::ZERO, None),
+        Vec2::ZERO
+    );
+}
+
+#[test]
+fn same_steps_same_path() {
+    let trajectory = Trajectory::SineWave {
+        amplitude: 5.0,
+        frequency: 3.0,
+    };
+    let mut a = SpellMotion::new(trajectory, Vec2::ZERO, Vec2::Y, 80.0, 0.0, 0.0);
+    let mut b = a;
+
+    assert_eq!(
+        simulate(&mut a, 100, Vec2::ZERO, Vec2::ZERO, None),
+        simulate(&mut b, 100, Vec2::ZERO, Vec2::ZERO, None)
+    );
+}
+
+#[test]
+fn homing_modifiers() {
+    assert_eq!(
+        Trajectory::Straight.with_homing(90.0),
+        Trajectory::Homing { turn_rate: 90.0 }
+    );
+    assert_eq!(
+        Trajectory::Homing { turn_rate: 30.0 }.with_homing(60.0),
+        Trajectory::Homing { turn_rate: 90.0 }
+    );
+    assert_eq!(
+        Trajectory::Boomerang.with_homing(90.0),
+        Trajectory::Boomerang
+    );
+    assert_eq!(Trajectory::Straight.with_homing(0.0), Trajectory::Straight);
+
+    assert!(Trajectory::Straight.supports_homing());
+    assert!(!Trajectory::Boomerang.supports_homing());
+}
@@ -0,0 +1,72 @@
+use game_library::{
+    enums::{SpellTag, StatEnum},
+    SpellCondition, SpellData, SpellSnapshot, SpellStatModifier, StatBonus, StatBundle,
+};
+
+fn almost_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.0001
+}
+
+fn test_spell() -> SpellData {
+    SpellData {
+        speed: 2.0,
+        duration: 4.0,
+        range: 5.0,
+        damage: 10,
+        healing: 3,
+        tags: vec![SpellTag::Projectile],
+        ..SpellData::default()
+    }
+}
+
+#[test]
+fn without_caster_stats() {
+    let snapshot = SpellSnapshot::new("test", &test_spell(), None, []);
+
+    assert_eq!(snapshot.spell_id, "test");
+    assert!(almost_eq(snapshot.speed, 2.0));
+    assert!(almost_eq(snapshot.scale, 1.0));
+    assert!(almost_eq(snapshot.lifetime, 4.0));
+    assert!(almost_eq(snapshot.range, 5.0));
+    assert!(almost_eq(snapshot.damage, 10.0));
+    assert!(almost_eq(snapshot.healing, 3.0));
+}
+
+#[test]
+fn with_caster_stats() {
+    let stats = StatBundle::new(vec![
+        (StatEnum::ProjectileSpeed, 1.5),
+        (StatEnum::ProjectileSize, 2.0),
+        (StatEnum::ProjectileLifetime, 0.5),
+        (StatEnum::SpellRange, 50.0),
+        (StatEnum::MagicDamage, 1.2),
+    ]);
+    let snapshot = SpellSnapshot::new("test", &test_spell(), Some(&stats), []);
+
+    assert!(almost_eq(snapshot.speed, 3.0));
+    assert!(almost_eq(snapshot.scale, 2.0));
+    assert!(almost_eq(snapshot.lifetime, 2.0));
+    assert!(almost_eq(snapshot.range, 250.0));
+    assert!(almost_eq(snapshot.damage, 12.0));
+}
+
+#[test]
+fn with_spell_modifiers() {
+    let stats = StatBundle::new(vec![(StatEnum::ProjectileSpeed, 2.0)]);
+    let modifiers = [
+        SpellStatModifier {
+            condition: SpellCondition::tagged(SpellTag::Projectile),
+            effects: vec![(StatEnum::ProjectileSpeed, StatBonus::new(1.1))],
+        },
+        SpellStatModifier {
+            condition: SpellCondition::tagged(SpellTag::Beam),
+            effects: vec![(StatEnum::MagicDamage, StatBonus::new(3.0))],
+        },
+    ];
+    let snapshot = SpellSnapshot::new("test", &test_spell(), Some(&stats), &modifiers);
+
+    // 2.0 base * 2.0 stat * 1.1 modifier
+    assert!(almost_eq(snapshot.speed, 4.4));
+    // Beam modifier doesn't apply
+    assert!(almost_eq(snapshot.damage, 10.0));
+}
@@ -0,0 +1,145 @@
+use game_library::{
+    data_loader::{read_data_file, storage::Vault, DataFile},
+    enums::{CastSlot, MagicType, SpellTag, StatEnum},
+    SpellCondition, SpellData, SpellStatModifier, StatBonus,
+};
+
+fn spell(name: &str, magic: MagicType, tags: Vec<SpellTag>) -> SpellData {
+    SpellData {
+        name: name.to_string(),
+        magic,
+        tags,
+        ..SpellData::default()
+    }
+}
+
+#[test]
+fn parse_flags() {
+    let yaml = r"
+header:
+  uniqueId: test_spell
+  system: Spell
+  author: test
+  description: test
+  internalVersion: 1
+  displayVersion: v1.0
+  validGameInternalVersion: 1
+data:
+  name: Test
+  description: Test spell
+  spellTier: 0
+  magic: time
+  castSlot: primary
+  flags:
+    - debuff
+    - projectile
+    - self
+";
+    let data_file: DataFile<SpellData> = serde_yaml::from_str(yaml).expect("failed to parse spell");
+
+    assert_eq!(
+        data_file.data.tags,
+        vec![SpellTag::Debuff, SpellTag::Projectile, SpellTag::OnSelf]
+    );
+    assert!(data_file.data.has_tag(SpellTag::Projectile));
+    assert!(!data_file.data.has_tag(SpellTag::Beam));
+}
+
+#[test]
+fn all_spell_files_parse() {
+    for entry in std::fs::read_dir("../game_data/spells").expect("no spells directory") {
+        let path = entry.expect("bad directory entry").path();
+        let data_file: Option<DataFile<SpellData>> =
+            read_data_file(path.to_string_lossy().as_ref());
+        assert!(data_file.is_some(), "failed to parse {path:?}");
+    }
+}
+
+#[test]
+fn condition_matches() {
+    let fire_bolt = spell(
+        "Fire Bolt",
+        MagicType::Fire,
+        vec![SpellTag::Projectile, SpellTag::Damage],
+    );
+    let fire_wall = spell("Fire Wall", MagicType::Fire, vec![SpellTag::Wall]);
+    let ice_dart = spell("Ice Dart", MagicType::Ice, vec![SpellTag::Projectile]);
+
+    assert!(SpellCondition::default().matches(&fire_wall));
+
+    let projectiles = SpellCondition::tagged(SpellTag::Projectile);
+    assert!(projectiles.matches(&fire_bolt));
+    assert!(!projectiles.matches(&fire_wall));
+    assert!(projectiles.matches(&ice_dart));
+
+    let fire_projectiles = SpellCondition {
+        magic: Some(MagicType::Fire),
+        ..SpellCondition::tagged(SpellTag::Projectile)
+    };
+    assert!(fire_projectiles.matches(&fire_bolt));
+    assert!(!fire_projectiles.matches(&ice_dart));
+
+    let any = SpellCondition {
+        any_tags: vec![SpellTag::Wall, SpellTag::Damage],
+        without_tags: vec![SpellTag::Projectile],
+        ..SpellCondition::default()
+    };
+    assert!(!any.matches(&fire_bolt));
+    assert!(any.matches(&fire_wall));
+    assert!(!any.matches(&ice_dart));
+
+    let secondary = SpellCondition {
+        cast_slot: Some(CastSlot::Secondary),
+        ..SpellCondition::default()
+    };
+    assert!(!secondary.matches(&fire_bolt));
+}
+
+#[test]
+fn modifier_bonus_totals() {
+    let fire_bolt = spell("Fire Bolt", MagicType::Fire, vec![SpellTag::Projectile]);
+    let modifiers = [
+        SpellStatModifier {
+            condition: SpellCondition::tagged(SpellTag::Projectile),
+            effects: vec![(StatEnum::ProjectileSpeed, StatBonus::new(1.1))],
+        },
+        SpellStatModifier {
+            condition: SpellCondition::magic(MagicType::Fire),
+            effects: vec![(StatEnum::ProjectileSpeed, StatBonus::new(1.2))],
+        },
+        SpellStatModifier {
+            condition: SpellCondition::magic(MagicType::Ice),
+            effects: vec![(StatEnum::MagicDamage, StatBonus::new(2.0))],
+        },
+    ];
+
+    let totals = SpellStatModifier::bonus_totals(&modifiers, &fire_bolt);
+    let speed = totals
+        .get(&StatEnum::ProjectileSpeed)
+        .copied()
+        .unwrap_or(0.0);
+    assert!((speed - 0.3).abs() < 0.0001);
+    assert!(!totals.contains_key(&StatEnum::MagicDamage));
+}
+
+#[test]
+fn vault_tag_queries() {
+    let mut spells = Vault::default();
+    spells.insert(
+        "fire_bolt".to_string(),
+        spell("Fire Bolt", MagicType::Fire, vec![SpellTag::Projectile]),
+    );
+    spells.insert(
+        "fire_wall".to_string(),
+        spell("Fire Wall", MagicType::Fire, vec![SpellTag::Wall]),
+    );
+
+    let projectiles: Vec<&String> = spells
+        .with_tag(SpellTag::Projectile)
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(projectiles, vec!["fire_bolt"]);
+
+    let condition = SpellCondition::magic(MagicType::Fire);
+    assert_eq!(spells.matching(&condition).count(), 2);
+}
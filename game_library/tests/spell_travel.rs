@@ -0,0 +1,47 @@
+use bevy::math::Vec2;
+use game_library::{MaxSpeed, SpellData, SpellSnapshot, SpellTravel};
+
+#[test]
+fn spell_travel() {
+    let mut travel = SpellTravel::new(Vec2::new(10.0, 0.0), 20.0);
+    assert!(travel.distance.abs() < f32::EPSILON);
+    assert!(!travel.is_out_of_range());
+
+    travel.update(Vec2::new(20.0, 0.0));
+    assert!((travel.distance - 10.0).abs() < f32::EPSILON);
+    assert!((travel.remaining() - 10.0).abs() < f32::EPSILON);
+    assert!(!travel.is_out_of_range());
+
+    // Coming back towards the origin still counts as travelling
+    travel.update(Vec2::new(10.0, 0.0));
+    assert!((travel.distance - 20.0).abs() < f32::EPSILON);
+    assert!(travel.displacement().abs() < f32::EPSILON);
+    assert!(travel.remaining().abs() < f32::EPSILON);
+    assert!(travel.is_out_of_range());
+}
+
+#[test]
+fn unlimited_range() {
+    let mut travel = SpellTravel::new(Vec2::ZERO, 0.0);
+    travel.update(Vec2::new(1000.0, 1000.0));
+    assert!(!travel.is_out_of_range());
+    assert!(travel.remaining().is_infinite());
+}
+
+#[test]
+fn default_range_projectiles_travel_across_the_screen() {
+    let snapshot = SpellSnapshot::new("test", &SpellData::default(), None, []);
+    let mut travel = SpellTravel::new(Vec2::ZERO, snapshot.range);
+
+    travel.update(Vec2::new(300.0, 0.0));
+    assert!(!travel.is_out_of_range());
+    travel.update(Vec2::new(330.0, 0.0));
+    assert!(travel.is_out_of_range());
+}
+
+#[test]
+fn max_speed() {
+    let max_speed = MaxSpeed::new(5.0);
+    assert_eq!(max_speed.clamp(Vec2::new(3.0, 0.0)), Vec2::new(3.0, 0.0));
+    assert_eq!(max_speed.clamp(Vec2::new(0.0, 10.0)), Vec2::new(0.0, 5.0));
+}
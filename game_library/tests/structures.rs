@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use game_library::{ground_target, AreaShape, Structure, StructureData};
+
+#[test]
+fn structure_data_shapes() {
+    let wall: StructureData = serde_yaml::from_str(
+        "spriteTileset: rock\nshape:\n  rectangle:\n    width: 32\n    height: 6\nhealth: 40\n",
+    )
+    .expect("valid structure data");
+    assert_eq!(
+        wall.shape,
+        AreaShape::Rectangle {
+            width: 32.0,
+            height: 6.0
+        }
+    );
+    assert_eq!(wall.health, 40);
+    assert!(wall.destruction_spell.is_none());
+
+    let pillar: StructureData =
+        serde_yaml::from_str("destructionSpell: flame_burst\n").expect("valid structure data");
+    assert_eq!(pillar.shape, AreaShape::default());
+    assert_eq!(pillar.destruction_spell.as_deref(), Some("flame_burst"));
+}
+
+#[test]
+fn ground_target_is_limited_by_range() {
+    let origin = Vec2::new(10.0, 0.0);
+    assert_eq!(
+        ground_target(origin, Vec2::new(20.0, 0.0), 50.0),
+        Vec2::new(20.0, 0.0)
+    );
+    assert_eq!(
+        ground_target(origin, Vec2::new(110.0, 0.0), 50.0),
+        Vec2::new(60.0, 0.0)
+    );
+    assert_eq!(
+        ground_target(origin, Vec2::new(110.0, 0.0), 0.0),
+        Vec2::new(110.0, 0.0)
+    );
+}
+
+#[test]
+fn structures_expire_after_their_lifetime() {
+    let mut structure = Structure::new(Entity::from_raw(1), "rock-pillar", 2.0);
+    structure.tick(1.0);
+    assert!(!structure.is_expired());
+    structure.tick(1.0);
+    assert!(structure.is_expired());
+}
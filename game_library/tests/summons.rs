@@ -0,0 +1,70 @@
+use bevy::prelude::Entity;
+use game_library::{
+    enums::{Faction, SpellTarget, StatEnum},
+    SummonData, Summoned,
+};
+
+#[test]
+fn factions_are_targeted_by_their_side() {
+    assert!(Faction::Enemy.is_targeted_by(&SpellTarget::Enemy));
+    assert!(!Faction::Ally.is_targeted_by(&SpellTarget::Enemy));
+    assert!(!Faction::Player.is_targeted_by(&SpellTarget::Enemy));
+
+    assert!(Faction::Ally.is_targeted_by(&SpellTarget::Ally));
+    assert!(Faction::Player.is_targeted_by(&SpellTarget::Ally));
+    assert!(!Faction::Ally.is_targeted_by(&SpellTarget::Player));
+
+    assert!(Faction::Enemy.is_targeted_by(&SpellTarget::Any));
+    assert!(!Faction::Player.is_targeted_by(&SpellTarget::None));
+    assert_eq!(Faction::default(), Faction::Enemy);
+}
+
+#[test]
+fn summon_data_defaults() {
+    let summon: SummonData = serde_yaml::from_str(
+        "spriteTileset: imp\nstats:\n  - [movementSpeed, 2.5]\nattackSpell: firebolt\n",
+    )
+    .expect("valid summon data");
+
+    assert_eq!(summon.sprite_tileset, "imp");
+    assert_eq!(summon.stats, vec![(StatEnum::MovementSpeed, 2.5)]);
+    assert_eq!(summon.attack_spell.as_deref(), Some("firebolt"));
+    assert_eq!(summon.max_count, 1);
+    assert_eq!(summon.count, 1);
+    assert!((summon.lifetime - 30.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn summoning_replaces_the_oldest_past_max_count() {
+    let summon = SummonData {
+        max_count: 3,
+        count: 2,
+        ..SummonData::default()
+    };
+
+    assert_eq!(summon.plan_spawns(0), (2, 0));
+    assert_eq!(summon.plan_spawns(1), (2, 0));
+    assert_eq!(summon.plan_spawns(2), (2, 1));
+    assert_eq!(summon.plan_spawns(3), (2, 2));
+
+    let greedy = SummonData {
+        max_count: 1,
+        count: 4,
+        ..SummonData::default()
+    };
+    assert_eq!(greedy.plan_spawns(1), (1, 1));
+}
+
+#[test]
+fn summons_expire_after_their_lifetime() {
+    let owner = Entity::from_raw(1);
+    let mut summoned = Summoned::new(owner, "summon-imp-0", 0.0, 1.0);
+    summoned.tick(0.5);
+    assert!(!summoned.is_expired());
+    summoned.tick(0.5);
+    assert!(summoned.is_expired());
+
+    let mut permanent = Summoned::new(owner, "summon-undead-basic", 0.0, 0.0);
+    permanent.tick(1000.0);
+    assert!(!permanent.is_expired());
+}
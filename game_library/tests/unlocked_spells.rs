@@ -0,0 +1,184 @@
+use game_library::{
+    data_loader::{read_data_file, storage::Vault, DataFile},
+    enums::{CastSlot, MagicType, Skill},
+    Skills, SpellChoiceError, SpellChoices, SpellData, SpellRequirements, SpellUnlockError,
+    UnlockedSpells,
+};
+
+fn spell(cast_slot: CastSlot, requirements: SpellRequirements) -> SpellData {
+    SpellData {
+        magic: MagicType::Fire,
+        cast_slot,
+        requirements,
+        ..SpellData::default()
+    }
+}
+
+fn spells() -> Vault<SpellData> {
+    let mut spells = Vault::default();
+    spells.add(
+        "firebolt".to_string(),
+        spell(CastSlot::Primary, SpellRequirements::default()),
+    );
+    spells.add(
+        "fireball".to_string(),
+        spell(
+            CastSlot::Secondary,
+            SpellRequirements {
+                prerequisites: vec!["firebolt".to_string()],
+                ..SpellRequirements::default()
+            },
+        ),
+    );
+    spells.add(
+        "inferno".to_string(),
+        spell(
+            CastSlot::Ultimate,
+            SpellRequirements {
+                skill_level: 5,
+                research: vec!["combustion".to_string()],
+                ..SpellRequirements::default()
+            },
+        ),
+    );
+    spells
+}
+
+#[test]
+fn unlock_available_follows_prerequisites() {
+    let spells = spells();
+    let mut unlocked = UnlockedSpells::default();
+
+    let newly_unlocked = unlocked.unlock_available(&spells, &Skills::default());
+
+    assert_eq!(newly_unlocked, ["fireball", "firebolt"]);
+    assert!(unlocked.is_unlocked("firebolt"));
+    assert!(!unlocked.is_unlocked("inferno"));
+    assert!(unlocked
+        .unlock_available(&spells, &Skills::default())
+        .is_empty());
+}
+
+#[test]
+fn requirements_give_clear_errors() {
+    let spells = spells();
+    let mut unlocked = UnlockedSpells::default();
+    let skills = Skills::default();
+    let fireball = spells.get("fireball").expect("fireball");
+    let inferno = spells.get("inferno").expect("inferno");
+
+    assert_eq!(
+        unlocked.unlock("fireball", fireball, &skills),
+        Err(SpellUnlockError::MissingPrerequisite(
+            "firebolt".to_string()
+        ))
+    );
+    assert_eq!(
+        unlocked.check_requirements(inferno, &skills),
+        Err(SpellUnlockError::SkillTooLow {
+            skill: Skill::Pyromancy,
+            required: 5,
+            current: 1,
+        })
+    );
+}
+
+#[test]
+fn research_unlocks_spells() {
+    let spells = spells();
+    let mut unlocked = UnlockedSpells::default();
+    let inferno = spells.get("inferno").expect("inferno");
+    let inferno = SpellData {
+        requirements: SpellRequirements {
+            skill_level: 0,
+            ..inferno.requirements.clone()
+        },
+        ..inferno.clone()
+    };
+
+    assert_eq!(
+        unlocked.unlock("inferno", &inferno, &Skills::default()),
+        Err(SpellUnlockError::MissingResearch("combustion".to_string()))
+    );
+    unlocked.complete_research("combustion");
+    assert!(unlocked
+        .unlock("inferno", &inferno, &Skills::default())
+        .is_ok());
+    assert!(unlocked.is_unlocked("inferno"));
+}
+
+#[test]
+fn choices_are_validated() {
+    let spells = spells();
+    let mut unlocked = UnlockedSpells::default();
+    unlocked.unlock_available(&spells, &Skills::default());
+    let mut choices = SpellChoices::default();
+
+    assert!(choices
+        .set_primary_by_id("firebolt".to_string(), &spells, &unlocked)
+        .is_ok());
+    assert_eq!(
+        choices.get(CastSlot::Primary).map(String::as_str),
+        Some("firebolt")
+    );
+
+    assert_eq!(
+        choices.set_primary_by_id("fireball".to_string(), &spells, &unlocked),
+        Err(SpellChoiceError::WrongSlot {
+            spell_id: "fireball".to_string(),
+            slot: CastSlot::Primary,
+            spell_slot: CastSlot::Secondary,
+        })
+    );
+    assert_eq!(
+        choices.set_ultimate_by_id("inferno".to_string(), &spells, &unlocked),
+        Err(SpellChoiceError::Locked("inferno".to_string()))
+    );
+    assert_eq!(
+        choices.set(
+            CastSlot::Defensive,
+            "shield".to_string(),
+            &spells,
+            &unlocked
+        ),
+        Err(SpellChoiceError::UnknownSpell("shield".to_string()))
+    );
+
+    // failed choices leave the loadout unchanged
+    assert_eq!(
+        choices.get(CastSlot::Primary).map(String::as_str),
+        Some("firebolt")
+    );
+    assert!(choices.get(CastSlot::Ultimate).is_none());
+    assert!(choices.validate(&spells, &unlocked).is_ok());
+
+    choices.ultimate = Some("inferno".to_string());
+    assert_eq!(
+        choices.validate(&spells, &unlocked),
+        Err(SpellChoiceError::Locked("inferno".to_string()))
+    );
+}
+
+#[test]
+fn default_loadout_can_be_chosen() {
+    let mut spells = Vault::default();
+    for entry in std::fs::read_dir("../game_data/spells").expect("no spells directory") {
+        let path = entry.expect("bad directory entry").path();
+        let data_file: DataFile<SpellData> =
+            read_data_file(path.to_string_lossy().as_ref()).expect("valid spell");
+        spells.add(data_file.header.unique_id, data_file.data);
+    }
+    let mut unlocked = UnlockedSpells::default();
+    unlocked.unlock_available(&spells, &Skills::default());
+
+    // the spells the player avatar spawns with
+    let mut choices = SpellChoices::default();
+    assert_eq!(
+        choices.set_primary_by_id("time_dart".to_string(), &spells, &unlocked),
+        Ok(())
+    );
+    assert_eq!(
+        choices.set_secondary_by_id("freeze_ray".to_string(), &spells, &unlocked),
+        Ok(())
+    );
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use game_library::{enums::StatEnum, AreaShape, Zone, ZoneData};
+
+#[test]
+fn zone_data_defaults() {
+    let zone: ZoneData = serde_yaml::from_str("onTick:\n  damage: 2\n").expect("valid zone data");
+    assert_eq!(zone.shape, AreaShape::Circle { radius: 24.0 });
+    assert!((zone.tick_interval - 1.0).abs() < f32::EPSILON);
+    assert_eq!(zone.on_tick.damage, 2);
+    assert!(zone.on_enter.is_empty());
+    assert!(zone.on_exit.is_empty());
+    // no movement speed change means no effect
+    assert!(zone.movement_effect("path-of-coals").is_none());
+}
+
+#[test]
+fn zone_movement_effect() {
+    let zone: ZoneData =
+        serde_yaml::from_str("lifetime: 8\nmovementSpeed: 0.4\n").expect("valid zone data");
+    let effect = zone.movement_effect("quicksand").expect("slows characters");
+    assert_eq!(effect.id, ZoneData::movement_effect_id("quicksand"));
+    assert_eq!(effect.duration, 800);
+    assert_eq!(effect.effects.len(), 1);
+    assert_eq!(effect.effects[0].0, StatEnum::MovementSpeed);
+}
+
+#[test]
+fn zone_tracks_occupants() {
+    let mut zone = Zone::new(Entity::from_raw(1), "quicksand", &ZoneData::default());
+    let (a, b) = (Entity::from_raw(2), Entity::from_raw(3));
+
+    assert!(zone.enter(a));
+    assert!(!zone.enter(a));
+    assert!(zone.enter(b));
+    assert_eq!(zone.occupants().collect::<Vec<_>>(), vec![a, b]);
+
+    assert!(zone.exit(a));
+    assert!(!zone.exit(a));
+    assert!(!zone.contains(a));
+    assert!(zone.contains(b));
+
+    let removed = zone.retain_occupants(|occupant| occupant != b);
+    assert_eq!(removed, vec![b]);
+    assert_eq!(zone.occupants().count(), 0);
+}
+
+#[test]
+fn zone_ticks_and_expires() {
+    let data = ZoneData {
+        lifetime: 2.0,
+        tick_interval: 0.5,
+        ..Default::default()
+    };
+    let mut zone = Zone::new(Entity::from_raw(1), "path-of-coals", &data);
+
+    assert_eq!(zone.tick(0.25), 0);
+    assert_eq!(zone.tick(0.25), 1);
+    // a long frame ticks several times
+    assert_eq!(zone.tick(1.0), 2);
+    assert!(!zone.is_expired());
+    assert_eq!(zone.tick(0.5), 1);
+    assert!(zone.is_expired());
+}
+
+#[test]
+fn area_shape_contains() {
+    let center = Vec2::new(10.0, 10.0);
+    let circle = AreaShape::Circle { radius: 5.0 };
+    assert!(circle.contains(center, Vec2::new(13.0, 14.0)));
+    assert!(!circle.contains(center, Vec2::new(14.0, 14.0)));
+
+    let rectangle = AreaShape::Rectangle {
+        width: 20.0,
+        height: 4.0,
+    };
+    assert!(rectangle.contains(center, Vec2::new(19.0, 11.0)));
+    assert!(!rectangle.contains(center, Vec2::new(10.0, 13.0)));
+}